@@ -0,0 +1,101 @@
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::prelude::{
+   Component, EntityWorldMut, IntoSystemConfigs, Query, ReflectComponent, With,
+};
+use bevy_hierarchy::Parent;
+use bevy_math::Vec2;
+use bevy_reflect::Reflect;
+use bevy_ui::{Node, Style, UiSystem, Val};
+use bevy_window::{PrimaryWindow, Window};
+
+use rxy_core::{CalcProperty, CalcVal, CalcValues};
+
+use crate::ElementStyleEntityExt;
+
+/// The [`Calc`](rxy_core::Calc) values of a node, written into its [`Style`] as [`Val::Px`] before each layout.
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[reflect(Component)]
+pub struct CalcStyle(pub CalcValues);
+
+pub fn style_val_mut(style: &mut Style, property: CalcProperty) -> &mut Val {
+   match property {
+      CalcProperty::Left => &mut style.left,
+      CalcProperty::Right => &mut style.right,
+      CalcProperty::Top => &mut style.top,
+      CalcProperty::Bottom => &mut style.bottom,
+      CalcProperty::Width => &mut style.width,
+      CalcProperty::Height => &mut style.height,
+      CalcProperty::MinWidth => &mut style.min_width,
+      CalcProperty::MinHeight => &mut style.min_height,
+      CalcProperty::MaxWidth => &mut style.max_width,
+      CalcProperty::MaxHeight => &mut style.max_height,
+      CalcProperty::MarginLeft => &mut style.margin.left,
+      CalcProperty::MarginRight => &mut style.margin.right,
+      CalcProperty::MarginTop => &mut style.margin.top,
+      CalcProperty::MarginBottom => &mut style.margin.bottom,
+      CalcProperty::PaddingLeft => &mut style.padding.left,
+      CalcProperty::PaddingRight => &mut style.padding.right,
+      CalcProperty::PaddingTop => &mut style.padding.top,
+      CalcProperty::PaddingBottom => &mut style.padding.bottom,
+   }
+}
+
+pub trait CalcStyleEntityWorldMutExt {
+   fn set_calc_val(&mut self, property: CalcProperty, value: CalcVal<Val>);
+}
+
+impl CalcStyleEntityWorldMutExt for EntityWorldMut<'_> {
+   fn set_calc_val(&mut self, property: CalcProperty, value: CalcVal<Val>) {
+      match value {
+         CalcVal::Val(value) => {
+            self.try_set::<CalcStyle>(|calc_style| {
+               calc_style.0.remove(property);
+            });
+            self.try_set_style(|style| {
+               *style_val_mut(style, property) = value;
+            });
+         }
+         CalcVal::Calc(calc) => {
+            if let Some(mut calc_style) = self.get_mut::<CalcStyle>() {
+               calc_style.0.insert(property, calc);
+            } else {
+               self.insert(CalcStyle(CalcValues(vec![(property, calc)])));
+            }
+         }
+      }
+   }
+}
+
+pub struct CalcStylePlugin;
+
+impl Plugin for CalcStylePlugin {
+   fn build(&self, app: &mut App) {
+      app.register_type::<CalcStyle>()
+         .add_systems(PostUpdate, resolve_calc_styles.before(UiSystem::Layout));
+   }
+}
+
+/// Percentages resolve against the parent size of the last layout.
+fn resolve_calc_styles(
+   windows: Query<&Window, With<PrimaryWindow>>,
+   nodes: Query<&Node>,
+   mut query: Query<(&CalcStyle, Option<&Parent>, &mut Style)>,
+) {
+   let viewport = windows
+      .get_single()
+      .map(|window| Vec2::new(window.width(), window.height()))
+      .unwrap_or_default();
+   for (calc_style, parent, mut style) in query.iter_mut() {
+      let parent_size = parent
+         .and_then(|parent| nodes.get(parent.get()).ok())
+         .map(|node| node.size())
+         .unwrap_or(viewport);
+      for (property, value) in calc_style.0.resolve(parent_size.into(), viewport.into()) {
+         let value = Val::Px(value);
+         // only trigger change detection when the resolved value is different
+         if *style_val_mut(style.bypass_change_detection(), property) != value {
+            *style_val_mut(&mut style, property) = value;
+         }
+      }
+   }
+}
@@ -0,0 +1,273 @@
+use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::prelude::{
+   Changed, Commands, Component, DetectChangesMut, Entity, EventReader, IntoSystemConfigs, Query,
+   Res, ResMut, With,
+};
+use bevy_hierarchy::{Children, Parent};
+use bevy_input::keyboard::{Key, KeyboardInput};
+use bevy_input::mouse::MouseScrollUnit;
+use bevy_input::ButtonState;
+use bevy_mod_picking::prelude::Pointer;
+use bevy_reflect::Reflect;
+use bevy_render::render_asset::RenderAssetUsages;
+use bevy_render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_render::texture::Image;
+use bevy_text::{Text, TextSection, TextStyle};
+use bevy_transform::prelude::Transform;
+use bevy_transform::TransformSystem;
+use bevy_ui::{Interaction, Node, UiImage, UiSystem};
+
+use rxy_core::common_renderer::CommonKeyEvent;
+
+use crate::{FocusedEntity, UiEventCommandsExt, Wheel};
+
+/// The pixels scrolled by one line of the mouse wheel
+const SCROLL_LINE_HEIGHT: f32 = 20.;
+
+/// The new value of a text input, triggered as a [`UiEvent`](crate::UiEvent).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputValue(pub String);
+
+/// The new checked state of a toggle, triggered as a [`UiEvent`](crate::UiEvent).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToggleValue(pub bool);
+
+/// A single line text field edited with the keyboard while focused.
+#[derive(Reflect, Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextInput {
+   pub value: String,
+}
+
+/// A node switching between checked and unchecked when pressed.
+#[derive(Reflect, Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Toggle {
+   pub checked: bool,
+}
+
+/// The vertical scroll position of a node, its children are offset by it after the layout.
+#[derive(Reflect, Component, Clone, Debug, Default, PartialEq)]
+pub struct ScrollOffset {
+   pub y: f32,
+}
+
+/// The pixels of a canvas node, shown as its [`UiImage`].
+///
+/// The image is created at the size of the node by the layout, and resized and cleared when the size changes.
+/// Draw into it from a system reacting to `Changed<Canvas>`:
+///
+/// ```ignore
+/// fn draw(canvases: Query<&Canvas, Changed<Canvas>>, mut images: ResMut<Assets<Image>>) {
+///    for canvas in canvases.iter() {
+///       let Some(image) = canvas.image_mut(&mut images) else {
+///          continue;
+///       };
+///       // image.data holds the `Rgba8UnormSrgb` pixels, row by row
+///    }
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Default)]
+pub struct Canvas {
+   image: Option<Handle<Image>>,
+}
+
+impl Canvas {
+   /// The image drawn by the node, `None` until its first layout
+   pub fn image(&self) -> Option<&Handle<Image>> {
+      self.image.as_ref()
+   }
+
+   pub fn image_mut<'a>(&self, images: &'a mut Assets<Image>) -> Option<&'a mut Image> {
+      images.get_mut(self.image.as_ref()?)
+   }
+}
+
+/// The behaviors of the views created by [`CommonRenderer`](rxy_core::common_renderer::CommonRenderer),
+/// and the key down events of the focused node.
+pub struct CommonWidgetsPlugin;
+
+impl Plugin for CommonWidgetsPlugin {
+   fn build(&self, app: &mut App) {
+      app.register_type::<TextInput>()
+         .register_type::<Toggle>()
+         .register_type::<ScrollOffset>()
+         .add_systems(PreUpdate, (keyboard_events, toggle_pressed, wheel_scroll))
+         .add_systems(
+            PostUpdate,
+            (
+               apply_scroll_offset
+                  .after(UiSystem::Layout)
+                  .before(TransformSystem::TransformPropagate),
+               resize_canvas_images.after(UiSystem::Layout),
+            ),
+         );
+   }
+}
+
+fn key_name(key: &Key) -> Option<String> {
+   match key {
+      Key::Character(str) => Some(str.to_string()),
+      Key::Space => Some(" ".to_string()),
+      Key::Unidentified(_) | Key::Dead(_) => None,
+      key => Some(format!("{:?}", key)),
+   }
+}
+
+fn keyboard_events(
+   mut commands: Commands,
+   mut keyboard_events: EventReader<KeyboardInput>,
+   focused: Res<FocusedEntity>,
+   mut text_inputs: Query<(&mut TextInput, Option<&mut Text>)>,
+) {
+   let Some(focused) = focused.0 else {
+      keyboard_events.clear();
+      return;
+   };
+   for event in keyboard_events.read() {
+      if event.state != ButtonState::Pressed {
+         continue;
+      }
+      let Some(key) = key_name(&event.logical_key) else {
+         continue;
+      };
+      if let Ok((mut text_input, text)) = text_inputs.get_mut(focused) {
+         let changed = match &event.logical_key {
+            Key::Character(_) | Key::Space => {
+               text_input.value.push_str(&key);
+               true
+            }
+            Key::Backspace => text_input.value.pop().is_some(),
+            _ => false,
+         };
+         if changed {
+            if let Some(text) = text {
+               // A text input created without content has no section yet
+               let sections = &mut text.into_inner().sections;
+               match sections.first_mut() {
+                  Some(section) => section.value.clone_from(&text_input.value),
+                  None => sections.push(TextSection::new(
+                     text_input.value.clone(),
+                     TextStyle::default(),
+                  )),
+               }
+            }
+            commands.trigger_ui_event(focused, InputValue(text_input.value.clone()));
+         }
+      }
+      commands.trigger_ui_event(focused, CommonKeyEvent { key: key.into() });
+   }
+}
+
+fn toggle_pressed(
+   mut commands: Commands,
+   mut query: Query<(Entity, &Interaction, &mut Toggle), Changed<Interaction>>,
+) {
+   for (entity, interaction, mut toggle) in query.iter_mut() {
+      if *interaction != Interaction::Pressed {
+         continue;
+      }
+      toggle.checked = !toggle.checked;
+      let checked = toggle.checked;
+      #[cfg(feature = "style")]
+      commands.add(move |world: &mut bevy_ecs::world::World| {
+         use crate::style::StyleStatesEntityWorldMutExt;
+         if let Some(mut entity_world_mut) = world.get_entity_mut(entity) {
+            entity_world_mut.set_style_states(rxy_core::style::StyleInteraction::Checked, checked);
+         }
+      });
+      commands.trigger_ui_event(entity, ToggleValue(checked));
+   }
+}
+
+fn wheel_scroll(
+   mut wheel_events: EventReader<Pointer<Wheel>>,
+   parents: Query<&Parent>,
+   mut scroll_offsets: Query<&mut ScrollOffset>,
+) {
+   for wheel in wheel_events.read() {
+      let delta = match wheel.unit {
+         MouseScrollUnit::Line => wheel.delta.y * SCROLL_LINE_HEIGHT,
+         MouseScrollUnit::Pixel => wheel.delta.y,
+      };
+      // The nearest scrollable ancestor of the hovered node
+      let mut entity = wheel.target;
+      loop {
+         if let Ok(mut scroll_offset) = scroll_offsets.get_mut(entity) {
+            scroll_offset.y -= delta;
+            break;
+         }
+         match parents.get(entity) {
+            Ok(parent) => entity = parent.get(),
+            Err(_) => break,
+         }
+      }
+   }
+}
+
+/// The layout places the children from scratch, so the offset is applied again every frame.
+fn apply_scroll_offset(
+   mut scroll_views: Query<(&mut ScrollOffset, &Node, &Children)>,
+   mut children_query: Query<(&mut Transform, &Node), With<Parent>>,
+) {
+   for (mut scroll_offset, node, children) in scroll_views.iter_mut() {
+      let height = node.size().y;
+      // Child translations are relative to the center of the parent
+      let content_height = children
+         .iter()
+         .filter_map(|child| children_query.get(*child).ok())
+         .map(|(transform, child_node)| {
+            transform.translation.y + 0.5 * (height + child_node.size().y)
+         })
+         .fold(0., f32::max);
+      let max_offset = (content_height - height).max(0.);
+      let offset = scroll_offset.y.clamp(0., max_offset);
+      if scroll_offset.y != offset {
+         scroll_offset.y = offset;
+      }
+      if offset == 0. {
+         continue;
+      }
+      for child in children.iter() {
+         if let Ok((mut transform, _)) = children_query.get_mut(*child) {
+            transform.translation.y -= offset;
+         }
+      }
+   }
+}
+
+fn resize_canvas_images(
+   mut images: ResMut<Assets<Image>>,
+   mut canvases: Query<(&mut Canvas, &mut UiImage, &Node), Changed<Node>>,
+) {
+   for (mut canvas, mut ui_image, node) in canvases.iter_mut() {
+      let size = node.size().as_uvec2();
+      if size.x == 0 || size.y == 0 {
+         continue;
+      }
+      let size = Extent3d {
+         width: size.x,
+         height: size.y,
+         depth_or_array_layers: 1,
+      };
+      if let Some(image) = canvas.image_mut(&mut images) {
+         if image.texture_descriptor.size == size {
+            continue;
+         }
+         image.resize(size);
+         image.data.fill(0);
+      } else {
+         let image = Image::new_fill(
+            size,
+            TextureDimension::D2,
+            &[0, 0, 0, 0],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+         );
+         let handle = images.add(image);
+         ui_image.texture = handle.clone();
+         canvas.image = Some(handle);
+      }
+      // Redraw on the new pixels
+      canvas.set_changed();
+   }
+}
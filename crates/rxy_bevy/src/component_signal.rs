@@ -0,0 +1,160 @@
+use std::sync::{Arc, Weak};
+
+use bevy_app::PreUpdate;
+use bevy_ecs::prelude::{Component, Entity, Query, Ref, Res, ResMut, Resource, World};
+use bevy_ecs::schedule::IntoSystemConfigs;
+use xy_reactive::prelude::{
+   create_effect, use_rw_signal, ReadSignal, RwSignal, SignalGet, SignalSet, SignalWithUntracked,
+};
+
+use rxy_core::{DeferredNodeTreeScoped, InnerSchemaCtx, NodeTree};
+
+use crate::{add_system, BevyRenderer, SchemaCtx};
+
+struct ComponentSignalBinding<C: Component> {
+   entity: Entity,
+   signal: RwSignal<Option<C>>,
+   guard: Weak<()>,
+}
+
+#[derive(Resource)]
+struct ComponentSignals<C: Component> {
+   bindings: Vec<ComponentSignalBinding<C>>,
+}
+
+impl<C> ComponentSignals<C>
+where
+   C: Component + Clone + PartialEq,
+{
+   fn system_sync(mut signals: ResMut<Self>, query: Query<Option<Ref<C>>>) {
+      signals.bindings.retain(|binding| {
+         if binding.guard.strong_count() == 0 {
+            return false;
+         }
+         let Ok(component) = query.get(binding.entity) else {
+            // The entity is despawned, the binding will never be updated again
+            binding.signal.set(None);
+            return false;
+         };
+         match component {
+            Some(component) => {
+               if component.is_changed()
+                  && binding
+                     .signal
+                     .with_untracked(|n| n.as_ref() != Some(&*component))
+               {
+                  binding.signal.set(Some(component.clone()));
+               }
+            }
+            None => {
+               if binding.signal.with_untracked(|n| n.is_some()) {
+                  binding.signal.set(None);
+               }
+            }
+         }
+         true
+      });
+   }
+}
+
+fn bind_component_signal<C>(
+   ctx: &mut InnerSchemaCtx<BevyRenderer>,
+   entity: Entity,
+) -> RwSignal<Option<C>>
+where
+   C: Component + Clone + PartialEq,
+{
+   let signal = use_rw_signal(ctx.world.get::<C>(entity).cloned());
+   // The binding is dropped with the schema: it lives as long as this guard
+   let guard = Arc::new(());
+   let weak_guard = Arc::downgrade(&guard);
+   ctx.add_drop_guard(guard);
+
+   let is_add_system = ctx.world.contains_resource::<ComponentSignals<C>>();
+   ctx.world
+      .get_resource_or_insert_with(|| ComponentSignals::<C> { bindings: vec![] })
+      .bindings
+      .push(ComponentSignalBinding {
+         entity,
+         signal,
+         guard: weak_guard,
+      });
+   if !is_add_system {
+      add_system(
+         ctx.world,
+         PreUpdate,
+         ComponentSignals::<C>::system_sync
+            .run_if(|signals: Res<ComponentSignals<C>>| !signals.bindings.is_empty()),
+      );
+   }
+   signal
+}
+
+fn write_component<C>(world: &mut World, entity: Entity, value: Option<C>)
+where
+   C: Component + PartialEq,
+{
+   let Some(mut entity_world_mut) = world.get_entity_mut(entity) else {
+      return;
+   };
+   match value {
+      Some(value) => match entity_world_mut.get_mut::<C>() {
+         Some(mut component) => {
+            if *component != value {
+               *component = value;
+            }
+         }
+         None => {
+            entity_world_mut.insert(value);
+         }
+      },
+      None => {
+         if entity_world_mut.contains::<C>() {
+            entity_world_mut.remove::<C>();
+         }
+      }
+   }
+}
+
+pub trait ComponentSignalSchemaCtxExt {
+   /// A signal of the component `C` of `entity`, `None` while the entity does not have it.
+   ///
+   /// The signal is updated when the component changes, and set to `None` once the entity is
+   /// despawned.
+   fn use_component_signal<C>(&mut self, entity: Entity) -> ReadSignal<Option<C>>
+   where
+      C: Component + Clone + PartialEq;
+
+   /// Same as [`use_component_signal`](Self::use_component_signal), and values set to the signal
+   /// are written back into the world. Setting `None` removes the component.
+   fn use_component_signal_rw<C>(&mut self, entity: Entity) -> RwSignal<Option<C>>
+   where
+      C: Component + Clone + PartialEq;
+}
+
+impl ComponentSignalSchemaCtxExt for SchemaCtx {
+   fn use_component_signal<C>(&mut self, entity: Entity) -> ReadSignal<Option<C>>
+   where
+      C: Component + Clone + PartialEq,
+   {
+      self.mut_scoped(|ctx| bind_component_signal::<C>(ctx, entity).read_only())
+   }
+
+   fn use_component_signal_rw<C>(&mut self, entity: Entity) -> RwSignal<Option<C>>
+   where
+      C: Component + Clone + PartialEq,
+   {
+      self.mut_scoped(|ctx| {
+         let signal = bind_component_signal::<C>(ctx, entity);
+         let world_scoped = ctx.world.world_scoped();
+         let write_effect = create_effect(move |prev: Option<()>| {
+            let value = signal.get();
+            if prev.is_some() {
+               world_scoped.scoped(move |world| write_component(world, entity, value));
+            }
+         });
+         ctx.effect_state().push(write_effect.erase());
+         signal
+      })
+   }
+}
@@ -0,0 +1,211 @@
+use std::any::{Any, TypeId};
+use std::sync::Arc;
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::prelude::{Component, Entity, Res, ResMut, Resource, World};
+use bevy_ecs::schedule::IntoSystemConfigs;
+use bevy_hierarchy::{Children, DespawnRecursiveExt};
+use bevy_math::Vec2;
+use bevy_mod_picking::prelude::Pickable;
+use bevy_reflect::Reflect;
+use bevy_ui::node_bundles::NodeBundle;
+use bevy_ui::{PositionType, Style, Val, ZIndex};
+#[cfg(feature = "xy_reactive")]
+use xy_reactive::prelude::{SignalSet, WriteSignal};
+
+use rxy_core::IntoView;
+
+use crate::{BevyRenderer, RxyViewSpawner};
+
+pub struct DragDropPlugin;
+
+impl Plugin for DragDropPlugin {
+   fn build(&self, app: &mut App) {
+      app.register_type::<DropHoverState>()
+         .init_resource::<DragDropState>()
+         .add_systems(
+            Last,
+            clear_ended_drag.run_if(|state: Res<DragDropState>| {
+               state.active.as_ref().map_or(false, |n| n.ended)
+            }),
+         );
+   }
+}
+
+pub(crate) type DragPreviewFn = Arc<dyn Fn(&mut World, Entity, &dyn Any) + Send + Sync>;
+
+pub(crate) fn drag_preview_fn<T, IV>(f: impl Fn(&T) -> IV + Send + Sync + 'static) -> DragPreviewFn
+where
+   T: 'static,
+   IV: IntoView<BevyRenderer>,
+{
+   Arc::new(move |world, container, payload| {
+      let Some(payload) = payload.downcast_ref::<T>() else {
+         return;
+      };
+      world.spawn_view(f(payload), move |_| container);
+   })
+}
+
+pub struct ActiveDrag {
+   pub source: Entity,
+   pub position: Vec2,
+   pub start_position: Vec2,
+   type_id: TypeId,
+   payload: Box<dyn Any + Send + Sync>,
+   preview: Option<Entity>,
+   ended: bool,
+}
+
+/// The payload that is currently dragged by a [`Draggable`](crate::Draggable) member.
+#[derive(Resource, Default)]
+pub struct DragDropState {
+   active: Option<ActiveDrag>,
+}
+
+impl DragDropState {
+   pub fn active(&self) -> Option<&ActiveDrag> {
+      self.active.as_ref().filter(|n| !n.ended)
+   }
+
+   pub fn is_dragging(&self) -> bool {
+      self.active().is_some()
+   }
+
+   pub fn is_dragging_type<T: 'static>(&self) -> bool {
+      self
+         .active()
+         .map_or(false, |n| n.type_id == TypeId::of::<T>())
+   }
+
+   pub fn payload<T: 'static>(&self) -> Option<&T> {
+      self.active()?.payload.downcast_ref::<T>()
+   }
+}
+
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DropHoverState {
+   #[default]
+   None,
+   /// A payload the target accepts is dragged over it
+   Accept,
+   /// A payload the target rejects is dragged over it
+   Reject,
+}
+
+/// The hover state of a drop target, usable for hover styling.
+#[derive(Component, Default)]
+pub struct DropTargetHover {
+   pub state: DropHoverState,
+   #[cfg(feature = "xy_reactive")]
+   pub(crate) signal: Option<WriteSignal<DropHoverState>>,
+}
+
+pub(crate) fn set_drop_hover(world: &mut World, entity: Entity, state: DropHoverState) {
+   let Some(mut hover) = world.get_mut::<DropTargetHover>(entity) else {
+      return;
+   };
+   if hover.state == state {
+      return;
+   }
+   hover.state = state;
+   #[cfg(feature = "xy_reactive")]
+   if let Some(signal) = hover.signal {
+      signal.set(state);
+   }
+}
+
+pub(crate) fn start_drag<T>(
+   world: &mut World,
+   source: Entity,
+   payload: T,
+   preview: Option<DragPreviewFn>,
+   position: Vec2,
+) where
+   T: Send + Sync + 'static,
+{
+   end_drag(world);
+
+   let preview = preview.map(|preview| {
+      let container = world
+         .spawn((
+            NodeBundle {
+               style: Style {
+                  position_type: PositionType::Absolute,
+                  left: Val::Px(position.x),
+                  top: Val::Px(position.y),
+                  ..Default::default()
+               },
+               z_index: ZIndex::Global(i32::MAX),
+               ..Default::default()
+            },
+            Pickable::IGNORE,
+         ))
+         .id();
+      preview(world, container, &payload);
+      ignore_picking_recursive(world, container);
+      container
+   });
+
+   world.resource_mut::<DragDropState>().active = Some(ActiveDrag {
+      source,
+      position,
+      start_position: position,
+      type_id: TypeId::of::<T>(),
+      payload: Box::new(payload),
+      preview,
+      ended: false,
+   });
+}
+
+/// The preview must not be hit by the pointer, otherwise drop targets under it never get hovered
+fn ignore_picking_recursive(world: &mut World, entity: Entity) {
+   world.entity_mut(entity).insert(Pickable::IGNORE);
+   let children = world
+      .get::<Children>(entity)
+      .map(|n| n.to_vec())
+      .unwrap_or_default();
+   for child in children {
+      ignore_picking_recursive(world, child);
+   }
+}
+
+pub(crate) fn move_drag(world: &mut World, position: Vec2) {
+   let mut state = world.resource_mut::<DragDropState>();
+   let Some(active) = state.active.as_mut() else {
+      return;
+   };
+   active.position = position;
+   let Some(preview) = active.preview else {
+      return;
+   };
+   if let Some(mut style) = world.get_mut::<Style>(preview) {
+      style.left = Val::Px(position.x);
+      style.top = Val::Px(position.y);
+   }
+}
+
+/// The payload stays readable by drop targets until the end of the frame.
+pub(crate) fn end_drag(world: &mut World) {
+   let mut state = world.resource_mut::<DragDropState>();
+   let Some(active) = state.active.as_mut() else {
+      return;
+   };
+   active.ended = true;
+   let Some(preview) = active.preview.take() else {
+      return;
+   };
+   if let Some(entity_world_mut) = world.get_entity_mut(preview) {
+      entity_world_mut.despawn_recursive();
+   }
+}
+
+fn clear_ended_drag(mut state: ResMut<DragDropState>) {
+   state.active = None;
+}
+
+pub(crate) fn take_payload<T: Clone + 'static>(world: &World) -> Option<(T, Entity, Vec2)> {
+   let active = world.resource::<DragDropState>().active.as_ref()?;
+   let payload = active.payload.downcast_ref::<T>()?;
+   Some((payload.clone(), active.source, active.position))
+}
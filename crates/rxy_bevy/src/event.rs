@@ -5,26 +5,36 @@ use core::{
    ops::{Deref, DerefMut},
 };
 use std::iter::once;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use bevy_app::PreUpdate;
 use bevy_ecs::entity::EntityHashMap;
 use bevy_ecs::{
-   prelude::{Commands, Res, Resource, World},
-   system::SystemId,
+   prelude::{
+      Commands, Entity, EventReader, IntoSystem, Observer, Res, ResMut, Resource, Trigger, World,
+   },
+   schedule::IntoSystemConfigs,
+   system::{BoxedSystem, SystemId},
 };
 use bevy_input::{
    gamepad::GamepadButton, keyboard::KeyCode, mouse::MouseButton, ButtonInput, InputSystem,
 };
+use bevy_math::Vec2;
+use bevy_mod_picking::picking_core::PickSet;
 use bevy_mod_picking::prelude::*;
 use bevy_reflect::Reflect;
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::Node;
 use bevy_utils::tracing::error;
 use bevy_utils::{all_tuples, HashMap};
 
 use rxy_core::{NodeTree, RendererNodeId, RendererWorld};
 
-use crate::world_ext::EntityWorldMutExt;
-use crate::{add_system, BevyRenderer, FocusedEntity};
+use crate::{
+   add_system, BevyRenderer, ContextMenu, DoubleClick, FocusedEntity, KeyModifiers, LongPress, Pan,
+   Pinch, Rotate, Swipe, Tap, UiEvent, UiEventCommandsExt, UiEventId, Wheel,
+};
 
 fn add_focus_event<T>(
    world: &mut RendererWorld<BevyRenderer>,
@@ -36,8 +46,6 @@ fn add_focus_event<T>(
    T: Copy + Eq + Hash + Send + Sync + 'static,
    FocusInputEvents<T>: Resource,
 {
-   use bevy_ecs::schedule::IntoSystemConfigs;
-
    let is_add_system = world.contains_resource::<FocusInputEvents<T>>();
 
    let mut focus_input_events = world.get_resource_or_insert_with(FocusInputEvents::<T>::default);
@@ -62,6 +70,13 @@ fn add_focus_event<T>(
 pub trait EventIsMatch {
    type Data: Clone + Send + Sync + 'static;
    fn is_match(&self, other: &Self::Data) -> bool;
+
+   /// The pointer position in window coordinates.
+   fn position(&self) -> Vec2;
+
+   fn button(&self) -> Option<PointerButton> {
+      None
+   }
 }
 
 macro_rules! impl_event_is_match_empty_data {
@@ -73,6 +88,10 @@ macro_rules! impl_event_is_match_empty_data {
             fn is_match(&self, _other: &Self::Data) -> bool {
                 true
             }
+
+            fn position(&self) -> Vec2 {
+                self.pointer_location.position
+            }
         }
         )*
     };
@@ -86,13 +105,22 @@ macro_rules! impl_event_is_match_pointer_button {
             fn is_match(&self, other: &Self::Data) -> bool {
                 &self.button == other
             }
+
+            fn position(&self) -> Vec2 {
+                self.pointer_location.position
+            }
+
+            fn button(&self) -> Option<PointerButton> {
+                Some(self.button)
+            }
         }
         )*
     };
 }
 
 impl_event_is_match_empty_data! {
-    Pointer<Over> Pointer<Out> Pointer<Move>
+    Pointer<Over> Pointer<Out> Pointer<Move> Pointer<Wheel> Pointer<ContextMenu>
+    Pointer<Tap> Pointer<Pan> Pointer<Pinch> Pointer<Rotate> Pointer<Swipe>
 }
 
 impl_event_is_match_pointer_button! {
@@ -106,6 +134,8 @@ impl_event_is_match_pointer_button! {
     Pointer<DragOver>
     Pointer<DragLeave>
     Pointer<Drop>
+    Pointer<DoubleClick>
+    Pointer<LongPress>
 }
 
 fn remove_bubble_event<T>(
@@ -130,41 +160,265 @@ fn add_bubble_event<T>(
 ) where
    T: EntityEvent + EventIsMatch,
 {
-   let mut entity_world_mut = world.entity_mut(node_id);
-   if entity_world_mut.contains::<On<T>>() {
-      let system_ids = entity_world_mut.get_or_default::<BubbleEventSystemIds<T>>();
+   if let Some(system_ids) = world.get_node_state_mut::<BubbleEventSystemIds<T>>(&node_id) {
       system_ids.push((system_id, data));
-   } else {
-      entity_world_mut.world_scope(|world| {
-         world.set_node_state(
-            &node_id,
-            BubbleEventSystemIds::<T>::new(smallvec::SmallVec::from_elem((system_id, data), 1)),
-         );
-      });
-      entity_world_mut.insert(On::<T>::run(move |world: &mut World| {
-         let mut listerner = world.resource_mut::<ListenerInput<T>>();
-         if stop_propagation {
-            listerner.stop_propagation();
-         }
+      return;
+   }
+   world.set_node_state(
+      &node_id,
+      BubbleEventSystemIds::<T>::new(smallvec::SmallVec::from_elem((system_id, data), 1)),
+   );
 
-         let event_data: T = ListenerInput::deref(&*listerner).clone();
-         world.node_state_scoped(
-            &node_id,
-            |world, system_ids: &mut BubbleEventSystemIds<T>| {
-               for (system_id, data) in system_ids.iter() {
-                  if let Some(data) = data {
-                     if !event_data.is_match(data) {
-                        return;
-                     }
-                  }
-                  let err = world.run_system(*system_id);
-                  if let Err(err) = err {
-                     error!("run system error: {:?}", err);
-                  }
+   if !world.contains_resource::<PointerEventTrigger<T>>() {
+      world.insert_resource(PointerEventTrigger::<T>(PhantomData));
+      add_system(
+         world,
+         PreUpdate,
+         trigger_pointer_events::<T>.in_set(PickSet::Last),
+      );
+   }
+
+   // Despawned with the node
+   world.spawn(
+      Observer::new(
+         move |trigger: Trigger<UiEvent<T>>, mut commands: Commands| {
+            let event = trigger.event().clone();
+            commands.add(move |world: &mut World| {
+               run_bubble_event_handlers(world, node_id, event, stop_propagation)
+            });
+         },
+      )
+      .with_entity(node_id),
+   );
+}
+
+/// Marks the pointer events of type `T` as forwarded to the [`UiEvent`] observers.
+#[derive(Resource)]
+struct PointerEventTrigger<T: Send + Sync + 'static>(PhantomData<T>);
+
+fn trigger_pointer_events<T>(mut events: EventReader<T>, mut commands: Commands)
+where
+   T: EntityEvent,
+{
+   for event in events.read() {
+      commands.trigger_ui_event(event.target(), event.clone());
+   }
+}
+
+fn run_bubble_event_handlers<T>(
+   world: &mut World,
+   node_id: Entity,
+   event: UiEvent<T>,
+   stop_propagation: bool,
+) where
+   T: EntityEvent + EventIsMatch,
+{
+   let data = pointer_event_data(world, &event, node_id);
+   world.resource_mut::<PointerEventDispatch>().current = Some(data.clone());
+   world.insert_resource(PointerInput(event.event.clone()));
+
+   world.node_state_scoped(
+      &node_id,
+      |world, system_ids: &mut BubbleEventSystemIds<T>| {
+         for (system_id, match_data) in system_ids.iter() {
+            if let Some(match_data) = match_data {
+               if !event.is_match(match_data) {
+                  continue;
                }
-            },
-         );
-      }));
+            }
+            let err = world.run_system(*system_id);
+            if let Err(err) = err {
+               error!("run system error: {:?}", err);
+            }
+         }
+      },
+   );
+
+   world.remove_resource::<PointerInput<T>>();
+   world.resource_mut::<PointerEventDispatch>().current = None;
+   if stop_propagation || data.is_propagation_stopped() {
+      event.propagate(false);
+   }
+}
+
+fn pointer_event_data<T>(
+   world: &mut World,
+   event: &UiEvent<T>,
+   current_target: Entity,
+) -> PointerEventData
+where
+   T: EntityEvent + EventIsMatch,
+{
+   let target = event.target;
+   // Shared by every listener the event bubbles through
+   let flags = world
+      .resource_mut::<PointerEventDispatch>()
+      .flags
+      .entry(event.id)
+      .or_default()
+      .clone();
+   let position = event.position();
+   let local_position = match (
+      world.get::<Node>(current_target),
+      world.get::<GlobalTransform>(current_target),
+   ) {
+      (Some(node), Some(transform)) => {
+         position - (transform.translation().truncate() - node.size() / 2.)
+      }
+      _ => position,
+   };
+   PointerEventData {
+      event_id: event.id,
+      target,
+      current_target,
+      position,
+      local_position,
+      button: event.button(),
+      modifiers: world
+         .get_resource::<ButtonInput<KeyCode>>()
+         .map(KeyModifiers::pressed)
+         .unwrap_or_default(),
+      flags,
+   }
+}
+
+#[derive(Debug, Default)]
+struct PointerEventFlags {
+   propagation_stopped: AtomicBool,
+   default_prevented: AtomicBool,
+}
+
+/// The pointer event a handler is running for, received by handlers taking
+/// `In<PointerEventData>`.
+#[derive(Debug, Clone)]
+pub struct PointerEventData {
+   /// The same for all the handlers of the event, as it bubbles up
+   pub event_id: UiEventId,
+   /// The entity the pointer hit
+   pub target: Entity,
+   /// The entity whose handlers are running
+   pub current_target: Entity,
+   /// The pointer position in window coordinates
+   pub position: Vec2,
+   /// The pointer position relative to the top-left corner of `current_target`
+   pub local_position: Vec2,
+   /// `None` for events that are not tied to a button, like `Over` and `Move`
+   pub button: Option<PointerButton>,
+   pub modifiers: KeyModifiers,
+   flags: Arc<PointerEventFlags>,
+}
+
+impl Default for PointerEventData {
+   fn default() -> Self {
+      Self {
+         event_id: UiEventId::default(),
+         target: Entity::PLACEHOLDER,
+         current_target: Entity::PLACEHOLDER,
+         position: Vec2::ZERO,
+         local_position: Vec2::ZERO,
+         button: None,
+         modifiers: KeyModifiers::default(),
+         flags: Default::default(),
+      }
+   }
+}
+
+impl PointerEventData {
+   /// Stops the event from bubbling to the ancestors of `current_target`.
+   pub fn stop_propagation(&self) {
+      self
+         .flags
+         .propagation_stopped
+         .store(true, Ordering::Relaxed);
+   }
+
+   pub fn is_propagation_stopped(&self) -> bool {
+      self.flags.propagation_stopped.load(Ordering::Relaxed)
+   }
+
+   /// Skips the built-in behaviors that would run later for this event, such as a
+   /// [`draggable`](crate::draggable) ancestor starting a drag.
+   pub fn prevent_default(&self) {
+      self.flags.default_prevented.store(true, Ordering::Relaxed);
+   }
+
+   pub fn is_default_prevented(&self) -> bool {
+      self.flags.default_prevented.load(Ordering::Relaxed)
+   }
+}
+
+#[derive(Resource, Default)]
+pub struct PointerEventDispatch {
+   current: Option<PointerEventData>,
+   flags: HashMap<UiEventId, Arc<PointerEventFlags>>,
+}
+
+impl PointerEventDispatch {
+   /// The event whose handlers are running, if any.
+   pub fn current(&self) -> Option<&PointerEventData> {
+      self.current.as_ref()
+   }
+
+   pub fn is_default_prevented(&self) -> bool {
+      self
+         .current
+         .as_ref()
+         .map_or(false, PointerEventData::is_default_prevented)
+   }
+
+   pub(crate) fn system_clear(mut dispatch: ResMut<Self>) {
+      dispatch.flags.clear();
+   }
+}
+
+/// The pointer event whose handlers are running, read with `Res<ListenerInputPointerClick>` and
+/// the other `ListenerInput*` aliases.
+#[derive(Resource, Debug, Clone)]
+pub struct PointerInput<T: Send + Sync + 'static>(T);
+
+impl<T> Deref for PointerInput<T>
+where
+   T: Send + Sync + 'static,
+{
+   type Target = T;
+
+   fn deref(&self) -> &Self::Target {
+      &self.0
+   }
+}
+
+fn current_pointer_event_data(dispatch: Res<PointerEventDispatch>) -> PointerEventData {
+   dispatch.current.clone().unwrap_or_default()
+}
+
+pub struct WithoutEventData;
+
+pub struct WithEventData;
+
+/// Systems usable as event handlers: either taking no input, or taking `In<PointerEventData>`.
+///
+/// Handlers of keyboard and gamepad events receive a default [`PointerEventData`].
+pub trait IntoEventHandler<Marker>: Send + 'static {
+   fn into_event_handler(self) -> BoxedSystem;
+}
+
+impl<S, M> IntoEventHandler<(WithoutEventData, M)> for S
+where
+   S: IntoSystem<(), (), M> + Send + 'static,
+{
+   fn into_event_handler(self) -> BoxedSystem {
+      Box::new(IntoSystem::into_system(self))
+   }
+}
+
+impl<S, M> IntoEventHandler<(WithEventData, M)> for S
+where
+   S: IntoSystem<PointerEventData, (), M> + Send + 'static,
+{
+   fn into_event_handler(self) -> BoxedSystem {
+      Box::new(IntoSystem::into_system(
+         current_pointer_event_data.pipe(self),
+      ))
    }
 }
 
@@ -264,6 +518,41 @@ impl FocusEventWorldExt for World {
          BubblePointerEvent::Drop(data) => {
             add_bubble_event::<Pointer<Drop>>(self, node_id, system_id, stop_propagation, data)
          }
+         BubblePointerEvent::Wheel => {
+            add_bubble_event::<Pointer<Wheel>>(self, node_id, system_id, stop_propagation, None)
+         }
+         BubblePointerEvent::DoubleClick(data) => add_bubble_event::<Pointer<DoubleClick>>(
+            self,
+            node_id,
+            system_id,
+            stop_propagation,
+            data,
+         ),
+         BubblePointerEvent::LongPress(data) => {
+            add_bubble_event::<Pointer<LongPress>>(self, node_id, system_id, stop_propagation, data)
+         }
+         BubblePointerEvent::ContextMenu => add_bubble_event::<Pointer<ContextMenu>>(
+            self,
+            node_id,
+            system_id,
+            stop_propagation,
+            None,
+         ),
+         BubblePointerEvent::Tap => {
+            add_bubble_event::<Pointer<Tap>>(self, node_id, system_id, stop_propagation, None)
+         }
+         BubblePointerEvent::Pan => {
+            add_bubble_event::<Pointer<Pan>>(self, node_id, system_id, stop_propagation, None)
+         }
+         BubblePointerEvent::Pinch => {
+            add_bubble_event::<Pointer<Pinch>>(self, node_id, system_id, stop_propagation, None)
+         }
+         BubblePointerEvent::Rotate => {
+            add_bubble_event::<Pointer<Rotate>>(self, node_id, system_id, stop_propagation, None)
+         }
+         BubblePointerEvent::Swipe => {
+            add_bubble_event::<Pointer<Swipe>>(self, node_id, system_id, stop_propagation, None)
+         }
       }
    }
 
@@ -352,6 +641,33 @@ impl FocusEventWorldExt for World {
             BubblePointerEvent::Drop(..) => {
                remove_bubble_event::<Pointer<Drop>>(self, node_id, system_id)
             }
+            BubblePointerEvent::Wheel => {
+               remove_bubble_event::<Pointer<Wheel>>(self, node_id, system_id)
+            }
+            BubblePointerEvent::DoubleClick(..) => {
+               remove_bubble_event::<Pointer<DoubleClick>>(self, node_id, system_id)
+            }
+            BubblePointerEvent::LongPress(..) => {
+               remove_bubble_event::<Pointer<LongPress>>(self, node_id, system_id)
+            }
+            BubblePointerEvent::ContextMenu => {
+               remove_bubble_event::<Pointer<ContextMenu>>(self, node_id, system_id)
+            }
+            BubblePointerEvent::Tap => {
+               remove_bubble_event::<Pointer<Tap>>(self, node_id, system_id)
+            }
+            BubblePointerEvent::Pan => {
+               remove_bubble_event::<Pointer<Pan>>(self, node_id, system_id)
+            }
+            BubblePointerEvent::Pinch => {
+               remove_bubble_event::<Pointer<Pinch>>(self, node_id, system_id)
+            }
+            BubblePointerEvent::Rotate => {
+               remove_bubble_event::<Pointer<Rotate>>(self, node_id, system_id)
+            }
+            BubblePointerEvent::Swipe => {
+               remove_bubble_event::<Pointer<Swipe>>(self, node_id, system_id)
+            }
          },
       }
    }
@@ -439,6 +755,15 @@ pub enum BubblePointerEvent {
    DragOver(Option<PointerButton>),
    DragLeave(Option<PointerButton>),
    Drop(Option<PointerButton>),
+   Wheel,
+   DoubleClick(Option<PointerButton>),
+   LongPress(Option<PointerButton>),
+   ContextMenu,
+   Tap,
+   Pan,
+   Pinch,
+   Rotate,
+   Swipe,
 }
 
 impl BubblePointerEvent {
@@ -660,7 +985,7 @@ macro_rules! impl_element_pointer_events_alias {
         $(
            pub type $name = $event_type;
            paste::paste!{
-               pub type [<ListenerInput $name>] = ListenerInput<$name>;
+               pub type [<ListenerInput $name>] = PointerInput<$name>;
            }
         )*
     };
@@ -679,6 +1004,15 @@ impl_element_pointer_events_alias!(
     PointerDragOver = Pointer<DragOver>;
     PointerDragLeave = Pointer<DragLeave>;
     PointerDrop = Pointer<Drop>;
+    PointerWheel = Pointer<Wheel>;
+    PointerDoubleClick = Pointer<DoubleClick>;
+    PointerLongPress = Pointer<LongPress>;
+    PointerContextMenu = Pointer<ContextMenu>;
+    PointerTap = Pointer<Tap>;
+    PointerPan = Pointer<Pan>;
+    PointerPinch = Pointer<Pinch>;
+    PointerRotate = Pointer<Rotate>;
+    PointerSwipe = Pointer<Swipe>;
 );
 
 pub fn x_trigger_way(
@@ -754,3 +1088,39 @@ pub fn x_pointer_drag_leave() -> BubblePointerEvent {
 pub fn x_pointer_drop() -> BubblePointerEvent {
    BubblePointerEvent::Drop(None)
 }
+
+pub fn x_pointer_wheel() -> BubblePointerEvent {
+   BubblePointerEvent::Wheel
+}
+
+pub fn x_pointer_double_click() -> BubblePointerEvent {
+   BubblePointerEvent::DoubleClick(None)
+}
+
+pub fn x_pointer_long_press() -> BubblePointerEvent {
+   BubblePointerEvent::LongPress(None)
+}
+
+pub fn x_context_menu() -> BubblePointerEvent {
+   BubblePointerEvent::ContextMenu
+}
+
+pub fn x_tap() -> BubblePointerEvent {
+   BubblePointerEvent::Tap
+}
+
+pub fn x_pan() -> BubblePointerEvent {
+   BubblePointerEvent::Pan
+}
+
+pub fn x_pinch() -> BubblePointerEvent {
+   BubblePointerEvent::Pinch
+}
+
+pub fn x_rotate() -> BubblePointerEvent {
+   BubblePointerEvent::Rotate
+}
+
+pub fn x_swipe() -> BubblePointerEvent {
+   BubblePointerEvent::Swipe
+}
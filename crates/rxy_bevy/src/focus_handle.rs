@@ -0,0 +1,139 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use bevy_ecs::prelude::{Component, Entity, Query, Res, World};
+use xy_reactive::prelude::{use_rw_signal, ReadSignal, RwSignal, SignalGetUntracked, SignalSet};
+
+use rxy_core::{ViewMember, ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin};
+
+use crate::{focus_entity, BevyRenderer, CmdSender, FocusedEntity, SchemaCtx};
+
+/// A handle to the focus state of an element.
+///
+/// The handle is bound to an element by adding it as a member, e.g. `button().member(focus.clone())`.
+#[derive(Clone)]
+pub struct FocusHandle {
+   entity: RwSignal<Option<Entity>>,
+   is_focused: RwSignal<bool>,
+   pending_focus: Arc<AtomicBool>,
+   cmd_sender: CmdSender,
+}
+
+impl FocusHandle {
+   pub fn new(world: &World) -> Self {
+      Self {
+         entity: use_rw_signal(None),
+         is_focused: use_rw_signal(false),
+         pending_focus: Default::default(),
+         cmd_sender: world.resource::<CmdSender>().clone(),
+      }
+   }
+
+   #[inline]
+   pub fn is_focused(&self) -> ReadSignal<bool> {
+      self.is_focused.read_only()
+   }
+
+   #[inline]
+   pub fn entity(&self) -> ReadSignal<Option<Entity>> {
+      self.entity.read_only()
+   }
+
+   /// Focus the bound element. If the handle is not bound yet, the element is focused once it is built.
+   ///
+   /// Like tab navigation, this does nothing while a trapping [`FocusScope`](crate::FocusScope)
+   /// that doesn't contain the element is active.
+   pub fn focus(&self) {
+      let entity = self.entity;
+      let pending_focus = self.pending_focus.clone();
+      self
+         .cmd_sender
+         .add(move |world: &mut World| match entity.get_untracked() {
+            Some(entity) => {
+               focus_entity(world, entity);
+            }
+            None => pending_focus.store(true, Ordering::Relaxed),
+         });
+   }
+
+   pub fn blur(&self) {
+      let entity = self.entity;
+      self.pending_focus.store(false, Ordering::Relaxed);
+      self.cmd_sender.add(move |world: &mut World| {
+         let Some(entity) = entity.get_untracked() else {
+            return;
+         };
+         let mut focus = world.resource_mut::<FocusedEntity>();
+         if focus.0 == Some(entity) {
+            focus.0 = None;
+         }
+      });
+   }
+}
+
+pub trait FocusSchemaCtxExt {
+   fn use_focus(&mut self) -> FocusHandle;
+}
+
+impl FocusSchemaCtxExt for SchemaCtx {
+   #[inline]
+   fn use_focus(&mut self) -> FocusHandle {
+      self.world_ref_scoped(|world| FocusHandle::new(world))
+   }
+}
+
+#[derive(Component)]
+pub(crate) struct FocusHandleBinding(RwSignal<bool>);
+
+pub(crate) fn sync_focus_handles(
+   focus: Res<FocusedEntity>,
+   query: Query<(Entity, &FocusHandleBinding)>,
+) {
+   for (entity, binding) in query.iter() {
+      let is_focused = focus.0 == Some(entity);
+      if binding.0.get_untracked() != is_focused {
+         binding.0.set(is_focused);
+      }
+   }
+}
+
+impl ViewMemberOrigin<BevyRenderer> for FocusHandle {
+   type Origin = Self;
+}
+
+impl ViewMember<BevyRenderer> for FocusHandle {
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(ctx: ViewMemberCtx<BevyRenderer>, view_removed: bool) {
+      let entity = ctx.node_id;
+      let Some(binding) = ctx.world.get::<FocusHandleBinding>(entity) else {
+         return;
+      };
+      binding.0.set(false);
+      if !view_removed {
+         ctx.world.entity_mut(entity).remove::<FocusHandleBinding>();
+      }
+   }
+
+   fn build(self, ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      let entity = ctx.node_id;
+      let is_focused = ctx.world.resource::<FocusedEntity>().0 == Some(entity);
+      self.entity.set(Some(entity));
+      self.is_focused.set(is_focused);
+      ctx.world
+         .entity_mut(entity)
+         .insert(FocusHandleBinding(self.is_focused));
+      // The other members, such as `Focusable`, may not be built yet
+      if self.pending_focus.swap(false, Ordering::Relaxed) {
+         self.cmd_sender.add(move |world: &mut World| {
+            focus_entity(world, entity);
+         });
+      }
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      self.build(ctx, true);
+   }
+}
@@ -1,11 +1,13 @@
 use bevy_app::{Plugin, PostUpdate, PreUpdate};
 use bevy_ecs::prelude::{
-   DetectChangesMut, Entity, Local, Query, RemovedComponents, Res, ResMut, Resource,
+   Added, DetectChanges, DetectChangesMut, Entity, Local, Query, Ref, RemovedComponents, Res,
+   ResMut, Resource, With, World,
 };
 use bevy_ecs::{
    component::Component, prelude::resource_changed, schedule::IntoSystemConfigs,
    system::SystemParam,
 };
+use bevy_hierarchy::{Children, Parent};
 use bevy_reflect::Reflect;
 use bevy_ui::UiSystem;
 
@@ -17,7 +19,10 @@ pub struct FocusablePlugin;
 impl Plugin for FocusablePlugin {
    fn build(&self, app: &mut bevy_app::App) {
       app.register_type::<Focusable>()
+         .register_type::<FocusScope>()
+         .register_type::<AutoFocus>()
          .init_resource::<FocusedEntity>()
+         .init_resource::<FocusScopeStack>()
          .add_systems(
             PreUpdate,
             FocusableSystemParam::update_focused_state
@@ -27,8 +32,196 @@ impl Plugin for FocusablePlugin {
          )
          .add_systems(
             PostUpdate,
-            check_focus.run_if(|removed: RemovedComponents<Focusable>| !removed.is_empty()),
+            (
+               check_focus.run_if(|removed: RemovedComponents<Focusable>| !removed.is_empty()),
+               pop_focus_scopes
+                  .run_if(|removed: RemovedComponents<FocusScope>| !removed.is_empty()),
+               push_focus_scopes,
+               autofocus.run_if(|query: Query<(), Added<AutoFocus>>| !query.is_empty()),
+            )
+               .chain(),
          );
+
+      #[cfg(feature = "xy_reactive")]
+      app.add_systems(
+         PreUpdate,
+         crate::sync_focus_handles
+            .after(FocusableSystemParam::update_focused_state)
+            .run_if(resource_changed::<FocusedEntity>),
+      );
+   }
+}
+
+/// Marks the root of a focus scope.
+///
+/// While a trapping scope is the top-most one, <kbd>tab</kbd> navigation only cycles through its descendants.
+/// When the scope is removed, the focus is moved back to the entity that was focused when it was added.
+#[derive(Reflect, Component, Clone, Debug, Eq, PartialEq)]
+pub struct FocusScope {
+   pub trap: bool,
+   pub restore_focus: bool,
+}
+
+impl Default for FocusScope {
+   fn default() -> Self {
+      Self {
+         trap: true,
+         restore_focus: true,
+      }
+   }
+}
+
+/// Focuses the entity as soon as it is built.
+#[derive(Reflect, Component, Clone, Debug, Default, Eq, PartialEq)]
+pub struct AutoFocus;
+
+#[derive(Debug, Clone)]
+struct FocusScopeEntry {
+   entity: Entity,
+   scope: FocusScope,
+   restore_to: Option<Entity>,
+}
+
+/// The active focus scopes, in the order they were added.
+#[derive(Resource, Default, Debug)]
+pub struct FocusScopeStack {
+   scopes: Vec<FocusScopeEntry>,
+}
+
+impl FocusScopeStack {
+   /// The top-most scope that traps the focus.
+   pub fn trap_scope(&self) -> Option<Entity> {
+      self
+         .scopes
+         .iter()
+         .rev()
+         .find(|n| n.scope.trap)
+         .map(|n| n.entity)
+   }
+
+   pub fn scopes(&self) -> impl Iterator<Item = Entity> + '_ {
+      self.scopes.iter().map(|n| n.entity)
+   }
+
+   /// Whether the entity is the scope entity or one of its descendants.
+   pub fn is_in_scope(scope: Entity, mut entity: Entity, parents: &Query<&Parent>) -> bool {
+      loop {
+         if entity == scope {
+            return true;
+         }
+         match parents.get(entity) {
+            Ok(parent) => entity = parent.get(),
+            Err(_) => return false,
+         }
+      }
+   }
+}
+
+/// Focuses the entity, unless it is not focusable or the active trapping scope doesn't contain it.
+///
+/// Returns whether the entity is focused afterwards.
+pub fn focus_entity(world: &mut World, entity: Entity) -> bool {
+   if world.get::<Focusable>(entity).is_none() {
+      return false;
+   }
+   if let Some(scope) = world.resource::<FocusScopeStack>().trap_scope() {
+      let mut current = entity;
+      while current != scope {
+         match world.get::<Parent>(current) {
+            Some(parent) => current = parent.get(),
+            None => return false,
+         }
+      }
+   }
+   let mut focus = world.resource_mut::<FocusedEntity>();
+   if focus.0 != Some(entity) {
+      focus.0 = Some(entity);
+   }
+   true
+}
+
+/// The scope entity or its first focusable descendant, depth first.
+fn first_focusable(
+   entity: Entity,
+   children: &Query<&Children>,
+   focusables: &Query<(), With<Focusable>>,
+) -> Option<Entity> {
+   if focusables.contains(entity) {
+      return Some(entity);
+   }
+   children
+      .get(entity)
+      .ok()?
+      .iter()
+      .find_map(|child| first_focusable(*child, children, focusables))
+}
+
+fn push_focus_scopes(
+   query: Query<(Entity, Ref<FocusScope>)>,
+   mut focus: ResMut<FocusedEntity>,
+   mut stack: ResMut<FocusScopeStack>,
+   parents: Query<&Parent>,
+   children: Query<&Children>,
+   focusables: Query<(), With<Focusable>>,
+) {
+   for (entity, scope) in query.iter() {
+      if scope.is_added() {
+         stack.scopes.push(FocusScopeEntry {
+            entity,
+            scope: scope.clone(),
+            restore_to: focus.0,
+         });
+         // The focus is moved into a trapping scope, where tab navigation can't reach it otherwise
+         let is_focus_inside = focus
+            .0
+            .is_some_and(|focused| FocusScopeStack::is_in_scope(entity, focused, &parents));
+         if scope.trap && !is_focus_inside {
+            if let Some(first) = first_focusable(entity, &children, &focusables) {
+               focus.0 = Some(first);
+            }
+         }
+      } else if scope.is_changed() {
+         if let Some(entry) = stack.scopes.iter_mut().find(|n| n.entity == entity) {
+            entry.scope = scope.clone();
+         }
+      }
+   }
+}
+
+fn pop_focus_scopes(
+   mut focus: ResMut<FocusedEntity>,
+   mut stack: ResMut<FocusScopeStack>,
+   mut removed: RemovedComponents<FocusScope>,
+   focusables: Query<(), With<Focusable>>,
+) {
+   for entity in removed.read() {
+      let Some(index) = stack.scopes.iter().position(|n| n.entity == entity) else {
+         continue;
+      };
+      let entry = stack.scopes.remove(index);
+      if !entry.scope.restore_focus {
+         continue;
+      }
+      let focus_lost = focus
+         .0
+         .map_or(true, |focused| !focusables.contains(focused));
+      if !focus_lost {
+         continue;
+      }
+      if let Some(restore_to) = entry.restore_to.filter(|n| focusables.contains(*n)) {
+         focus.0 = Some(restore_to);
+      }
+   }
+}
+
+fn autofocus(
+   query: Query<Entity, (Added<AutoFocus>, With<Focusable>)>,
+   mut focus: ResMut<FocusedEntity>,
+) {
+   if let Some(entity) = query.iter().last() {
+      if focus.0 != Some(entity) {
+         focus.0 = Some(entity);
+      }
    }
 }
 
@@ -115,3 +308,54 @@ pub enum FocusState {
        // visible: bool,
    } */
 }
+
+#[cfg(test)]
+mod tests {
+   use bevy_ecs::system::RunSystemOnce;
+   use bevy_hierarchy::BuildWorldChildren;
+
+   use super::*;
+
+   #[test]
+   fn trapping_scope_takes_and_keeps_the_focus() {
+      let mut world = World::new();
+      world.init_resource::<FocusedEntity>();
+      world.init_resource::<FocusScopeStack>();
+
+      let outside = world.spawn(Focusable::default()).id();
+      assert!(focus_entity(&mut world, outside));
+
+      let mut inside = None;
+      world.spawn(FocusScope::default()).with_children(|parent| {
+         parent.spawn_empty().with_children(|parent| {
+            inside = Some(parent.spawn(Focusable::default()).id());
+         });
+      });
+      world.run_system_once(push_focus_scopes);
+      assert_eq!(world.resource::<FocusedEntity>().0, inside);
+
+      assert!(!focus_entity(&mut world, outside));
+      assert_eq!(world.resource::<FocusedEntity>().0, inside);
+      assert!(focus_entity(&mut world, inside.unwrap()));
+   }
+
+   #[test]
+   fn non_trapping_scope_leaves_the_focus() {
+      let mut world = World::new();
+      world.init_resource::<FocusedEntity>();
+      world.init_resource::<FocusScopeStack>();
+
+      let outside = world.spawn(Focusable::default()).id();
+      focus_entity(&mut world, outside);
+      world
+         .spawn(FocusScope {
+            trap: false,
+            restore_focus: true,
+         })
+         .with_children(|parent| {
+            parent.spawn(Focusable::default());
+         });
+      world.run_system_once(push_focus_scopes);
+      assert_eq!(world.resource::<FocusedEntity>().0, Some(outside));
+   }
+}
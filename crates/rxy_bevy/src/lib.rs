@@ -1,13 +1,26 @@
 #![allow(clippy::type_complexity)]
 
+pub use calc_style::*;
 pub use cmd::*;
 pub use command::*;
+pub use common_widgets::*;
+#[cfg(feature = "xy_reactive")]
+pub use component_signal::*;
+pub use drag_drop::*;
 pub use entity_extra_data::*;
+#[cfg(feature = "xy_reactive")]
+pub use focus_handle::*;
 pub use focusable::*;
 pub use plugin::*;
+pub use pointer_gesture::*;
 pub use renderer::*;
 pub use res::*;
 pub use res_change_observe::*;
+#[cfg(feature = "xy_reactive")]
+pub use res_memo::*;
+pub use shortcut::*;
+pub use touch_gesture::*;
+pub use ui_event::*;
 use rxy_core::{
    CloneableSchemaSlot, FnSchema, IntoViewSchemaFnWrapper, RebuildFnReceiver, RenderSchemaCtx,
    RendererSchemaView, SchemaSlot,
@@ -16,17 +29,30 @@ pub use view::*;
 pub use view_member::*;
 pub use world_ext::*;
 
+mod calc_style;
 mod cmd;
 mod command;
+mod common_widgets;
+#[cfg(feature = "xy_reactive")]
+mod component_signal;
+mod drag_drop;
 mod entity_extra_data;
 pub mod event;
+#[cfg(feature = "xy_reactive")]
+mod focus_handle;
 mod focusable;
 pub mod navigation;
 mod nest;
 mod plugin;
+mod pointer_gesture;
 mod renderer;
 mod res;
 mod res_change_observe;
+#[cfg(feature = "xy_reactive")]
+mod res_memo;
+mod shortcut;
+mod touch_gesture;
+mod ui_event;
 pub mod vec_data_source;
 mod view;
 mod view_member;
@@ -48,6 +74,7 @@ pub mod all_attrs {
 
 pub mod prelude {
    pub use bevy_ui::prelude::Val;
+   pub use rxy_core::{Calc, CalcVal};
 
    pub use rxy_bevy_macro::{ElementSchema, Schema};
 
@@ -68,9 +95,23 @@ pub mod prelude {
    #[cfg(feature = "style")]
    pub use super::style::prelude::*;
    pub use super::{
-      event::*, system_once, x_res, BevyRenderer, CloneableSlot, CmdReceiver, CmdSender,
+      event::*, system_once, x_query, x_res, BevyRenderer, CloneableSlot, CmdReceiver, CmdSender,
       FnSchemaView, Focusable, ReceiverProp, ResChangeWorldExt, RxyPlugin, RxyViewSpawner,
       SchemaCtx, Slot,
    };
    pub use super::{ElementViewCompositeAttrs, MemberOwnerCompositeAttrs};
+   pub use super::{AutoFocus, FocusScope, FocusedEntity};
+   pub use super::{KeyChord, ShortcutOptions, ShortcutScope};
+   pub use super::{ContextMenu, DoubleClick, LongPress, PointerGestureSettings, Wheel};
+   pub use super::{
+      GesturePhase, Pan, Pinch, Rotate, Swipe, SwipeDirection, Tap, TouchGestureSettings,
+   };
+   pub use super::{UiEvent, UiEventCommandsExt, UiEventWorldExt};
+   pub use super::{
+      drop_target, draggable, DragDropState, DropEvent, DropHoverState, DropTargetHover,
+   };
+   #[cfg(feature = "xy_reactive")]
+   pub use super::{
+      ComponentSignalSchemaCtxExt, FocusHandle, FocusSchemaCtxExt, ResMemoSchemaCtxExt,
+   };
 }
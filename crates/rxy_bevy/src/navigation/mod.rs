@@ -3,12 +3,17 @@ use bevy_ecs::query::With;
 use bevy_ecs::schedule::IntoSystemConfigs;
 use bevy_ecs::system::{Query, Res, ResMut};
 use bevy_ecs::{change_detection::DetectChangesMut, entity::Entity, prelude::Resource};
+use bevy_hierarchy::Parent;
 use bevy_input::prelude::KeyCode;
 use bevy_input::{ButtonInput, InputSystem};
 use bevy_render::view::ViewVisibility;
 use bevy_ui::{Interaction, UiStack, UiSystem};
 
-use crate::{Focusable, FocusedEntity};
+pub use spatial::*;
+
+use crate::{FocusScopeStack, Focusable, FocusedEntity};
+
+mod spatial;
 
 #[derive(Default, Debug)]
 pub struct RxyKeyboardNavigationPlugin {}
@@ -63,20 +68,28 @@ pub(crate) fn tab_pressed(
 /// The system updates the [`Focus`] resource when the user uses keyboard navigation with <kbd>tab</kbd> or <kbd>shift</kbd> + <kbd>tab</kbd>.
 ///
 /// Entities can be focused if [`ComputedVisibility`] is visible and they have the [`Focusable`] component.
+/// If a trapping [`FocusScope`](crate::FocusScope) is active, only its descendants can be focused.
 pub(crate) fn keyboard_navigation_system(
    mut focus: ResMut<FocusedEntity>,
    mut interactions: Query<&mut Interaction>,
    focusables: Query<&ViewVisibility, With<Focusable>>,
    keyboard_input: Res<ButtonInput<KeyCode>>,
    ui_stack: Res<UiStack>,
+   focus_scopes: Res<FocusScopeStack>,
+   parents: Query<&Parent>,
 ) {
    let reverse_order =
       keyboard_input.pressed(KeyCode::ShiftLeft) || keyboard_input.pressed(KeyCode::ShiftRight);
 
+   let trap_scope = focus_scopes.trap_scope();
+
    let can_focus = |entity: &&Entity| {
       focusables
          .get(**entity)
          .map_or(false, |computed_visibility| computed_visibility.get())
+         && trap_scope.map_or(true, |scope| {
+            FocusScopeStack::is_in_scope(scope, **entity, &parents)
+         })
    };
 
    let ui_nodes = &ui_stack.uinodes;
@@ -0,0 +1,456 @@
+use bevy_app::{Plugin, PreUpdate};
+use bevy_ecs::prelude::{Component, Entity, Local, Query, Res, ResMut, Resource, With};
+use bevy_ecs::schedule::IntoSystemConfigs;
+use bevy_hierarchy::Parent;
+use bevy_input::gamepad::{
+   GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType, Gamepads,
+};
+use bevy_input::prelude::KeyCode;
+use bevy_input::{Axis, ButtonInput, InputSystem};
+use bevy_math::{Rect, Vec2};
+use bevy_reflect::Reflect;
+use bevy_render::view::ViewVisibility;
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::{Node, UiStack, UiSystem};
+use bevy_utils::{Duration, Instant};
+
+use crate::event::{FocusInputEvents, FocusInputTriggerWay};
+use crate::{FocusScopeStack, Focusable, FocusedEntity};
+
+/// Moves the focus to the nearest focusable node in the pressed direction,
+/// using arrow keys, the gamepad D-pad or the left stick.
+#[derive(Default, Debug)]
+pub struct RxySpatialNavigationPlugin {}
+
+impl Plugin for RxySpatialNavigationPlugin {
+   fn build(&self, app: &mut bevy_app::App) {
+      app.register_type::<NavigationNeighbors>()
+         .init_resource::<SpatialNavigationInput>()
+         .add_systems(
+            PreUpdate,
+            spatial_navigation_system
+               .in_set(UiSystem::Focus)
+               .run_if(|input: Res<SpatialNavigationInput>| input.enabled)
+               .after(InputSystem),
+         );
+   }
+}
+
+/// Resource for the configuration of directional navigation.
+#[derive(Resource, Debug, Clone)]
+pub struct SpatialNavigationInput {
+   pub enabled: bool,
+   pub keyboard: bool,
+   pub gamepad: bool,
+   /// How far the left stick must be pushed before it counts as a direction.
+   pub stick_threshold: f32,
+   /// Delay before a held stick moves the focus again.
+   pub stick_repeat_delay: Duration,
+}
+
+impl Default for SpatialNavigationInput {
+   fn default() -> Self {
+      Self {
+         enabled: true,
+         keyboard: true,
+         gamepad: true,
+         stick_threshold: 0.5,
+         stick_repeat_delay: Duration::from_millis(300),
+      }
+   }
+}
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NavigationDirection {
+   Up,
+   Down,
+   Left,
+   Right,
+}
+
+impl NavigationDirection {
+   /// The unit vector of the direction in ui coordinates, where y grows downwards.
+   pub fn vec(self) -> Vec2 {
+      match self {
+         NavigationDirection::Up => Vec2::NEG_Y,
+         NavigationDirection::Down => Vec2::Y,
+         NavigationDirection::Left => Vec2::NEG_X,
+         NavigationDirection::Right => Vec2::X,
+      }
+   }
+
+   pub fn is_horizontal(self) -> bool {
+      matches!(self, NavigationDirection::Left | NavigationDirection::Right)
+   }
+
+   fn key_code(self) -> KeyCode {
+      match self {
+         NavigationDirection::Up => KeyCode::ArrowUp,
+         NavigationDirection::Down => KeyCode::ArrowDown,
+         NavigationDirection::Left => KeyCode::ArrowLeft,
+         NavigationDirection::Right => KeyCode::ArrowRight,
+      }
+   }
+
+   fn gamepad_button_type(self) -> GamepadButtonType {
+      match self {
+         NavigationDirection::Up => GamepadButtonType::DPadUp,
+         NavigationDirection::Down => GamepadButtonType::DPadDown,
+         NavigationDirection::Left => GamepadButtonType::DPadLeft,
+         NavigationDirection::Right => GamepadButtonType::DPadRight,
+      }
+   }
+
+   const ALL: [NavigationDirection; 4] = [
+      NavigationDirection::Up,
+      NavigationDirection::Down,
+      NavigationDirection::Left,
+      NavigationDirection::Right,
+   ];
+}
+
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NavigationWrap {
+   #[default]
+   None,
+   Horizontal,
+   Vertical,
+   Both,
+}
+
+impl NavigationWrap {
+   pub fn allows(self, direction: NavigationDirection) -> bool {
+      match self {
+         NavigationWrap::None => false,
+         NavigationWrap::Horizontal => direction.is_horizontal(),
+         NavigationWrap::Vertical => !direction.is_horizontal(),
+         NavigationWrap::Both => true,
+      }
+   }
+}
+
+/// Per-node overrides of directional navigation.
+///
+/// An explicit neighbour always wins over the spatial search. When nothing is found in the
+/// pressed direction, `wrap` decides whether the focus jumps to the opposite side.
+#[derive(Reflect, Component, Debug, Default, Clone, PartialEq, Eq)]
+pub struct NavigationNeighbors {
+   pub up: Option<Entity>,
+   pub down: Option<Entity>,
+   pub left: Option<Entity>,
+   pub right: Option<Entity>,
+   pub wrap: NavigationWrap,
+}
+
+impl NavigationNeighbors {
+   pub fn get(&self, direction: NavigationDirection) -> Option<Entity> {
+      match direction {
+         NavigationDirection::Up => self.up,
+         NavigationDirection::Down => self.down,
+         NavigationDirection::Left => self.left,
+         NavigationDirection::Right => self.right,
+      }
+   }
+}
+
+#[derive(Default)]
+pub(crate) struct StickState {
+   direction: Option<NavigationDirection>,
+   last_move: Option<Instant>,
+}
+
+/// Returns the pressed direction, unless the focused node handles that input itself
+/// through a [`FocusInputEvent`](crate::event::FocusInputEvent) listener.
+fn pressed_direction(
+   input: &SpatialNavigationInput,
+   focus: Option<Entity>,
+   keyboard_input: &ButtonInput<KeyCode>,
+   gamepad_input: &ButtonInput<GamepadButton>,
+   gamepads: &Gamepads,
+   keyboard_events: Option<&FocusInputEvents<KeyCode>>,
+   gamepad_events: Option<&FocusInputEvents<GamepadButton>>,
+) -> Option<NavigationDirection> {
+   fn is_handled<T>(events: Option<&FocusInputEvents<T>>, focus: Option<Entity>, input: T) -> bool
+   where
+      T: Copy + Eq + core::hash::Hash + Send + Sync + 'static,
+   {
+      let (Some(events), Some(focus)) = (events, focus) else {
+         return false;
+      };
+      events.get(&focus).map_or(false, |events| {
+         [
+            FocusInputTriggerWay::JustPressed,
+            FocusInputTriggerWay::Pressed,
+         ]
+         .into_iter()
+         .any(|way| events.contains_key(&(input, way)))
+      })
+   }
+
+   NavigationDirection::ALL.into_iter().find(|direction| {
+      if input.keyboard {
+         let key = direction.key_code();
+         if keyboard_input.just_pressed(key) && !is_handled(keyboard_events, focus, key) {
+            return true;
+         }
+      }
+      if input.gamepad {
+         return gamepads.iter().any(|gamepad| {
+            let button = GamepadButton::new(gamepad, direction.gamepad_button_type());
+            gamepad_input.just_pressed(button) && !is_handled(gamepad_events, focus, button)
+         });
+      }
+      false
+   })
+}
+
+fn stick_direction(
+   input: &SpatialNavigationInput,
+   gamepads: &Gamepads,
+   axes: &Axis<GamepadAxis>,
+) -> Option<NavigationDirection> {
+   gamepads.iter().find_map(|gamepad| {
+      let x = axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX))?;
+      let y = axes.get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY))?;
+      if x.abs().max(y.abs()) < input.stick_threshold {
+         return None;
+      }
+      Some(if x.abs() > y.abs() {
+         if x > 0. {
+            NavigationDirection::Right
+         } else {
+            NavigationDirection::Left
+         }
+      } else if y > 0. {
+         // The stick y axis points upwards
+         NavigationDirection::Up
+      } else {
+         NavigationDirection::Down
+      })
+   })
+}
+
+/// Finds the nearest candidate in `direction` from `from`.
+///
+/// Candidates overlapping `from` on the perpendicular axis are preferred; the distance along
+/// the perpendicular axis otherwise counts double.
+pub fn find_in_direction(
+   from: Rect,
+   direction: NavigationDirection,
+   candidates: impl Iterator<Item = (Entity, Rect)>,
+   wrap: bool,
+) -> Option<Entity> {
+   let axis = direction.vec();
+   let from_center = from.center();
+
+   let score = |rect: Rect| -> (f32, f32) {
+      let delta = rect.center() - from_center;
+      let primary = delta.dot(axis);
+      let (overlaps, secondary) = if direction.is_horizontal() {
+         (
+            rect.min.y < from.max.y && rect.max.y > from.min.y,
+            delta.y.abs(),
+         )
+      } else {
+         (
+            rect.min.x < from.max.x && rect.max.x > from.min.x,
+            delta.x.abs(),
+         )
+      };
+      (primary, if overlaps { 0. } else { secondary })
+   };
+
+   let candidates = candidates
+      .map(|(entity, rect)| (entity, score(rect)))
+      .collect::<Vec<_>>();
+
+   let nearest = candidates
+      .iter()
+      .filter(|(_, (primary, _))| *primary > f32::EPSILON)
+      .min_by(|(_, (a_p, a_s)), (_, (b_p, b_s))| (a_p + a_s * 2.).total_cmp(&(b_p + b_s * 2.)))
+      .map(|(entity, _)| *entity);
+
+   if nearest.is_some() || !wrap {
+      return nearest;
+   }
+
+   // Wrap to the farthest node on the opposite side
+   candidates
+      .iter()
+      .filter(|(_, (primary, _))| *primary < -f32::EPSILON)
+      .min_by(|(_, (a_p, a_s)), (_, (b_p, b_s))| (a_p + a_s * 2.).total_cmp(&(b_p + b_s * 2.)))
+      .map(|(entity, _)| *entity)
+}
+
+fn node_rect(node: &Node, transform: &GlobalTransform) -> Rect {
+   Rect::from_center_size(transform.translation().truncate(), node.size())
+}
+
+/// The system updates the [`FocusedEntity`] resource when the user navigates with arrow keys, D-pad or left stick.
+pub(crate) fn spatial_navigation_system(
+   mut focus: ResMut<FocusedEntity>,
+   input: Res<SpatialNavigationInput>,
+   keyboard_input: Res<ButtonInput<KeyCode>>,
+   gamepad_input: Res<ButtonInput<GamepadButton>>,
+   gamepads: Res<Gamepads>,
+   axes: Res<Axis<GamepadAxis>>,
+   mut stick_state: Local<StickState>,
+   keyboard_events: Option<Res<FocusInputEvents<KeyCode>>>,
+   gamepad_events: Option<Res<FocusInputEvents<GamepadButton>>>,
+   focusables: Query<
+      (
+         &ViewVisibility,
+         &Node,
+         &GlobalTransform,
+         Option<&NavigationNeighbors>,
+      ),
+      With<Focusable>,
+   >,
+   ui_stack: Res<UiStack>,
+   focus_scopes: Res<FocusScopeStack>,
+   parents: Query<&Parent>,
+) {
+   let mut direction = pressed_direction(
+      &input,
+      focus.0,
+      &keyboard_input,
+      &gamepad_input,
+      &gamepads,
+      keyboard_events.as_deref(),
+      gamepad_events.as_deref(),
+   );
+
+   if input.gamepad && direction.is_none() {
+      let stick = stick_direction(&input, &gamepads, &axes);
+      let now = Instant::now();
+      let repeat = stick_state.last_move.map_or(true, |last_move| {
+         now - last_move >= input.stick_repeat_delay
+      });
+      if stick.is_some() && (stick != stick_state.direction || repeat) {
+         direction = stick;
+         stick_state.last_move = Some(now);
+      }
+      stick_state.direction = stick;
+   }
+
+   let Some(direction) = direction else {
+      return;
+   };
+
+   let trap_scope = focus_scopes.trap_scope();
+   let can_focus = |entity: Entity| {
+      focusables
+         .get(entity)
+         .map_or(false, |(visibility, ..)| visibility.get())
+         && trap_scope.map_or(true, |scope| {
+            FocusScopeStack::is_in_scope(scope, entity, &parents)
+         })
+   };
+
+   let current = focus
+      .0
+      .and_then(|entity| focusables.get(entity).ok().map(|n| (entity, n)));
+
+   let new_focus = match current {
+      None => ui_stack.uinodes.iter().copied().find(|n| can_focus(*n)),
+      Some((current, (_, node, transform, neighbors))) => {
+         match neighbors.and_then(|n| n.get(direction)) {
+            Some(neighbor) if can_focus(neighbor) => Some(neighbor),
+            _ => find_in_direction(
+               node_rect(node, transform),
+               direction,
+               ui_stack
+                  .uinodes
+                  .iter()
+                  .copied()
+                  .filter(|n| *n != current && can_focus(*n))
+                  .filter_map(|n| {
+                     focusables
+                        .get(n)
+                        .ok()
+                        .map(|(_, node, transform, _)| (n, node_rect(node, transform)))
+                  }),
+               neighbors.map_or(false, |n| n.wrap.allows(direction)),
+            ),
+         }
+      }
+   };
+
+   if new_focus.is_some() && focus.0 != new_focus {
+      *focus = FocusedEntity(new_focus);
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   fn rect(x: f32, y: f32) -> Rect {
+      Rect::from_center_size(Vec2::new(x, y), Vec2::splat(10.))
+   }
+
+   fn find(
+      from: Rect,
+      direction: NavigationDirection,
+      candidates: &[(Entity, Rect)],
+      wrap: bool,
+   ) -> Option<Entity> {
+      find_in_direction(from, direction, candidates.iter().copied(), wrap)
+   }
+
+   #[test]
+   fn find_the_nearest_in_direction() {
+      let near = Entity::from_raw(1);
+      let far = Entity::from_raw(2);
+      let behind = Entity::from_raw(3);
+      let candidates = [
+         (far, rect(40., 0.)),
+         (near, rect(20., 0.)),
+         (behind, rect(-20., 0.)),
+      ];
+
+      let from = rect(0., 0.);
+      assert_eq!(
+         find(from, NavigationDirection::Right, &candidates, false),
+         Some(near)
+      );
+      assert_eq!(
+         find(from, NavigationDirection::Left, &candidates, false),
+         Some(behind)
+      );
+      assert_eq!(
+         find(from, NavigationDirection::Up, &candidates, false),
+         None
+      );
+   }
+
+   #[test]
+   fn prefer_overlapping_candidates() {
+      let overlapping = Entity::from_raw(1);
+      let diagonal = Entity::from_raw(2);
+      // The diagonal one is nearer along the axis, but does not overlap horizontally
+      let candidates = [(overlapping, rect(0., 30.)), (diagonal, rect(20., 15.))];
+
+      assert_eq!(
+         find(rect(0., 0.), NavigationDirection::Down, &candidates, false),
+         Some(overlapping)
+      );
+   }
+
+   #[test]
+   fn wrap_to_the_farthest_on_the_opposite_side() {
+      let first = Entity::from_raw(1);
+      let second = Entity::from_raw(2);
+      let candidates = [(first, rect(0., 0.)), (second, rect(20., 0.))];
+
+      let last = rect(40., 0.);
+      assert_eq!(
+         find(last, NavigationDirection::Right, &candidates, false),
+         None
+      );
+      assert_eq!(
+         find(last, NavigationDirection::Right, &candidates, true),
+         Some(first)
+      );
+   }
+}
@@ -1,8 +1,8 @@
-use bevy_ecs::prelude::{Bundle, IntoSystem, Resource};
+use bevy_ecs::prelude::{Bundle, Resource};
 
 use rxy_core::{InnerIvmToVm, MaybeSend, XNest, XNestMapper};
 
-use crate::prelude::ElementEventIds;
+use crate::prelude::{ElementEventIds, IntoEventHandler};
 use crate::{x_res, EventViewMember, XBundle, XRes};
 
 impl<T> XNest for XBundle<T>
@@ -39,7 +39,7 @@ where
 impl<T, S, TM> XNest for EventViewMember<T, S, TM>
 where
    T: ElementEventIds,
-   S: IntoSystem<(), (), TM> + Send + 'static,
+   S: IntoEventHandler<TM>,
    TM: Send + 'static,
 {
    type Inner = Self;
@@ -58,7 +58,7 @@ impl<T, S, TM, U> XNestMapper<U> for EventViewMember<T, S, TM>
 where
    U: 'static,
    T: ElementEventIds,
-   S: IntoSystem<(), (), TM> + Send + 'static,
+   S: IntoEventHandler<TM>,
    TM: Send + 'static,
 {
    type MapInnerTo = U;
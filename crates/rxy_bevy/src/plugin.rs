@@ -8,7 +8,11 @@ use bevy_ui::widget::TextFlags;
 use bevy_ui::Style;
 
 use crate::elements::ElementTypeRegisterAppExt;
-use crate::{handle_schedule_event, CommandChannelPlugin, FocusablePlugin, ScheduleSystemAdds};
+use crate::event::PointerEventDispatch;
+use crate::{
+   handle_schedule_event, CalcStylePlugin, CommandChannelPlugin, CommonWidgetsPlugin,
+   DragDropPlugin, FocusablePlugin, PointerGesturePlugin, ScheduleSystemAdds, TouchGesturePlugin,
+};
 
 #[derive(Resource)]
 pub struct RxyContainerEntity {
@@ -54,18 +58,29 @@ impl Plugin for RxyPlugin {
             .id()
       }));
 
-      app.add_plugins((DefaultPickingPlugins, CommandChannelPlugin, FocusablePlugin))
-         .insert_resource(root_entity)
-         .register_type::<TextFlags>()
-         .register_type::<PickingInteraction>()
-         .init_resource::<ScheduleSystemAdds>()
-         // todo:
-         // .register_attr_values()
-         .register_element_types()
-         .add_systems(
-            First,
-            handle_schedule_event
-               .run_if(|systems: Res<ScheduleSystemAdds>| !systems.systems.is_empty()),
-         );
+      app.add_plugins((
+         DefaultPickingPlugins,
+         CommandChannelPlugin,
+         FocusablePlugin,
+         DragDropPlugin,
+         PointerGesturePlugin,
+         TouchGesturePlugin,
+         CalcStylePlugin,
+         CommonWidgetsPlugin,
+      ))
+      .insert_resource(root_entity)
+      .register_type::<TextFlags>()
+      .register_type::<PickingInteraction>()
+      .init_resource::<ScheduleSystemAdds>()
+      .init_resource::<PointerEventDispatch>()
+      // todo:
+      // .register_attr_values()
+      .register_element_types()
+      .add_systems(
+         First,
+         handle_schedule_event
+            .run_if(|systems: Res<ScheduleSystemAdds>| !systems.systems.is_empty()),
+      )
+      .add_systems(First, PointerEventDispatch::system_clear);
    }
 }
@@ -0,0 +1,298 @@
+use core::time::Duration;
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::{Entity, EventReader, EventWriter, Local, Query, Res, Resource};
+use bevy_ecs::schedule::IntoSystemConfigs;
+use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy_math::Vec2;
+use bevy_mod_picking::focus::HoverMap;
+use bevy_mod_picking::picking_core::PickSet;
+use bevy_mod_picking::pointer::{Location, PointerId, PointerLocation};
+use bevy_mod_picking::prelude::*;
+use bevy_reflect::Reflect;
+use bevy_utils::{HashMap, Instant};
+
+/// Thresholds of the gestures synthesized from picking events.
+#[derive(Resource, Reflect, Debug, Clone)]
+pub struct PointerGestureSettings {
+   /// The max time between two clicks of the same button on the same entity to count as a double click
+   pub double_click_interval: Duration,
+   /// How long a button must be held for a long press
+   pub long_press_duration: Duration,
+   /// How far the pointer may move while held before the long press is canceled
+   pub long_press_tolerance: f32,
+}
+
+impl PointerGestureSettings {
+   /// Whether a held button dragged by `distance` is no longer a long press.
+   fn cancels_long_press(&self, distance: Vec2) -> bool {
+      distance.length() > self.long_press_tolerance
+   }
+}
+
+impl Default for PointerGestureSettings {
+   fn default() -> Self {
+      Self {
+         double_click_interval: Duration::from_millis(500),
+         long_press_duration: Duration::from_millis(500),
+         long_press_tolerance: 8.,
+      }
+   }
+}
+
+/// The mouse wheel scrolled over the target.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct Wheel {
+   pub unit: MouseScrollUnit,
+   pub delta: Vec2,
+}
+
+/// The second click of a double click.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct DoubleClick {
+   pub button: PointerButton,
+}
+
+/// A button held over the target for [`PointerGestureSettings::long_press_duration`].
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct LongPress {
+   pub button: PointerButton,
+   pub duration: Duration,
+}
+
+/// A secondary button click, where a context menu is usually opened.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct ContextMenu;
+
+pub struct PointerGesturePlugin;
+
+impl Plugin for PointerGesturePlugin {
+   fn build(&self, app: &mut App) {
+      app.register_type::<PointerGestureSettings>()
+         .init_resource::<PointerGestureSettings>()
+         .add_event::<Pointer<Wheel>>()
+         .add_event::<Pointer<DoubleClick>>()
+         .add_event::<Pointer<LongPress>>()
+         .add_event::<Pointer<ContextMenu>>()
+         .add_systems(
+            PreUpdate,
+            (wheel_events, click_gesture_events, long_press_events).in_set(PickSet::PostFocus),
+         );
+   }
+}
+
+fn wheel_events(
+   mut wheel_events: EventReader<MouseWheel>,
+   hover_map: Res<HoverMap>,
+   pointers: Query<(&PointerId, &PointerLocation)>,
+   mut writer: EventWriter<Pointer<Wheel>>,
+) {
+   for wheel in wheel_events.read() {
+      let Some(hovered) = hover_map.get(&PointerId::Mouse) else {
+         continue;
+      };
+      // The topmost hovered entity, the event bubbles up from it
+      let Some((target, _)) = hovered
+         .iter()
+         .min_by(|(_, a), (_, b)| a.depth.total_cmp(&b.depth))
+      else {
+         continue;
+      };
+      let Some(location) = pointers
+         .iter()
+         .find(|(id, _)| **id == PointerId::Mouse)
+         .and_then(|(_, location)| location.location.clone())
+      else {
+         continue;
+      };
+      writer.send(Pointer::new(
+         PointerId::Mouse,
+         location,
+         *target,
+         Wheel {
+            unit: wheel.unit,
+            delta: Vec2::new(wheel.x, wheel.y),
+         },
+      ));
+   }
+}
+
+/// Records the click, returns whether it is the second click of a double click.
+fn record_click(
+   last_clicks: &mut HashMap<(PointerId, PointerButton), (Entity, Instant)>,
+   key: (PointerId, PointerButton),
+   target: Entity,
+   now: Instant,
+   settings: &PointerGestureSettings,
+) -> bool {
+   let is_double_click = last_clicks.get(&key).map_or(false, |(last_target, time)| {
+      *last_target == target && now.duration_since(*time) <= settings.double_click_interval
+   });
+   if is_double_click {
+      last_clicks.remove(&key);
+   } else {
+      last_clicks.insert(key, (target, now));
+   }
+   is_double_click
+}
+
+fn click_gesture_events(
+   settings: Res<PointerGestureSettings>,
+   mut clicks: EventReader<Pointer<Click>>,
+   mut last_clicks: Local<HashMap<(PointerId, PointerButton), (Entity, Instant)>>,
+   mut double_click_writer: EventWriter<Pointer<DoubleClick>>,
+   mut context_menu_writer: EventWriter<Pointer<ContextMenu>>,
+) {
+   let now = Instant::now();
+   for click in clicks.read() {
+      let key = (click.pointer_id, click.button);
+      if record_click(&mut last_clicks, key, click.target, now, &settings) {
+         double_click_writer.send(Pointer::new(
+            click.pointer_id,
+            click.pointer_location.clone(),
+            click.target,
+            DoubleClick {
+               button: click.button,
+            },
+         ));
+      }
+
+      if click.button == PointerButton::Secondary {
+         context_menu_writer.send(Pointer::new(
+            click.pointer_id,
+            click.pointer_location.clone(),
+            click.target,
+            ContextMenu,
+         ));
+      }
+   }
+}
+
+struct PressState {
+   target: Entity,
+   location: Location,
+   start: Instant,
+   fired: bool,
+}
+
+impl PressState {
+   /// Returns the held duration once, when it reaches the long press duration.
+   fn poll(&mut self, now: Instant, settings: &PointerGestureSettings) -> Option<Duration> {
+      let duration = now.duration_since(self.start);
+      if self.fired || duration < settings.long_press_duration {
+         return None;
+      }
+      self.fired = true;
+      Some(duration)
+   }
+}
+
+fn long_press_events(
+   settings: Res<PointerGestureSettings>,
+   mut downs: EventReader<Pointer<Down>>,
+   mut ups: EventReader<Pointer<Up>>,
+   mut drags: EventReader<Pointer<Drag>>,
+   mut presses: Local<HashMap<(PointerId, PointerButton), PressState>>,
+   mut writer: EventWriter<Pointer<LongPress>>,
+) {
+   let now = Instant::now();
+   for down in downs.read() {
+      presses.insert(
+         (down.pointer_id, down.button),
+         PressState {
+            target: down.target,
+            location: down.pointer_location.clone(),
+            start: now,
+            fired: false,
+         },
+      );
+   }
+   for drag in drags.read() {
+      if settings.cancels_long_press(drag.distance) {
+         presses.remove(&(drag.pointer_id, drag.button));
+      }
+   }
+   for up in ups.read() {
+      presses.remove(&(up.pointer_id, up.button));
+   }
+
+   for ((pointer_id, button), press) in presses.iter_mut() {
+      let Some(duration) = press.poll(now, &settings) else {
+         continue;
+      };
+      writer.send(Pointer::new(
+         *pointer_id,
+         press.location.clone(),
+         press.target,
+         LongPress {
+            button: *button,
+            duration,
+         },
+      ));
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use bevy_render::camera::NormalizedRenderTarget;
+   use bevy_window::WindowRef;
+
+   use super::*;
+
+   #[test]
+   fn double_click_within_the_interval_on_the_same_target() {
+      let settings = PointerGestureSettings::default();
+      let mut last_clicks = HashMap::default();
+      let mut click = |target: Entity, at: Instant| {
+         let key = (PointerId::Mouse, PointerButton::Primary);
+         record_click(&mut last_clicks, key, target, at, &settings)
+      };
+      let target = Entity::from_raw(1);
+      let start = Instant::now();
+
+      assert!(!click(target, start));
+      assert!(click(target, start + Duration::from_millis(300)));
+      // The third click starts a new double click
+      assert!(!click(target, start + Duration::from_millis(400)));
+      assert!(!click(target, start + Duration::from_millis(1000)));
+      let other = Entity::from_raw(2);
+      assert!(!click(other, start + Duration::from_millis(1100)));
+   }
+
+   #[test]
+   fn long_press_fires_once_after_the_duration() {
+      let settings = PointerGestureSettings::default();
+      let start = Instant::now();
+      let mut press = PressState {
+         target: Entity::from_raw(1),
+         location: Location {
+            target: NormalizedRenderTarget::Window(
+               WindowRef::Entity(Entity::PLACEHOLDER)
+                  .normalize(None)
+                  .unwrap(),
+            ),
+            position: Vec2::ZERO,
+         },
+         start,
+         fired: false,
+      };
+
+      assert_eq!(
+         press.poll(start + Duration::from_millis(300), &settings),
+         None
+      );
+      let held = Duration::from_millis(600);
+      assert_eq!(press.poll(start + held, &settings), Some(held));
+      assert_eq!(
+         press.poll(start + Duration::from_millis(900), &settings),
+         None
+      );
+   }
+
+   #[test]
+   fn long_press_tolerates_small_drags() {
+      let settings = PointerGestureSettings::default();
+      assert!(!settings.cancels_long_press(Vec2::new(5., 5.)));
+      assert!(settings.cancels_long_press(Vec2::new(6., 6.)));
+   }
+}
@@ -17,15 +17,26 @@ use bevy_utils::tracing::warn;
 use glam::{Quat, Vec3};
 
 use rxy_core::{
-   attrs_fn_define, define_attr_get_fn, impl_index_for_tys, AttrIndex, ElementAttrType,
-   ElementAttrUntyped, RendererNodeId, RendererWorld,
+   attrs_fn_define, define_attr_get_fn, impl_index_for_tys, AttrIndex, CalcProperty, CalcVal,
+   ElementAttrType, ElementAttrUntyped, RendererNodeId, RendererWorld,
 };
 
 pub use crate::prelude::no_preclude::ALL_ATTRS;
-use crate::{all_attrs, BevyRenderer, ElementStyleEntityExt, TextStyledElementEntityWorldMutExt};
+use crate::{
+   all_attrs, BevyRenderer, CalcStyleEntityWorldMutExt, ElementStyleEntityExt,
+   TextStyledElementEntityWorldMutExt,
+};
 
 macro_rules! common_attrs_fn_define {
-    ($($attr:ident)*) => {
+    (
+        extra = [$($extra:ident)*];
+        common = [$($common:ident)*];
+    ) => {
+        common_attrs_fn_define!(@DEFINE [$($common)* $($extra)*]);
+
+        $(impl rxy_core::common_renderer::CommonAttr<$crate::BevyRenderer> for $crate::all_attrs::$common {})*
+    };
+    (@DEFINE [$($attr:ident)*]) => {
         impl_index_for_tys! {
             $($crate::all_attrs::$attr)*
         }
@@ -102,73 +113,27 @@ macro_rules! element_attrs_fn_define {
     };
 }
 
-common_attrs_fn_define! {
-    // class
-    name
-    z_index
-    bg_color
-    border_left
-    border_right
-    border_top
-    border_bottom
-    border_color
-    display
-    position_type
-    overflow_x
-    overflow_y
-    direction
-    left
-    right
-    top
-    bottom
-    width
-    height
-    min_width
-    min_height
-    max_width
-    max_height
-    margin_left
-    margin_right
-    margin_top
-    margin_bottom
-    padding_left
-    padding_right
-    padding_top
-    padding_bottom
-    aspect_ratio
-    align_items
-    justify_items
-    align_self
-    justify_self
-    align_content
-    justify_content
-    flex_direction
-    flex_wrap
-    flex_grow
-    flex_shrink
-    flex_basis
-    column_gap
-    row_gap
-    visibility
-    translation
-    rotation
-    scale
-    text_color
-    font_size
-    text_linebreak
-    text_align
-    font
-    outline_width
-    outline_offset
-    outline_color
-    grid_auto_flow
-    grid_template_rows
-    grid_template_columns
-    grid_auto_rows
-    grid_auto_columns
-    grid_row
-    grid_column
-}
+rxy_core::common_attrs!(common_attrs_fn_define! {
+    extra = [
+        z_index
+        justify_items
+        justify_self
+        font
+        grid_auto_flow
+        grid_template_rows
+        grid_template_columns
+        grid_auto_rows
+        grid_auto_columns
+        grid_row
+        grid_column
+        disabled
+        checked
+        selected
+        invalid
+        custom_states
+    ];
+});
+rxy_core::assert_all_common_attrs!(BevyRenderer);
 define_attr_get_fn!(BevyRenderer);
 
 // #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
@@ -531,7 +496,7 @@ impl ElementAttrType<BevyRenderer> for direction {
 pub struct left;
 
 impl ElementAttrType<BevyRenderer> for left {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(left);
 
@@ -543,9 +508,7 @@ impl ElementAttrType<BevyRenderer> for left {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.left = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Left, value.into());
    }
 }
 
@@ -553,7 +516,7 @@ impl ElementAttrType<BevyRenderer> for left {
 pub struct right;
 
 impl ElementAttrType<BevyRenderer> for right {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(right);
    fn update_value(
@@ -564,9 +527,7 @@ impl ElementAttrType<BevyRenderer> for right {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.right = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Right, value.into());
    }
 }
 
@@ -574,7 +535,7 @@ impl ElementAttrType<BevyRenderer> for right {
 pub struct top;
 
 impl ElementAttrType<BevyRenderer> for top {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(top);
    fn update_value(
@@ -585,9 +546,7 @@ impl ElementAttrType<BevyRenderer> for top {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.top = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Top, value.into());
    }
 }
 
@@ -595,7 +554,7 @@ impl ElementAttrType<BevyRenderer> for top {
 pub struct bottom;
 
 impl ElementAttrType<BevyRenderer> for bottom {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(bottom);
    fn update_value(
@@ -606,9 +565,7 @@ impl ElementAttrType<BevyRenderer> for bottom {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.bottom = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Bottom, value.into());
    }
 }
 
@@ -616,7 +573,7 @@ impl ElementAttrType<BevyRenderer> for bottom {
 pub struct width;
 
 impl ElementAttrType<BevyRenderer> for width {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(width);
    fn update_value(
@@ -627,9 +584,7 @@ impl ElementAttrType<BevyRenderer> for width {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.width = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Width, value.into());
    }
 }
 
@@ -637,7 +592,7 @@ impl ElementAttrType<BevyRenderer> for width {
 pub struct height;
 
 impl ElementAttrType<BevyRenderer> for height {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(height);
    fn update_value(
@@ -648,9 +603,7 @@ impl ElementAttrType<BevyRenderer> for height {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.height = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Height, value.into());
    }
 }
 
@@ -658,7 +611,7 @@ impl ElementAttrType<BevyRenderer> for height {
 pub struct min_width;
 
 impl ElementAttrType<BevyRenderer> for min_width {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(min_width);
    fn update_value(
@@ -669,9 +622,7 @@ impl ElementAttrType<BevyRenderer> for min_width {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.min_width = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MinWidth, value.into());
    }
 }
 
@@ -679,7 +630,7 @@ impl ElementAttrType<BevyRenderer> for min_width {
 pub struct min_height;
 
 impl ElementAttrType<BevyRenderer> for min_height {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(min_height);
    fn update_value(
@@ -690,9 +641,7 @@ impl ElementAttrType<BevyRenderer> for min_height {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.min_height = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MinHeight, value.into());
    }
 }
 
@@ -700,7 +649,7 @@ impl ElementAttrType<BevyRenderer> for min_height {
 pub struct max_width;
 
 impl ElementAttrType<BevyRenderer> for max_width {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(max_width);
    fn update_value(
@@ -711,9 +660,7 @@ impl ElementAttrType<BevyRenderer> for max_width {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.max_width = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MaxWidth, value.into());
    }
 }
 
@@ -721,7 +668,7 @@ impl ElementAttrType<BevyRenderer> for max_width {
 pub struct max_height;
 
 impl ElementAttrType<BevyRenderer> for max_height {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(max_height);
    fn update_value(
@@ -732,9 +679,7 @@ impl ElementAttrType<BevyRenderer> for max_height {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.max_height = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MaxHeight, value.into());
    }
 }
 
@@ -742,7 +687,7 @@ impl ElementAttrType<BevyRenderer> for max_height {
 pub struct margin_left;
 
 impl ElementAttrType<BevyRenderer> for margin_left {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(margin_left);
    fn update_value(
@@ -753,10 +698,7 @@ impl ElementAttrType<BevyRenderer> for margin_left {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.margin.left = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MarginLeft, value.into());
    }
 }
 
@@ -764,7 +706,7 @@ impl ElementAttrType<BevyRenderer> for margin_left {
 pub struct margin_right;
 
 impl ElementAttrType<BevyRenderer> for margin_right {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(margin_right);
    fn update_value(
@@ -775,10 +717,7 @@ impl ElementAttrType<BevyRenderer> for margin_right {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.margin.right = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MarginRight, value.into());
    }
 }
 
@@ -786,7 +725,7 @@ impl ElementAttrType<BevyRenderer> for margin_right {
 pub struct margin_top;
 
 impl ElementAttrType<BevyRenderer> for margin_top {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(margin_top);
    fn update_value(
@@ -797,10 +736,7 @@ impl ElementAttrType<BevyRenderer> for margin_top {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.margin.top = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MarginTop, value.into());
    }
 }
 
@@ -808,7 +744,7 @@ impl ElementAttrType<BevyRenderer> for margin_top {
 pub struct margin_bottom;
 
 impl ElementAttrType<BevyRenderer> for margin_bottom {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(margin_bottom);
    fn update_value(
@@ -819,10 +755,7 @@ impl ElementAttrType<BevyRenderer> for margin_bottom {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.margin.bottom = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MarginBottom, value.into());
    }
 }
 
@@ -830,7 +763,7 @@ impl ElementAttrType<BevyRenderer> for margin_bottom {
 pub struct padding_left;
 
 impl ElementAttrType<BevyRenderer> for padding_left {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(padding_left);
    fn update_value(
@@ -841,10 +774,7 @@ impl ElementAttrType<BevyRenderer> for padding_left {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.padding.left = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::PaddingLeft, value.into());
    }
 }
 
@@ -852,7 +782,7 @@ impl ElementAttrType<BevyRenderer> for padding_left {
 pub struct padding_right;
 
 impl ElementAttrType<BevyRenderer> for padding_right {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(padding_right);
    fn update_value(
@@ -863,10 +793,7 @@ impl ElementAttrType<BevyRenderer> for padding_right {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.padding.right = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::PaddingRight, value.into());
    }
 }
 
@@ -874,7 +801,7 @@ impl ElementAttrType<BevyRenderer> for padding_right {
 pub struct padding_top;
 
 impl ElementAttrType<BevyRenderer> for padding_top {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(padding_top);
    fn update_value(
@@ -885,10 +812,7 @@ impl ElementAttrType<BevyRenderer> for padding_top {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.padding.top = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::PaddingTop, value.into());
    }
 }
 
@@ -896,7 +820,7 @@ impl ElementAttrType<BevyRenderer> for padding_top {
 pub struct padding_bottom;
 
 impl ElementAttrType<BevyRenderer> for padding_bottom {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(padding_bottom);
    fn update_value(
@@ -907,10 +831,7 @@ impl ElementAttrType<BevyRenderer> for padding_bottom {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.padding.bottom = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::PaddingBottom, value.into());
    }
 }
 
@@ -1524,3 +1445,77 @@ define_style_attr_type! {
     grid_row: GridPlacement
     grid_column: GridPlacement
 }
+
+macro_rules! define_style_state_attr_type {
+    ($($ident:ident:$state:ident)*) => {
+        $(
+            #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+            pub struct $ident;
+
+            impl ElementAttrType<BevyRenderer> for $ident {
+                type Value = bool;
+
+                const NAME: &'static str = stringify!($ident);
+
+                #[allow(unused_variables)]
+                fn update_value(
+                    world: &mut RendererWorld<BevyRenderer>,
+                    node_id: RendererNodeId<BevyRenderer>,
+                    value: impl Into<Self::Value>,
+                ) {
+                    #[cfg(feature = "style")]
+                    {
+                        use $crate::style::StyleStatesEntityWorldMutExt;
+                        let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else{
+                            return;
+                        };
+                        entity_world_mut.set_style_states(
+                            rxy_core::style::StyleInteraction::$state,
+                            value.into(),
+                        );
+                    }
+                }
+            }
+        )*
+    };
+}
+
+define_style_state_attr_type! {
+    disabled: Disabled
+    checked: Checked
+    selected: Selected
+    invalid: Invalid
+}
+
+/// The custom style states of the node, bit `i` is [`rxy_core::style::StyleInteraction::custom`]`(i)`.
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub struct custom_states;
+
+impl ElementAttrType<BevyRenderer> for custom_states {
+   type Value = u16;
+
+   const NAME: &'static str = stringify!(custom_states);
+
+   #[allow(unused_variables)]
+   fn update_value(
+      world: &mut RendererWorld<BevyRenderer>,
+      node_id: RendererNodeId<BevyRenderer>,
+      value: impl Into<Self::Value>,
+   ) {
+      #[cfg(feature = "style")]
+      {
+         use crate::style::StyleStatesEntityWorldMutExt;
+         use rxy_core::style::StyleInteraction;
+         let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
+            return;
+         };
+         let custom_states =
+            StyleInteraction::from_bits_retain(!0 << StyleInteraction::CUSTOM_OFFSET);
+         let value = StyleInteraction::from_bits_retain(
+            (value.into() as u32) << StyleInteraction::CUSTOM_OFFSET,
+         );
+         entity_world_mut.set_style_states(custom_states - value, false);
+         entity_world_mut.set_style_states(value, true);
+      }
+   }
+}
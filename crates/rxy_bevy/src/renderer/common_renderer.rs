@@ -1,13 +1,22 @@
 use bevy_ui::prelude::Button;
-use bevy_ui::{FocusPolicy, Interaction};
+use bevy_ui::{FocusPolicy, Interaction, OverflowAxis, UiImage};
 
-use rxy_core::common_renderer::CommonRenderer;
-use rxy_core::{define_common_view_fns, ElementAttrMember, ElementView, MapToAttrMarker, XNest};
+use rxy_core::common_renderer::{CommonEventRenderer, CommonKeyEvent, CommonRenderer};
+use rxy_core::{
+   define_common_view_fns, ElementAttr, ElementAttrMember, ElementView, MapToAttrMarker, MaybeSend,
+   MaybeSync, XNest,
+};
 
+use crate::all_attrs::overflow_y;
 use crate::elements::{element_div, element_img, element_span, element_span_attrs};
+use crate::event::{x_pointer_click, BubblePointerEvent};
 #[cfg(feature = "dynamic_element")]
 use crate::DynamicBevyElement;
-use crate::{x_bundle, BevyRenderer, Focusable};
+use crate::{
+   x_bundle, BevyRenderer, Canvas, CommonClickHandler, CommonClickHandlerMarker,
+   CommonEventViewMember, EventViewMember, Focusable, InputValue, ScrollOffset, TextInput, Toggle,
+   ToggleValue, XBundle,
+};
 
 define_common_view_fns!(BevyRenderer);
 
@@ -21,6 +30,13 @@ impl CommonRenderer for BevyRenderer {
    type ButtonView =
       BevyElement<element_div, (crate::XBundle<(FocusPolicy, Interaction, Button, Focusable)>,)>;
    type ImgView = BevyElement<element_img, ()>;
+   type ScrollView =
+      BevyElement<element_div, ((ElementAttr<Self, overflow_y>, XBundle<(ScrollOffset,)>),)>;
+   type TextInputView =
+      BevyElement<element_span, (XBundle<(TextInput, FocusPolicy, Interaction, Focusable)>,)>;
+   type ToggleView =
+      BevyElement<element_div, (XBundle<(Toggle, FocusPolicy, Interaction, Button, Focusable)>,)>;
+   type CanvasView = BevyElement<element_div, (XBundle<(Canvas, UiImage)>,)>;
    type TextContentEA = element_span_attrs::content;
 
    fn crate_text<T>(
@@ -48,6 +64,36 @@ impl CommonRenderer for BevyRenderer {
    fn crate_img() -> Self::ImgView {
       BevyElement::default()
    }
+
+   fn crate_scroll() -> Self::ScrollView {
+      BevyElement::default().members((
+         ElementAttr::<Self, overflow_y>::new(OverflowAxis::Clip),
+         x_bundle((ScrollOffset::default(),)),
+      ))
+   }
+
+   fn crate_text_input() -> Self::TextInputView {
+      BevyElement::default().members(x_bundle((
+         TextInput::default(),
+         FocusPolicy::default(),
+         Interaction::default(),
+         Focusable::default(),
+      )))
+   }
+
+   fn crate_toggle() -> Self::ToggleView {
+      BevyElement::default().members(x_bundle((
+         Toggle::default(),
+         FocusPolicy::default(),
+         Interaction::default(),
+         Button,
+         Focusable::default(),
+      )))
+   }
+
+   fn crate_canvas() -> Self::CanvasView {
+      BevyElement::default().members(x_bundle((Canvas::default(), UiImage::default())))
+   }
 }
 
 #[cfg(feature = "dynamic_element")]
@@ -57,6 +103,10 @@ impl CommonRenderer for BevyRenderer {
       DynamicBevyElement<element_span>;
    type ButtonView = DynamicBevyElement<element_div>;
    type ImgView = DynamicBevyElement<element_img>;
+   type ScrollView = DynamicBevyElement<element_div>;
+   type TextInputView = DynamicBevyElement<element_span>;
+   type ToggleView = DynamicBevyElement<element_div>;
+   type CanvasView = DynamicBevyElement<element_div>;
    type TextContentEA = element_span_attrs::content;
 
    fn crate_text<T>(
@@ -84,4 +134,94 @@ impl CommonRenderer for BevyRenderer {
    fn crate_img() -> Self::ImgView {
       DynamicBevyElement::default()
    }
+
+   fn crate_scroll() -> Self::ScrollView {
+      DynamicBevyElement::default().members((
+         ElementAttr::<Self, overflow_y>::new(OverflowAxis::Clip),
+         x_bundle((ScrollOffset::default(),)),
+      ))
+   }
+
+   fn crate_text_input() -> Self::TextInputView {
+      DynamicBevyElement::default().members(x_bundle((
+         TextInput::default(),
+         FocusPolicy::default(),
+         Interaction::default(),
+         Focusable::default(),
+      )))
+   }
+
+   fn crate_toggle() -> Self::ToggleView {
+      DynamicBevyElement::default().members(x_bundle((
+         Toggle::default(),
+         FocusPolicy::default(),
+         Interaction::default(),
+         Button,
+         Focusable::default(),
+      )))
+   }
+
+   fn crate_canvas() -> Self::CanvasView {
+      DynamicBevyElement::default().members(x_bundle((Canvas::default(), UiImage::default())))
+   }
+}
+
+impl CommonEventRenderer for BevyRenderer {
+   type ClickMember<F>
+      = EventViewMember<BubblePointerEvent, CommonClickHandler<F>, CommonClickHandlerMarker>
+   where
+      F: FnMut() + MaybeSend + MaybeSync + 'static;
+   type InputMember<F>
+      = CommonEventViewMember<InputValue, F>
+   where
+      F: FnMut(String) + MaybeSend + MaybeSync + 'static;
+   type ToggleMember<F>
+      = CommonEventViewMember<ToggleValue, F>
+   where
+      F: FnMut(bool) + MaybeSend + MaybeSync + 'static;
+   type KeyDownMember<F>
+      = CommonEventViewMember<CommonKeyEvent, F>
+   where
+      F: FnMut(CommonKeyEvent) + MaybeSend + MaybeSync + 'static;
+
+   fn crate_click_member<F>(f: F) -> Self::ClickMember<F>
+   where
+      F: FnMut() + MaybeSend + MaybeSync + 'static,
+   {
+      EventViewMember {
+         element_event_ids: x_pointer_click(),
+         system: CommonClickHandler(f),
+         _marker: Default::default(),
+      }
+   }
+
+   fn crate_input_member<F>(f: F) -> Self::InputMember<F>
+   where
+      F: FnMut(String) + MaybeSend + MaybeSync + 'static,
+   {
+      CommonEventViewMember {
+         handler: f,
+         _marker: Default::default(),
+      }
+   }
+
+   fn crate_toggle_member<F>(f: F) -> Self::ToggleMember<F>
+   where
+      F: FnMut(bool) + MaybeSend + MaybeSync + 'static,
+   {
+      CommonEventViewMember {
+         handler: f,
+         _marker: Default::default(),
+      }
+   }
+
+   fn crate_key_down_member<F>(f: F) -> Self::KeyDownMember<F>
+   where
+      F: FnMut(CommonKeyEvent) + MaybeSend + MaybeSync + 'static,
+   {
+      CommonEventViewMember {
+         handler: f,
+         _marker: Default::default(),
+      }
+   }
 }
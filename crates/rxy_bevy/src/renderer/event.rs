@@ -1,12 +1,16 @@
 use std::hash::Hash;
 
 use bevy_ecs::prelude::IntoSystem;
+use bevy_ecs::system::IntoObserverSystem;
 use bevy_input::keyboard::KeyCode;
 
 use rxy_core::{ElementView, MemberOwner};
 
 use crate::event::*;
-use crate::{BevyRenderer, EventViewMember};
+use crate::{
+   BevyRenderer, EventViewMember, KeyChord, ShortcutOptions, ShortcutViewMember, UiEvent,
+   UiEventViewMember,
+};
 
 macro_rules! define_event_view_builder {
    ($name:ident;$ty:ident) => {
@@ -20,7 +24,7 @@ macro_rules! define_event_view_builder {
          ) -> Self::AddMember<EventViewMember<T, S, Marker>>
          where
             T: ElementEventIds,
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.member(EventViewMember {
@@ -37,7 +41,7 @@ macro_rules! define_event_view_builder {
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
             T: Copy + Eq + Hash + Send + Sync + 'static,
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pressed(events), system)
@@ -48,7 +52,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on_just_pressed(KeyCode::Enter, system)
@@ -59,7 +63,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on_just_pressed(KeyCode::Escape, system)
@@ -71,7 +75,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_just_pressed(events), system)
@@ -83,18 +87,63 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_just_released(events), system)
          }
 
+         fn on_shortcut<S, Marker>(
+            self,
+            chord: impl Into<KeyChord>,
+            system: S,
+         ) -> Self::AddMember<ShortcutViewMember<S, Marker>>
+         where
+            S: IntoSystem<(), (), Marker> + Send + 'static,
+            Marker: Send + 'static,
+         {
+            self.on_shortcut_with(chord, ShortcutOptions::default(), system)
+         }
+
+         fn on_shortcut_with<S, Marker>(
+            self,
+            chord: impl Into<KeyChord>,
+            options: ShortcutOptions,
+            system: S,
+         ) -> Self::AddMember<ShortcutViewMember<S, Marker>>
+         where
+            S: IntoSystem<(), (), Marker> + Send + 'static,
+            Marker: Send + 'static,
+         {
+            self.member(ShortcutViewMember {
+               chord: chord.into(),
+               options,
+               system,
+               _marker: Default::default(),
+            })
+         }
+
+         fn on_event<E, S, Marker>(
+            self,
+            system: S,
+         ) -> Self::AddMember<UiEventViewMember<E, S, Marker>>
+         where
+            E: Clone + Send + Sync + 'static,
+            S: IntoObserverSystem<UiEvent<E>, (), Marker>,
+            Marker: Send + 'static,
+         {
+            self.member(UiEventViewMember {
+               system,
+               _marker: Default::default(),
+            })
+         }
+
          fn on_pointer_over<S, Marker>(
             self,
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_over(), system)
@@ -105,7 +154,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_out(), system)
@@ -116,7 +165,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_down(), system)
@@ -127,7 +176,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_up(), system)
@@ -138,7 +187,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_click(), system)
@@ -149,7 +198,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_move(), system)
@@ -159,7 +208,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_drag_start(), system)
@@ -169,7 +218,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_drag(), system)
@@ -179,7 +228,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_drag_end(), system)
@@ -189,7 +238,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_drag_enter(), system)
@@ -199,7 +248,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_drag_over(), system)
@@ -209,7 +258,7 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_drag_leave(), system)
@@ -219,11 +268,110 @@ macro_rules! define_event_view_builder {
             system: S,
          ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
          where
-            S: IntoSystem<(), (), Marker> + Send + 'static,
+            S: IntoEventHandler<Marker>,
             Marker: Send + 'static,
          {
             self.on(x_pointer_drop(), system)
          }
+
+         fn on_pointer_wheel<S, Marker>(
+            self,
+            system: S,
+         ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+         where
+            S: IntoEventHandler<Marker>,
+            Marker: Send + 'static,
+         {
+            self.on(x_pointer_wheel(), system)
+         }
+
+         fn on_pointer_double_click<S, Marker>(
+            self,
+            system: S,
+         ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+         where
+            S: IntoEventHandler<Marker>,
+            Marker: Send + 'static,
+         {
+            self.on(x_pointer_double_click(), system)
+         }
+
+         fn on_pointer_long_press<S, Marker>(
+            self,
+            system: S,
+         ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+         where
+            S: IntoEventHandler<Marker>,
+            Marker: Send + 'static,
+         {
+            self.on(x_pointer_long_press(), system)
+         }
+
+         fn on_context_menu<S, Marker>(
+            self,
+            system: S,
+         ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+         where
+            S: IntoEventHandler<Marker>,
+            Marker: Send + 'static,
+         {
+            self.on(x_context_menu(), system)
+         }
+
+         fn on_tap<S, Marker>(
+            self,
+            system: S,
+         ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+         where
+            S: IntoEventHandler<Marker>,
+            Marker: Send + 'static,
+         {
+            self.on(x_tap(), system)
+         }
+
+         fn on_pan<S, Marker>(
+            self,
+            system: S,
+         ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+         where
+            S: IntoEventHandler<Marker>,
+            Marker: Send + 'static,
+         {
+            self.on(x_pan(), system)
+         }
+
+         fn on_pinch<S, Marker>(
+            self,
+            system: S,
+         ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+         where
+            S: IntoEventHandler<Marker>,
+            Marker: Send + 'static,
+         {
+            self.on(x_pinch(), system)
+         }
+
+         fn on_rotate<S, Marker>(
+            self,
+            system: S,
+         ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+         where
+            S: IntoEventHandler<Marker>,
+            Marker: Send + 'static,
+         {
+            self.on(x_rotate(), system)
+         }
+
+         fn on_swipe<S, Marker>(
+            self,
+            system: S,
+         ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+         where
+            S: IntoEventHandler<Marker>,
+            Marker: Send + 'static,
+         {
+            self.on(x_swipe(), system)
+         }
       }
    };
 }
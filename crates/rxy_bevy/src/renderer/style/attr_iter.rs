@@ -7,16 +7,16 @@ use bevy_ui::Interaction;
 use rxy_core::prelude::EitherExt;
 use rxy_core::style::{
    IterExt, NodeInterStyleAttrInfos, NodeStyleAttrInfo, NodeStyleAttrInfos, PipeOp,
-   StyleInteraction,
 };
 use rxy_core::AttrIndex;
 
-use super::interaction_style::AttrSetBitsIterExt;
+use super::interaction_style::{node_style_interaction, AttrSetBitsIterExt};
 use super::rxy_bevy_crate::{
    AttrSetBits, ElementEntityExtraData, ElementEntityWorldMutExt, FocusedEntity, RendererState,
 };
+use super::state_style::NodeStyleStates;
+use super::EntityAttrSyncer;
 use super::Result;
-use super::{interaction_to_style_interaction, EntityAttrSyncer};
 
 #[derive(Default)]
 pub struct EntityStyleAttrInfoIterArgs<'a> {
@@ -110,14 +110,11 @@ impl<'a> EntityStyleAttrInfoIterArgs<'a> {
                .get_ref::<RendererState<NodeInterStyleAttrInfos>>()
                .map(|n| n.into_inner()),
             |_, entity_inter_style_state| {
-               let mut node_interaction = entity_ref
-                  .get::<Interaction>()
-                  .cloned()
-                  .and_then(interaction_to_style_interaction)
-                  .unwrap_or(StyleInteraction::empty());
-               if focused_entity == Some(entity_ref.id()) {
-                  node_interaction |= StyleInteraction::Focus;
-               }
+               let node_interaction = node_style_interaction(
+                  entity_ref.get::<Interaction>().cloned(),
+                  entity_ref.get::<NodeStyleStates>(),
+                  focused_entity == Some(entity_ref.id()),
+               );
 
                match self.limit_attr_ids {
                   Some(n) => n
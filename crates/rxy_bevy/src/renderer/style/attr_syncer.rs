@@ -3,8 +3,7 @@ use bevy_ecs::world::EntityWorldMut;
 use rxy_core::prelude::Either;
 use rxy_core::style::{NodeInterStyleItemId, NodeStyleAttrInfo, NodeStyleItemId};
 
-use crate::attrs::get_attr_by_index;
-
+use super::var_style::set_style_attr_value;
 use super::{Result, StyleStateOwner};
 
 pub trait EntityAttrSyncer {
@@ -43,7 +42,7 @@ impl EntityAttrSyncer for NodeStyleItemId {
          .get_style_item_value(node_id, self)
          .map(|n| n.clone().value)?;
       entity_world_mut.world_scope(|world| {
-         get_attr_by_index(attr_index).set_value(world, node_id, Some(value));
+         set_style_attr_value(world, node_id, attr_index, Some(value));
       });
       Ok(())
    }
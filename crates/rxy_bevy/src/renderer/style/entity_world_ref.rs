@@ -9,15 +9,16 @@ use bevy_ecs::{
 use bevy_hierarchy::DespawnRecursiveExt;
 
 use rxy_core::style::{
-   NodeInterStyleAttrInfos, NodeStyleAttrInfos, StyleSheetDefinition, StyleSheetId,
+   NodeInterStyleAttrInfos, NodeStyleAttrInfos, StyleMedia, StyleSheetDefinition, StyleSheetId,
    StyleSheetLocation,
 };
 
-use super::style_state_owner::NodeStyleStateOwner;
+use super::media_style::AppliedStyleMedia;
 use super::node_style_state::NodeStyleSheetsState;
 use super::plugin::TypedEntities;
 use super::rxy_bevy_crate::EntityWorldMutExt;
 use super::rxy_bevy_crate::{BevyRenderer, RendererState};
+use super::style_state_owner::NodeStyleStateOwner;
 use super::{Result, SharedStyleState, StyleError};
 
 #[derive(Copy, Clone)]
@@ -260,6 +261,7 @@ pub trait StyleWorldExt {
       f: impl FnOnce(&mut EntityWorldMut<'a>, &mut StyleSheetDefinition) -> U,
    ) -> Result<U>;
    fn insert_typed_entity(&mut self, type_id: TypeId, entity: Entity);
+   fn applied_style_media(&self) -> StyleMedia;
 }
 
 impl StyleWorldExt for World {
@@ -343,4 +345,11 @@ impl StyleWorldExt for World {
          self.entity_mut(prev).despawn_recursive()
       }
    }
+
+   fn applied_style_media(&self) -> StyleMedia {
+      self
+         .get_resource::<AppliedStyleMedia>()
+         .map(|n| n.0)
+         .unwrap_or_default()
+   }
 }
@@ -1,23 +1,29 @@
 use bevy_ecs::entity::Entity;
 use bevy_ecs::prelude::{Query, Res};
 use bevy_ecs::system::{Commands, ResMut};
+use bevy_ui::Interaction;
 
 use rxy_core::style::{NodeInterStyleAttrInfos, NodeStyleAttrInfos, StyleInteraction};
 
-use super::style_state_owner::{EntityStyleWorldQuery, NodeStyleStateOwner};
-use super::interaction_style::{AttrSetBitsIterExt, SetAttrValuesCommand};
+use super::interaction_style::{node_style_interaction, AttrSetBitsIterExt, SetAttrValuesCommand};
 use super::node_style_state::NodeStyleSheetsState;
 use super::rxy_bevy_crate::{ElementEntityExtraData, FocusedEntity, RendererState};
+use super::state_style::NodeStyleStates;
+use super::style_state_owner::{EntityStyleWorldQuery, NodeStyleStateOwner};
 use super::Previous;
 
+type StyledQueryData = (
+   &'static ElementEntityExtraData,
+   &'static RendererState<NodeInterStyleAttrInfos>,
+   &'static RendererState<NodeStyleAttrInfos>,
+   Option<&'static Interaction>,
+   Option<&'static NodeStyleStates>,
+);
+
 pub fn update_focus_style(
    mut commands: Commands,
    style_sheets_query: Query<&RendererState<NodeStyleSheetsState>>,
-   styled_query: Query<(
-      &ElementEntityExtraData,
-      &RendererState<NodeInterStyleAttrInfos>,
-      &RendererState<NodeStyleAttrInfos>,
-   )>,
+   styled_query: Query<StyledQueryData>,
    focus: Res<FocusedEntity>,
    mut previous_focus: ResMut<Previous<FocusedEntity>>,
 ) {
@@ -40,17 +46,13 @@ pub fn update_focus_style(
 fn do_f<'a, 'world, 'state>(
    previous_focus_entity: Option<Entity>,
    focus_entity: Option<Entity>,
-   styled_query: &Query<(
-      &ElementEntityExtraData,
-      &RendererState<NodeInterStyleAttrInfos>,
-      &RendererState<NodeStyleAttrInfos>,
-   )>,
+   styled_query: &Query<StyledQueryData>,
    set_attrs_cmd: &mut SetAttrValuesCommand,
    style_sheets_query: Query<'world, 'state, &'a RendererState<NodeStyleSheetsState>>,
 ) -> Query<'world, 'state, &'a RendererState<NodeStyleSheetsState>> {
    match (previous_focus_entity, focus_entity) {
       (None, Some(focus_entity)) => {
-         let Ok((entity_extra_data, RendererState(inter_attr_infos), _)) =
+         let Ok((entity_extra_data, RendererState(inter_attr_infos), _, interaction, states)) =
             styled_query.get(focus_entity)
          else {
             return style_sheets_query;
@@ -64,13 +66,14 @@ fn do_f<'a, 'world, 'state>(
             current_entity: focus_entity,
          };
 
+         let node_interaction = node_style_interaction(interaction.cloned(), states, true);
          for (attr_index, attr_info) in focus_attr_infos
             .iter()
             .map(|n| (*n.0, ()))
             .filter_attr_already_set(entity_extra_data.attr_is_set)
             .filter_map(|(attr_index, _)| {
-               focus_attr_infos
-                  .get(&attr_index)
+               inter_attr_infos
+                  .match_attr(attr_index, node_interaction, false)
                   .map(|attr_info| (attr_index, attr_info))
             })
          {
@@ -82,8 +85,13 @@ fn do_f<'a, 'world, 'state>(
          entity_style_world_query.query
       }
       (Some(previous_focus), None) => {
-         let Ok((entity_extra_data, RendererState(inter_attr_infos), RendererState(attr_infos))) =
-            styled_query.get(previous_focus)
+         let Ok((
+            entity_extra_data,
+            RendererState(inter_attr_infos),
+            RendererState(attr_infos),
+            interaction,
+            states,
+         )) = styled_query.get(previous_focus)
          else {
             return style_sheets_query;
          };
@@ -99,15 +107,19 @@ fn do_f<'a, 'world, 'state>(
             current_entity: previous_focus,
          };
 
+         let node_interaction = node_style_interaction(interaction.cloned(), states, false);
          for (attr_index, _) in focus_attr_infos
             .map(|n| (*n, ()))
             .filter_attr_already_set(entity_extra_data.attr_is_set)
          {
-            let value = attr_infos.get(&attr_index).map(|attr_info| {
-               entity_style_world_query
-                  .get_current_style_item_value(attr_info.top_item_id())
-                  .unwrap()
-            });
+            let value = inter_attr_infos
+               .match_attr(attr_index, node_interaction, false)
+               .or_else(|| attr_infos.get(&attr_index))
+               .map(|attr_info| {
+                  entity_style_world_query
+                     .get_current_style_item_value(attr_info.top_item_id())
+                     .unwrap()
+               });
             set_attrs_cmd.add(previous_focus, attr_index, value);
          }
          entity_style_world_query.query
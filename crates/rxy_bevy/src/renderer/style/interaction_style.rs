@@ -10,11 +10,11 @@ use rxy_core::style::{
 };
 use rxy_core::AttrIndex;
 
-use crate::attrs::get_attr_by_index;
-
-use super::style_state_owner::{EntityStyleWorldQuery, NodeStyleStateOwner};
 use super::node_style_state::NodeStyleSheetsState;
 use super::rxy_bevy_crate::{AttrSetBits, ElementEntityExtraData, FocusedEntity, RendererState};
+use super::state_style::NodeStyleStates;
+use super::style_state_owner::{EntityStyleWorldQuery, NodeStyleStateOwner};
+use super::var_style::set_style_attr_value;
 use super::Previous;
 
 #[derive(Default, DerefMut, Deref, Debug)]
@@ -47,7 +47,7 @@ impl Command for SetAttrValuesCommand {
             .attr_is_set;
          let world = entity_world_mut.into_world_mut();
          for (attr_index, value) in changed.into_iter().filter_attr_already_set(attr_is_set) {
-            get_attr_by_index(attr_index).set_value(world, entity, value);
+            set_style_attr_value(world, entity, attr_index, value);
          }
       }
    }
@@ -61,6 +61,24 @@ pub fn interaction_to_style_interaction(interaction: Interaction) -> Option<Styl
    }
 }
 
+/// All the interactions and user states the node currently matches
+pub fn node_style_interaction(
+   interaction: Option<Interaction>,
+   states: Option<&NodeStyleStates>,
+   is_focused: bool,
+) -> StyleInteraction {
+   let mut node_interaction = interaction
+      .and_then(interaction_to_style_interaction)
+      .unwrap_or(StyleInteraction::empty());
+   if let Some(states) = states {
+      node_interaction |= states.0;
+   }
+   if is_focused {
+      node_interaction |= StyleInteraction::Focus;
+   }
+   node_interaction
+}
+
 pub trait AttrSetBitsIterExt<M> {
    fn filter_attr_already_set(
       self,
@@ -93,6 +111,7 @@ pub fn update_interaction_styles(
          &RendererState<NodeStyleAttrInfos>,
          &Interaction,
          &mut Previous<Interaction>,
+         Option<&NodeStyleStates>,
       ),
       (
          Changed<Interaction>,
@@ -115,6 +134,7 @@ pub fn update_interaction_styles(
       RendererState(entity_style_state),
       interaction,
       mut previous_interaction,
+      states,
    ) in inter_styled_query.iter_mut()
    {
       if entity_inter_style_state.is_empty() {
@@ -153,18 +173,18 @@ pub fn update_interaction_styles(
                   continue;
                }
                attr_bits |= 1 << attr_index;
-               let value = if is_focused {
-                  entity_inter_style_state
-                     .get_attr_info(StyleInteraction::Focus, attr_index)
-                     .or_else(|| entity_style_state.get(&attr_index))
-               } else {
-                  entity_style_state.get(&attr_index)
-               }
-               .map(|attr_info| {
-                  entity_style_world_query
-                     .get_current_style_item_value(attr_info.top_item_id())
-                     .unwrap()
-               });
+               let value = entity_inter_style_state
+                  .match_attr(
+                     attr_index,
+                     node_style_interaction(None, states, is_focused),
+                     false,
+                  )
+                  .or_else(|| entity_style_state.get(&attr_index))
+                  .map(|attr_info| {
+                     entity_style_world_query
+                        .get_current_style_item_value(attr_info.top_item_id())
+                        .unwrap()
+                  });
                set_attrs_cmd.add(entity, attr_index, value);
             }
          }
@@ -176,7 +196,7 @@ pub fn update_interaction_styles(
                *focus = FocusedEntity(Some(entity));
             }
 
-            for (attr_index, matched_interaction) in entity_inter_style_state
+            for (attr_index, _matched_interaction) in entity_inter_style_state
                .iter_match_attr_ids(
                   style_interaction,
                   prev_interaction == Interaction::Pressed && interaction == Interaction::Hovered,
@@ -187,8 +207,13 @@ pub fn update_interaction_styles(
                   continue;
                }
                attr_bits |= 1 << attr_index;
+               // user states like disabled may have a higher priority than the interaction
                let value = entity_inter_style_state
-                  .get_attr_info(matched_interaction, attr_index)
+                  .match_attr(
+                     attr_index,
+                     node_style_interaction(Some(interaction), states, is_focused),
+                     false,
+                  )
                   .map(|attr_info| {
                      entity_style_world_query
                         .get_current_style_item_value(attr_info.top_item_id())
@@ -0,0 +1,227 @@
+use bevy_derive::Deref;
+use bevy_ecs::prelude::{Entity, EntityWorldMut, QueryState, Resource, With, World};
+use bevy_utils::tracing::warn;
+use bevy_window::{PrimaryWindow, Window, WindowTheme};
+
+use rxy_core::style::{
+   AttrStyleOwner, NodeStyleAttrInfos, NodeStyleSheetId, StyleMedia, StyleSheetDefinition,
+   StyleSheetLocation,
+};
+use rxy_core::AttrIndex;
+
+use super::node_style_state::NodeStyleSheetsState;
+use super::rxy_bevy_crate::{ElementEntityExtraData, RendererState};
+use super::var_style::set_style_attr_value;
+use super::{
+   EntityStyleAttrInfoIterArgs, Result, StyleEntityWorldMutExt, StyleError, StyleWorldExt,
+};
+
+/// User preferences the window does not report.
+#[derive(Resource, Default, Clone, Debug)]
+pub struct StyleMediaPreferences {
+   pub reduced_motion: bool,
+   /// Overrides the theme of the primary window
+   pub dark: Option<bool>,
+}
+
+/// The media that the style sheets of all nodes are currently applied for.
+#[derive(Resource, Default, Clone, Debug, Deref)]
+pub struct AppliedStyleMedia(pub StyleMedia);
+
+pub fn update_media_styles(
+   world: &mut World,
+   window_query: &mut QueryState<&Window, With<PrimaryWindow>>,
+   styled_query: &mut QueryState<Entity, With<RendererState<NodeStyleSheetsState>>>,
+) {
+   let Ok(window) = window_query.get_single(world) else {
+      return;
+   };
+   let preferences = world
+      .get_resource::<StyleMediaPreferences>()
+      .cloned()
+      .unwrap_or_default();
+   let media = StyleMedia {
+      width: window.width(),
+      height: window.height(),
+      scale_factor: window.scale_factor(),
+      reduced_motion: preferences.reduced_motion,
+      dark: preferences
+         .dark
+         .unwrap_or(window.window_theme == Some(WindowTheme::Dark)),
+   };
+   let prev_media = world.applied_style_media();
+   if prev_media == media {
+      return;
+   }
+   world.insert_resource(AppliedStyleMedia(media));
+
+   let entities = styled_query.iter(world).collect::<Vec<_>>();
+   for entity in entities {
+      if let Err(err) = apply_style_media_change(world.entity_mut(entity), &prev_media, &media) {
+         warn!("apply style media change failed: {:?}", err);
+      }
+   }
+}
+
+fn apply_style_media_change(
+   mut entity_world_mut: EntityWorldMut,
+   prev_media: &StyleMedia,
+   media: &StyleMedia,
+) -> Result {
+   let node_id = entity_world_mut.id();
+   let attr_is_set = entity_world_mut
+      .get::<ElementEntityExtraData>()
+      .map(|n| n.attr_is_set)
+      .unwrap_or_default();
+   let mut changed_attr_ids = vec![];
+   let mut reset_attr_ids = vec![];
+
+   entity_world_mut.scoped_style_sheets_state(|entity_world_mut, style_sheets_state| {
+      entity_world_mut.scoped_style_state(|entity_world_mut, style_state| {
+         for (style_sheet_index, style_sheet_definition) in
+            style_sheets_state.inline_style_sheet.iter().enumerate()
+         {
+            let Some(style_sheet_definition) = style_sheet_definition else {
+               continue;
+            };
+            toggle_media_style_sheet(
+               entity_world_mut,
+               style_state,
+               style_sheet_definition,
+               NodeStyleSheetId {
+                  index: style_sheet_index as _,
+                  location: StyleSheetLocation::Inline,
+               },
+               (prev_media, media),
+               &mut changed_attr_ids,
+               &mut reset_attr_ids,
+            )?;
+         }
+
+         for (style_sheet_index, style_sheet_id) in
+            style_sheets_state.shared_style_sheet_ids.iter().enumerate()
+         {
+            let Some(style_sheet_id) = style_sheet_id else {
+               continue;
+            };
+            let style_sheet_definition = entity_world_mut
+               .world()
+               .get_style_sheet_definition_ref(style_sheet_id.clone())?;
+            if style_sheet_definition.media.is_none() {
+               continue;
+            }
+            let style_sheet_definition = style_sheet_definition.clone();
+            toggle_media_style_sheet(
+               entity_world_mut,
+               style_state,
+               &style_sheet_definition,
+               NodeStyleSheetId {
+                  index: style_sheet_index as _,
+                  location: StyleSheetLocation::Shared,
+               },
+               (prev_media, media),
+               &mut changed_attr_ids,
+               &mut reset_attr_ids,
+            )?;
+         }
+         Ok::<(), StyleError>(())
+      })??;
+      Ok::<(), StyleError>(())
+   })??;
+
+   if changed_attr_ids.is_empty() {
+      return Ok(());
+   }
+
+   entity_world_mut.world_scope(|world: &mut World| {
+      for attr_index in reset_attr_ids.iter().cloned() {
+         if !ElementEntityExtraData::static_is_set_attr(attr_is_set, attr_index) {
+            set_style_attr_value(world, node_id, attr_index, None);
+         }
+      }
+   });
+
+   EntityStyleAttrInfoIterArgs {
+      limit_attr_ids: Some(changed_attr_ids.as_slice()),
+      ..EntityStyleAttrInfoIterArgs::all_kind()
+   }
+   .iter_and_sync_set(entity_world_mut)
+}
+
+/// Add or remove the items of the style sheet if its media query result changed.
+fn toggle_media_style_sheet(
+   entity_world_mut: &mut EntityWorldMut,
+   style_state: &mut NodeStyleAttrInfos,
+   style_sheet_definition: &StyleSheetDefinition,
+   style_sheet_id: NodeStyleSheetId,
+   (prev_media, media): (&StyleMedia, &StyleMedia),
+   changed_attr_ids: &mut Vec<AttrIndex>,
+   reset_attr_ids: &mut Vec<AttrIndex>,
+) -> Result {
+   let is_matched = style_sheet_definition.is_media_matched(media);
+   if style_sheet_definition.items.is_empty()
+      || style_sheet_definition.selector.is_some()
+      || style_sheet_definition.is_media_matched(prev_media) == is_matched
+   {
+      return Ok(());
+   }
+   changed_attr_ids.extend(style_sheet_definition.items.iter().map(|n| n.attr_id));
+   set_style_sheet_items_applied(
+      entity_world_mut,
+      style_state,
+      style_sheet_definition,
+      style_sheet_id,
+      is_matched,
+      reset_attr_ids,
+   )
+}
+
+/// Add the items of the style sheet to the normal or interaction attr infos of the node, or
+/// remove them and collect the attrs that lost their last style item.
+pub(super) fn set_style_sheet_items_applied(
+   entity_world_mut: &mut EntityWorldMut,
+   style_state: &mut NodeStyleAttrInfos,
+   style_sheet_definition: &StyleSheetDefinition,
+   style_sheet_id: NodeStyleSheetId,
+   applied: bool,
+   reset_attr_ids: &mut Vec<AttrIndex>,
+) -> Result {
+   let node_id = entity_world_mut.id();
+   let require_reset_f = |attr_id: AttrIndex| reset_attr_ids.push(attr_id);
+   if style_sheet_definition.interaction.is_some() {
+      entity_world_mut.scoped_inter_style_state_or_default(
+         |entity_world_mut, inter_style_state| -> Result {
+            if applied {
+               style_sheet_definition.add_to(
+                  inter_style_state,
+                  style_sheet_id.location,
+                  style_sheet_id.index,
+                  entity_world_mut.world(),
+                  node_id,
+               )
+            } else {
+               inter_style_state.remove_attr_style_of_definition(
+                  style_sheet_definition,
+                  style_sheet_id,
+                  require_reset_f,
+               )
+            }
+         },
+      )??;
+   } else if applied {
+      style_sheet_definition.add_to(
+         style_state,
+         style_sheet_id.location,
+         style_sheet_id.index,
+         entity_world_mut.world(),
+         node_id,
+      )?;
+   } else {
+      style_state.remove_attr_style_of_definition(
+         style_sheet_definition,
+         style_sheet_id,
+         require_reset_f,
+      )?;
+   }
+   Ok(())
+}
@@ -1,16 +1,26 @@
 use std::any::TypeId;
 
 pub use attr_iter::EntityStyleAttrInfoIterArgs;
-pub(crate) use style_state_owner::StyleStateOwner;
 pub use attr_syncer::EntityAttrSyncer;
 pub use element_view_ext::*;
 pub use entity_world_ref::*;
 pub use interaction_style::interaction_to_style_interaction;
+pub use media_style::{AppliedStyleMedia, StyleMediaPreferences};
 pub use plugin::{Previous, RxyStyleSheetPlugin};
 use rxy_bevy_crate::BevyRenderer;
 use rxy_core::style::{AppliedStyleSheet, StyleSheetCtx, StyleSheetsInfo};
 pub use shared_style_sheets::SharedStyleState;
 pub use shared_style_view::*;
+pub use state_style::{NodeStyleStates, StyleStatesEntityWorldMutExt};
+pub use style_report::StyleReportWorldExt;
+pub use style_sheet_asset::{replace_shared_style_sheets, StyleSheetAsset, StyleSheetAssetLoader};
+pub use style_sheet_parser::{
+   parse_reflect_value, parse_style_sheets, LabeledStyleSheets, StyleSheetParseError,
+};
+pub(crate) use style_state_owner::StyleStateOwner;
+pub use var_style::{
+   resolve_style_var, NodeStyleVarDependencies, NodeStyleVars, StyleVarsEntityWorldMutExt,
+};
 
 pub use crate as rxy_bevy_crate;
 
@@ -20,21 +30,29 @@ mod element_view_ext;
 mod entity_world_ref;
 mod focus_style;
 mod interaction_style;
+mod media_style;
 mod node_style_state;
 mod node_tree;
 mod plugin;
+mod res_style_sheets;
+mod selector_style;
 mod shared_style_sheets;
 mod shared_style_view;
-mod res_style_sheets;
+mod state_style;
+mod style_report;
+mod style_sheet_asset;
+mod style_sheet_parser;
 mod style_state_owner;
+mod var_style;
 
 pub type Result<T = ()> = rxy_core::style::Result<BevyRenderer, T>;
 pub type StyleError = rxy_core::style::StyleError<BevyRenderer>;
 
 pub mod prelude {
    pub use super::{
-      typed_shared_style_sheets, RxyStyleSheetPlugin, SchemaCtxExt, StyleError,
-      TypedStyleLabel,
+      typed_shared_style_sheets, NodeStyleStates, RxyStyleSheetPlugin, SchemaCtxExt, StyleError,
+      StyleMediaPreferences, StyleReportWorldExt, StyleSheetAsset, StyleStatesEntityWorldMutExt,
+      StyleVarsEntityWorldMutExt, TypedStyleLabel,
    };
 }
 
@@ -1,8 +1,8 @@
 use bevy_ecs::prelude::Entity;
 
 use rxy_core::style::{
-   AppliedStyleSheet, ApplyStyleSheetsMemberState, NodeStyleSheetId, StyleSheetDefinition,
-   StyleSheetId, StyleSheetIndex, StyleSheetLocation, StyleSheetsInfo,
+   to_style_sheet_index, AppliedStyleSheet, ApplyStyleSheetsMemberState, NodeStyleSheetId,
+   StyleSheetDefinition, StyleSheetId, StyleSheetIndex, StyleSheetLocation, StyleSheetsInfo,
 };
 
 use super::rxy_bevy_crate::BevyRenderer;
@@ -59,9 +59,10 @@ impl NodeStyleSheetsState {
    }
 
    pub fn style_sheets_info(&self) -> StyleSheetsInfo {
+      let count = |len: usize| StyleSheetIndex::try_from(len).unwrap_or(StyleSheetIndex::MAX);
       StyleSheetsInfo {
-         inline_style_sheet_count: self.inline_style_sheet.len() as _,
-         shared_style_sheet_count: self.shared_style_sheet_ids.len() as _,
+         inline_style_sheet_count: count(self.inline_style_sheet.len()),
+         shared_style_sheet_count: count(self.shared_style_sheet_ids.len()),
       }
    }
 }
@@ -121,11 +122,15 @@ impl NodeStyleSheetsState {
       Ok(style_sheet_id.clone())
    }
 
-   pub fn get_style_sheet_len(&self, location: StyleSheetLocation) -> StyleSheetIndex {
-      (match location {
-         StyleSheetLocation::Inline => self.inline_style_sheet.len(),
-         StyleSheetLocation::Shared => self.shared_style_sheet_ids.len(),
-      }) as _
+   /// The index of the next style sheet pushed to `location`
+   pub fn get_style_sheet_len(&self, location: StyleSheetLocation) -> Result<StyleSheetIndex> {
+      to_style_sheet_index(
+         match location {
+            StyleSheetLocation::Inline => self.inline_style_sheet.len(),
+            StyleSheetLocation::Shared => self.shared_style_sheet_ids.len(),
+         },
+         location,
+      )
    }
    pub fn push_applied_style_sheet(
       &mut self,
@@ -185,3 +190,165 @@ impl NodeStyleSheetsState {
          .filter_map(|n| n.1.take().map(|s| (n.0 as _, s)))
    }
 }
+
+#[cfg(test)]
+mod tests {
+   use bevy_ecs::world::World;
+
+   use rxy_core::style::{AttrStyleOwner, NodeStyleAttrInfos, StyleItemIndex, StyleItemValue};
+   use rxy_core::AttrValue;
+
+   use super::*;
+
+   /// `attr_count` attrs set in turn by `item_count` items
+   fn generated_style_sheet(item_count: usize, attr_count: usize) -> StyleSheetDefinition {
+      StyleSheetDefinition {
+         items: (0..item_count)
+            .map(|i| StyleItemValue {
+               attr_id: (i % attr_count) as _,
+               value: (i as f32).clone_att_value(),
+            })
+            .collect(),
+         ..Default::default()
+      }
+   }
+
+   #[test]
+   fn add_and_remove_large_generated_style_sheets() {
+      let mut world = World::new();
+      let node_id = world.spawn_empty().id();
+      let mut style_sheets_state = NodeStyleSheetsState::default();
+      let mut style_state = NodeStyleAttrInfos::default();
+
+      for _ in 0..300 {
+         let style_sheet_index = style_sheets_state
+            .get_style_sheet_len(StyleSheetLocation::Inline)
+            .unwrap();
+         let style_sheet_definition = generated_style_sheet(300, 100);
+         style_sheet_definition
+            .add_to::<BevyRenderer, _>(
+               &mut style_state,
+               StyleSheetLocation::Inline,
+               style_sheet_index,
+               &world,
+               node_id,
+            )
+            .unwrap();
+         style_sheets_state
+            .push_applied_style_sheet(AppliedStyleSheet::Inline(style_sheet_definition));
+      }
+      assert_eq!(
+         style_sheets_state
+            .style_sheets_info()
+            .inline_style_sheet_count,
+         300
+      );
+
+      let top_item_id = style_state.get(&0).unwrap().top_item_id();
+      assert_eq!(top_item_id.sheet_id.index, 299);
+      assert_eq!(top_item_id.item_index, 200);
+
+      let removed_style_sheets = style_sheets_state
+         .take_inline_style_sheets_from_member(ApplyStyleSheetsMemberState {
+            inline_sheet_index: 0,
+            inline_sheet_count: 300,
+            shared_sheet_index: 0,
+            shared_sheet_count: 0,
+         })
+         .collect::<Vec<_>>();
+      for (index, style_sheet_definition) in removed_style_sheets.into_iter().rev() {
+         AttrStyleOwner::<BevyRenderer>::remove_attr_style_of_definition(
+            &mut style_state,
+            &style_sheet_definition,
+            NodeStyleSheetId {
+               index,
+               location: StyleSheetLocation::Inline,
+            },
+            |_| {},
+         )
+         .unwrap();
+      }
+      assert!(style_state.is_empty());
+   }
+
+   #[test]
+   fn style_index_overflow() {
+      let mut world = World::new();
+      let node_id = world.spawn_empty().id();
+      let mut style_state = NodeStyleAttrInfos::default();
+
+      let style_sheet_definition = generated_style_sheet(StyleItemIndex::MAX as usize + 2, 1);
+      assert!(matches!(
+         style_sheet_definition.add_to::<BevyRenderer, _>(
+            &mut style_state,
+            StyleSheetLocation::Inline,
+            0,
+            &world,
+            node_id,
+         ),
+         Err(StyleError::StyleItemIndexOverflow { .. })
+      ));
+      assert!(style_state.is_empty());
+
+      let mut member_state = ApplyStyleSheetsMemberState {
+         inline_sheet_index: StyleSheetIndex::MAX - 1,
+         inline_sheet_count: 1,
+         shared_sheet_index: 0,
+         shared_sheet_count: 0,
+      };
+      assert!(member_state
+         .get_and_increment_and_by_location::<BevyRenderer>(StyleSheetLocation::Inline)
+         .is_ok());
+      assert!(matches!(
+         member_state.get_and_increment_and_by_location::<BevyRenderer>(StyleSheetLocation::Inline),
+         Err(StyleError::StyleSheetIndexOverflow { .. })
+      ));
+   }
+
+   #[test]
+   fn remove_style_sheet_below_the_top() {
+      let mut world = World::new();
+      let node_id = world.spawn_empty().id();
+      let mut style_state = NodeStyleAttrInfos::default();
+      let style_sheet_definition = StyleSheetDefinition {
+         items: vec![StyleItemValue {
+            attr_id: 0,
+            value: 1f32.clone_att_value(),
+         }],
+         ..Default::default()
+      };
+      for index in 0..3 {
+         style_sheet_definition
+            .add_to::<BevyRenderer, _>(
+               &mut style_state,
+               StyleSheetLocation::Inline,
+               index,
+               &world,
+               node_id,
+            )
+            .unwrap();
+      }
+
+      fn remove(
+         style_state: &mut NodeStyleAttrInfos,
+         style_sheet_definition: &StyleSheetDefinition,
+         index: StyleSheetIndex,
+      ) {
+         AttrStyleOwner::<BevyRenderer>::remove_attr_style_of_definition(
+            style_state,
+            style_sheet_definition,
+            NodeStyleSheetId {
+               index,
+               location: StyleSheetLocation::Inline,
+            },
+            |_| {},
+         )
+         .unwrap();
+      }
+      remove(&mut style_state, &style_sheet_definition, 1);
+      remove(&mut style_state, &style_sheet_definition, 0);
+      assert_eq!(style_state.get(&0).unwrap().top_item_id().sheet_id.index, 2);
+      remove(&mut style_state, &style_sheet_definition, 2);
+      assert!(style_state.is_empty());
+   }
+}
@@ -1,21 +1,26 @@
+use std::borrow::Cow;
+
 use bevy_ecs::prelude::{EntityWorldMut, World};
 use bevy_ui::Interaction;
 
 use rxy_core::style::{
-   AppliedStyleSheet, ApplyStyleSheetsMemberState, AttrStyleOwner, NodeInterStyleAttrInfos,
-   NodeStyleAttrInfos, NodeStyleSheetId, StyleSheetCtx, StyleSheetDefinition, StyleSheetLocation,
-   StyleSheets, StyledNodeTree,
+   to_style_sheet_index, AppliedStyleSheet, ApplyStyleSheetsMemberState, AttrStyleOwner,
+   NodeInterStyleAttrInfos, NodeStyleAttrInfos, NodeStyleSheetId, StyleAttrValue, StyleMedia,
+   StyleSheetCtx, StyleSheetDefinition, StyleSheetLocation, StyleSheets, StyledNodeTree,
 };
 use rxy_core::{AttrIndex, RendererNodeId};
 
-use crate::attrs::get_attr_by_index;
 use crate::renderer::style::node_style_state::NodeStyleSheetsState;
 use crate::renderer::style::{
-   EntityStyleAttrInfoIterArgs, Previous, StyleEntityMutExt, StyleEntityWorldMutExt,
+   AppliedStyleMedia, EntityStyleAttrInfoIterArgs, Previous, StyleEntityMutExt,
+   StyleEntityWorldMutExt,
 };
 use crate::{ElementEntityExtraData, ElementEntityWorldMutExt, EntityWorldMutExt, RendererState};
 
 use super::rxy_bevy_crate::BevyRenderer;
+use super::selector_style::remove_stale_selector_style_sheets;
+use super::style_sheet_asset::remove_added_shared_style_sheets;
+use super::var_style::{set_style_attr_value, StyleVarsEntityWorldMutExt};
 use super::{StyleError, StyleWorldExt};
 
 pub fn scoped_style_sheet_definition<U>(
@@ -49,6 +54,7 @@ impl StyledNodeTree<BevyRenderer> for World {
       node_id: RendererNodeId<BevyRenderer>,
       member_state: ApplyStyleSheetsMemberState,
    ) -> Result<(), StyleError> {
+      let style_media = self.applied_style_media();
       let mut entity_world_mut: EntityWorldMut<'_> = self.entity_mut(node_id);
       let attr_is_set = entity_world_mut
          .get_mut::<ElementEntityExtraData>()
@@ -58,14 +64,20 @@ impl StyledNodeTree<BevyRenderer> for World {
          .attr_is_set;
 
       let mut reset_keys = vec![];
+      let mut unsubscribed = vec![];
 
       fn remove_attr_style_of_definition(
          entity_world_mut: &mut EntityWorldMut,
          style_sheet_definition: &StyleSheetDefinition,
          style_sheet_id: NodeStyleSheetId,
          style_state: &mut NodeStyleAttrInfos,
+         style_media: &StyleMedia,
          require_reset_f: impl FnMut(AttrIndex),
       ) -> Result<(), StyleError> {
+         // the items of unmatched or selector style sheets were never added to the node
+         if !style_sheet_definition.is_applied_to_owner(style_media) {
+            return Ok(());
+         }
          if style_sheet_definition.interaction.is_some() {
             entity_world_mut
                .get_inter_style_state()?
@@ -99,6 +111,7 @@ impl StyledNodeTree<BevyRenderer> for World {
                      location: StyleSheetLocation::Inline,
                   },
                   style_state,
+                  &style_media,
                   |key: AttrIndex| {
                      if !ElementEntityExtraData::static_is_set_attr(attr_is_set, key) {
                         reset_keys.push(key);
@@ -110,6 +123,7 @@ impl StyledNodeTree<BevyRenderer> for World {
             for (style_sheet_index, style_sheet_id) in
                style_sheets_state.take_shared_style_sheets_from_member(member_state)
             {
+               unsubscribed.push(style_sheet_id.node_id);
                {
                   entity_world_mut
                      .world_scope(|world: &mut World| {
@@ -137,6 +151,7 @@ impl StyledNodeTree<BevyRenderer> for World {
                               location: StyleSheetLocation::Shared,
                            },
                            style_state,
+                           &style_media,
                            |key: AttrIndex| {
                               if !ElementEntityExtraData::static_is_set_attr(attr_is_set, key) {
                                  reset_keys.push(key);
@@ -151,12 +166,23 @@ impl StyledNodeTree<BevyRenderer> for World {
          })??;
          Ok::<(), StyleError>(())
       })??;
+      // the style sheets a reload added to the shared styles the node no longer subscribes
+      remove_added_shared_style_sheets(
+         &mut entity_world_mut,
+         &unsubscribed,
+         &style_media,
+         &mut reset_keys,
+      )?;
+      reset_keys.retain(|key| !ElementEntityExtraData::static_is_set_attr(attr_is_set, *key));
 
       entity_world_mut.world_scope(|world: &mut World| {
          for attr_index in reset_keys.iter().cloned() {
-            get_attr_by_index(attr_index).set_value(world, node_id, None);
+            set_style_attr_value(world, node_id, attr_index, None);
          }
       });
+      // the descendants must not keep the items of the removed selector style sheets
+      entity_world_mut
+         .world_scope(|world: &mut World| remove_stale_selector_style_sheets(world, node_id))?;
 
       EntityStyleAttrInfoIterArgs {
          iter_normal_style_sheet: true,
@@ -177,6 +203,7 @@ impl StyledNodeTree<BevyRenderer> for World {
    where
       T: StyleSheets<BevyRenderer>,
    {
+      let style_media = self.applied_style_media();
       let mut entity_world_mut = self.entity_mut(node_id.clone());
       entity_world_mut.insert_if_not_exist(RendererState(NodeStyleAttrInfos::default()));
       entity_world_mut.insert_if_not_exist(Interaction::default());
@@ -184,8 +211,14 @@ impl StyledNodeTree<BevyRenderer> for World {
 
       let style_sheets_state = entity_world_mut.get_or_default::<NodeStyleSheetsState>();
 
-      let inline_style_sheet_count = style_sheets_state.inline_style_sheet.len() as _;
-      let shared_style_sheet_count = style_sheets_state.shared_style_sheet_ids.len() as _;
+      let inline_style_sheet_count = to_style_sheet_index(
+         style_sheets_state.inline_style_sheet.len(),
+         StyleSheetLocation::Inline,
+      )?;
+      let shared_style_sheet_count = to_style_sheet_index(
+         style_sheets_state.shared_style_sheet_ids.len(),
+         StyleSheetLocation::Shared,
+      )?;
 
       let (applied_style_sheets, mut member_state, is_first_build) =
          if let Some(member_state) = member_state {
@@ -238,9 +271,9 @@ impl StyledNodeTree<BevyRenderer> for World {
                         continue;
                      };
                      let style_sheet_index = if is_first_build {
-                        style_sheets_state.get_style_sheet_len(style_sheet_location)
+                        style_sheets_state.get_style_sheet_len(style_sheet_location)?
                      } else {
-                        member_state.get_and_increment_and_by_location(style_sheet_location)
+                        member_state.get_and_increment_and_by_location(style_sheet_location)?
                      };
                      if !scoped_style_sheet_definition(
                         &applied_style_sheet,
@@ -252,6 +285,11 @@ impl StyledNodeTree<BevyRenderer> for World {
                            if style_sheet_definition.items.is_empty() {
                               return Ok(false);
                            }
+                           // keep the style sheet, its items are added once the media matches,
+                           // or to the matched descendants for selector style sheets
+                           if !style_sheet_definition.is_applied_to_owner(&style_media) {
+                              return Ok(true);
+                           }
 
                            if style_sheet_definition.interaction.is_some() {
                               recalculate_interaction_style_value = true;
@@ -360,10 +398,10 @@ impl StyledNodeTree<BevyRenderer> for World {
                      .value = item_value.value;
                }
 
-               member_state.inline_sheet_index += 1;
+               member_state.get_and_increment_and_by_location(StyleSheetLocation::Inline)?;
             }
             AppliedStyleSheet::Shared(_style_sheet) => {
-               member_state.shared_sheet_index += 1;
+               member_state.get_and_increment_and_by_location(StyleSheetLocation::Shared)?;
                // todo:
                // if style_sheets_state.shared_style_sheet_ids
                //     [member_state.shared_sheet_index as usize]
@@ -384,4 +422,16 @@ impl StyledNodeTree<BevyRenderer> for World {
       iter_args.iter_and_sync_set(entity_world_mut)?;
       Ok(())
    }
+
+   fn set_style_var(
+      &mut self,
+      node_id: RendererNodeId<BevyRenderer>,
+      name: Cow<'static, str>,
+      value: Option<StyleAttrValue>,
+   ) {
+      let Some(mut entity_world_mut) = self.get_entity_mut(node_id) else {
+         return;
+      };
+      entity_world_mut.set_style_var(name, value);
+   }
 }
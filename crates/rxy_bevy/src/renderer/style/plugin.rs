@@ -2,6 +2,7 @@ use core::any::TypeId;
 use core::fmt::Debug;
 
 use bevy_app::{App, Plugin, Update};
+use bevy_asset::AssetApp;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::component::Component;
 use bevy_ecs::entity::Entity;
@@ -11,11 +12,20 @@ use bevy_utils::HashMap;
 
 use super::focus_style::update_focus_style;
 use super::interaction_style::update_interaction_styles;
+use super::media_style::{update_media_styles, AppliedStyleMedia, StyleMediaPreferences};
 use super::rxy_bevy_crate::FocusedEntity;
+use super::selector_style::update_selector_styles;
+use super::state_style::update_state_styles;
+use super::style_sheet_asset::{apply_style_sheet_assets, StyleSheetAsset, StyleSheetAssetLoader};
+use super::var_style::update_moved_style_var_dependents;
 
 #[derive(Resource, Default, Deref, DerefMut)]
 pub struct TypedEntities(HashMap<TypeId, Entity>);
 
+/// The names of the defined `TypedStyle` labels, used to find them from style sheet assets.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct TypedStyleLabels(HashMap<&'static str, TypeId>);
+
 #[derive(Resource)]
 pub struct RxySharedStyleContainer(pub Entity);
 
@@ -36,12 +46,24 @@ impl Plugin for RxyStyleSheetPlugin {
    fn build(&self, app: &mut App) {
       app.init_resource::<RxySharedStyleContainer>()
          .init_resource::<TypedEntities>()
+         .init_resource::<TypedStyleLabels>()
          .init_resource::<Previous<FocusedEntity>>()
+         .init_resource::<AppliedStyleMedia>()
+         .init_resource::<StyleMediaPreferences>()
+         .init_asset::<StyleSheetAsset>()
+         .init_asset_loader::<StyleSheetAssetLoader>()
          .add_systems(
             Update,
             (
+               apply_style_sheet_assets.before(update_media_styles),
+               update_media_styles.before(update_interaction_styles),
+               update_state_styles.before(update_interaction_styles),
+               update_selector_styles
+                  .after(update_media_styles)
+                  .before(update_interaction_styles),
                update_interaction_styles.after(update_focus_style),
                update_focus_style.run_if(|res: Res<FocusedEntity>| res.is_changed()),
+               update_moved_style_var_dependents.before(update_interaction_styles),
             ),
          );
    }
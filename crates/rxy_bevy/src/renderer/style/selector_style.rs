@@ -0,0 +1,506 @@
+use core::any::TypeId;
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::EntityHashSet;
+use bevy_ecs::prelude::{Changed, Entity, Local, Or, QueryState, With, World};
+use bevy_hierarchy::{Children, Parent};
+use bevy_ui::Interaction;
+use bevy_utils::tracing::warn;
+use bevy_utils::HashMap;
+
+use rxy_core::style::{
+   AppliedStyleSheet, NodeStyleAttrInfos, NodeStyleSheetId, StyleCombinator, StyleSelector,
+   StyleSelectorTarget, StyleSheetDefinition, StyleSheetId, StyleSheetIndex, StyleSheetLocation,
+};
+
+use super::interaction_style::node_style_interaction;
+use super::media_style::{set_style_sheet_items_applied, AppliedStyleMedia};
+use super::node_style_state::NodeStyleSheetsState;
+use super::plugin::TypedEntities;
+use super::rxy_bevy_crate::{
+   BevyRenderer, ElementEntityExtraData, EntityWorldMutExt, FocusedEntity, RendererState,
+};
+use super::state_style::NodeStyleStates;
+use super::var_style::set_style_attr_value;
+use super::{
+   EntityStyleAttrInfoIterArgs, Previous, Result, StyleEntityMutExt, StyleEntityWorldMutExt,
+   StyleError, StyleWorldExt,
+};
+
+#[derive(Clone, Debug)]
+struct SelectorStyleSheetTargets {
+   style_sheet_id: StyleSheetId<BevyRenderer>,
+   /// Kept to remove the items from the targets after the style sheet is removed from the node
+   definition: StyleSheetDefinition,
+   targets: Vec<SelectorStyleTarget>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SelectorStyleTarget {
+   entity: Entity,
+   /// The index of the shared style sheet on the target, kept while the style sheet is inactive
+   style_sheet_index: StyleSheetIndex,
+   applied: bool,
+}
+
+#[derive(Component, Default, Debug)]
+pub struct NodeSelectorStyleState {
+   /// The selector style sheets of the node and the descendants they are applied to
+   owned: HashMap<NodeStyleSheetId, SelectorStyleSheetTargets>,
+   /// The shared style sheets of the node that come from the selectors of its ancestors
+   selected_indices: Vec<StyleSheetIndex>,
+   /// The shared style sheet slots of removed selector targets, reused by the next ones
+   free_indices: Vec<StyleSheetIndex>,
+}
+
+type TreeChangedFilter = Or<(
+   Changed<Children>,
+   Changed<Parent>,
+   Changed<RendererState<NodeStyleSheetsState>>,
+)>;
+
+type GroupChangedFilter = (
+   Or<(Changed<Interaction>, Changed<NodeStyleStates>)>,
+   With<NodeSelectorStyleState>,
+);
+
+pub fn update_selector_styles(
+   world: &mut World,
+   mut last_focus: Local<Option<Entity>>,
+   tree_changed_query: &mut QueryState<Entity, TreeChangedFilter>,
+   group_changed_query: &mut QueryState<Entity, GroupChangedFilter>,
+   styled_query: &mut QueryState<Entity, With<RendererState<NodeStyleSheetsState>>>,
+) {
+   let focus = world.resource::<FocusedEntity>().0;
+   let last_focus = core::mem::replace(&mut *last_focus, focus);
+
+   let owners = if world.is_resource_changed::<TypedEntities>()
+      || world.is_resource_changed::<AppliedStyleMedia>()
+   {
+      styled_query.iter(world).collect::<EntityHashSet>()
+   } else {
+      let mut owners = EntityHashSet::default();
+      // The targets of a selector only depend on the descendants of its owner, so a tree change
+      // only resolves the selectors of the styled ancestors of the changed nodes again
+      let mut visited = EntityHashSet::default();
+      let changed_nodes = tree_changed_query.iter(world).collect::<Vec<_>>();
+      for entity in changed_nodes {
+         let mut current = Some(entity);
+         while let Some(entity) = current {
+            if !visited.insert(entity) {
+               break;
+            }
+            if styled_query.get(world, entity).is_ok() {
+               owners.insert(entity);
+            }
+            current = world.get::<Parent>(entity).map(|n| n.get());
+         }
+      }
+      owners.extend(group_changed_query.iter(world));
+      if last_focus != focus {
+         owners.extend(
+            [last_focus, focus]
+               .into_iter()
+               .flatten()
+               .filter(|n| world.get::<NodeSelectorStyleState>(*n).is_some()),
+         );
+      }
+      owners
+   };
+
+   for owner in owners {
+      if let Err(err) = update_node_selector_styles(world, owner) {
+         warn!("update selector styles of {:?} failed: {:?}", owner, err);
+      }
+   }
+}
+
+/// Resolves the targets of the selector style sheets of `owner`, and adds or removes their items.
+fn update_node_selector_styles(world: &mut World, owner: Entity) -> Result {
+   let style_media = world.applied_style_media();
+   let Some(entity_ref) = world.get_entity(owner) else {
+      return Ok(());
+   };
+   let Some(style_sheets_state) = entity_ref.get_ref::<RendererState<NodeStyleSheetsState>>()
+   else {
+      return Ok(());
+   };
+   let is_style_sheets_changed = style_sheets_state.is_changed();
+   let selected_indices = entity_ref
+      .get::<NodeSelectorStyleState>()
+      .map(|n| n.selected_indices.clone())
+      .unwrap_or_default();
+
+   let mut selector_style_sheets = vec![];
+   for (index, style_sheet_definition) in style_sheets_state.0.inline_style_sheet.iter().enumerate()
+   {
+      let Some(style_sheet_definition) = style_sheet_definition else {
+         continue;
+      };
+      if style_sheet_definition.selector.is_none() {
+         continue;
+      }
+      let node_style_sheet_id = NodeStyleSheetId {
+         index: index as _,
+         location: StyleSheetLocation::Inline,
+      };
+      selector_style_sheets.push((
+         node_style_sheet_id,
+         StyleSheetId {
+            node_style_sheet_id,
+            node_id: owner,
+         },
+         style_sheet_definition.clone(),
+      ));
+   }
+   for (index, style_sheet_id) in style_sheets_state
+      .0
+      .shared_style_sheet_ids
+      .iter()
+      .enumerate()
+   {
+      let Some(style_sheet_id) = style_sheet_id else {
+         continue;
+      };
+      if selected_indices.contains(&(index as _)) {
+         continue;
+      }
+      let style_sheet_definition = world.get_style_sheet_definition_ref(style_sheet_id.clone())?;
+      if style_sheet_definition.selector.is_none() {
+         continue;
+      }
+      selector_style_sheets.push((
+         NodeStyleSheetId {
+            index: index as _,
+            location: StyleSheetLocation::Shared,
+         },
+         style_sheet_id.clone(),
+         style_sheet_definition.clone(),
+      ));
+   }
+
+   let mut prev_owned = world
+      .get_mut::<NodeSelectorStyleState>(owner)
+      .map(|mut n| core::mem::take(&mut n.owned))
+      .unwrap_or_default();
+   if selector_style_sheets.is_empty() && prev_owned.is_empty() {
+      return Ok(());
+   }
+
+   let entity_ref = world.entity(owner);
+   let owner_interaction = node_style_interaction(
+      entity_ref.get::<Interaction>().cloned(),
+      entity_ref.get::<NodeStyleStates>(),
+      world.resource::<FocusedEntity>().0 == Some(owner),
+   );
+
+   let mut owned = HashMap::default();
+   for (node_style_sheet_id, style_sheet_id, style_sheet_definition) in selector_style_sheets {
+      let selector = style_sheet_definition.selector.unwrap();
+      let is_active = style_sheet_definition.is_media_matched(&style_media)
+         && selector.is_group_matched(owner_interaction);
+      let matched_entities = find_selector_targets(world, owner, &selector);
+
+      let prev_targets = match prev_owned.remove(&node_style_sheet_id) {
+         Some(prev) if is_same_style_sheet(&prev.style_sheet_id, &style_sheet_id) => prev.targets,
+         Some(prev) => {
+            remove_selector_targets(world, &prev)?;
+            vec![]
+         }
+         None => vec![],
+      };
+
+      let mut targets = Vec::with_capacity(matched_entities.len());
+      for mut target in prev_targets {
+         if world.get_entity(target.entity).is_none() {
+            continue;
+         }
+         if !matched_entities.contains(&target.entity) {
+            remove_selector_target(world, &style_sheet_definition, target)?;
+            continue;
+         }
+         if target.applied != is_active {
+            set_selector_target_applied(world, &style_sheet_definition, target, is_active)?;
+            target.applied = is_active;
+         } else if target.applied && is_style_sheets_changed {
+            // the values of the style sheet may be rebuilt
+            EntityStyleAttrInfoIterArgs {
+               limit_attr_ids: Some(
+                  &style_sheet_definition
+                     .items
+                     .iter()
+                     .map(|n| n.attr_id)
+                     .collect::<Vec<_>>(),
+               ),
+               ..EntityStyleAttrInfoIterArgs::all_kind()
+            }
+            .iter_and_sync_set(world.entity_mut(target.entity))?;
+         }
+         targets.push(target);
+      }
+
+      for entity in matched_entities {
+         if targets.iter().any(|n| n.entity == entity) {
+            continue;
+         }
+         let target = SelectorStyleTarget {
+            entity,
+            style_sheet_index: add_selector_target(world, entity, style_sheet_id.clone())?,
+            applied: is_active,
+         };
+         if is_active {
+            set_selector_target_applied(world, &style_sheet_definition, target, true)?;
+         }
+         targets.push(target);
+      }
+
+      owned.insert(
+         node_style_sheet_id,
+         SelectorStyleSheetTargets {
+            style_sheet_id,
+            definition: style_sheet_definition,
+            targets,
+         },
+      );
+   }
+
+   for (_, prev) in prev_owned {
+      remove_selector_targets(world, &prev)?;
+   }
+
+   if let Some(mut entity_world_mut) = world.get_entity_mut(owner) {
+      entity_world_mut.insert_if_not_exist(NodeSelectorStyleState::default());
+      entity_world_mut
+         .get_mut::<NodeSelectorStyleState>()
+         .unwrap()
+         .owned = owned;
+   }
+   Ok(())
+}
+
+/// Removes the items of the selector style sheets of `owner` that were removed from it.
+pub(super) fn remove_stale_selector_style_sheets(world: &mut World, owner: Entity) -> Result {
+   let Some(mut selector_style_state) = world.get_mut::<NodeSelectorStyleState>(owner) else {
+      return Ok(());
+   };
+   let mut owned = core::mem::take(&mut selector_style_state.owned);
+   let style_sheets_state = world
+      .get::<RendererState<NodeStyleSheetsState>>(owner)
+      .ok_or(StyleError::NoFoundStyleSheetsState { node_id: owner })?;
+   let stale_ids = owned
+      .keys()
+      .filter(|n| match n.location {
+         StyleSheetLocation::Inline => !matches!(
+            style_sheets_state.inline_style_sheet.get(n.index as usize),
+            Some(Some(_))
+         ),
+         StyleSheetLocation::Shared => !matches!(
+            style_sheets_state
+               .shared_style_sheet_ids
+               .get(n.index as usize),
+            Some(Some(_))
+         ),
+      })
+      .cloned()
+      .collect::<Vec<_>>();
+   for stale_id in stale_ids {
+      let selector_style_sheet = owned.remove(&stale_id).unwrap();
+      remove_selector_targets(world, &selector_style_sheet)?;
+   }
+   world
+      .get_mut::<NodeSelectorStyleState>(owner)
+      .unwrap()
+      .owned = owned;
+   Ok(())
+}
+
+#[inline]
+fn is_same_style_sheet(a: &StyleSheetId<BevyRenderer>, b: &StyleSheetId<BevyRenderer>) -> bool {
+   a.node_id == b.node_id && a.node_style_sheet_id == b.node_style_sheet_id
+}
+
+fn find_selector_targets(world: &World, owner: Entity, selector: &StyleSelector) -> Vec<Entity> {
+   let typed_entity = selector
+      .typed_style
+      .and_then(|type_id| world.resource::<TypedEntities>().get(&type_id).cloned());
+   let mut targets = vec![];
+   let mut stack = vec![(owner, 0)];
+   while let Some((parent, depth)) = stack.pop() {
+      let Some(children) = world.get::<Children>(parent) else {
+         continue;
+      };
+      let depth = depth + 1;
+      let element_children = children
+         .iter()
+         .filter(|n| world.get::<ElementEntityExtraData>(**n).is_some())
+         .cloned()
+         .collect::<Vec<_>>();
+      for (index, child) in element_children.iter().cloned().enumerate() {
+         let typed_styles = match (selector.typed_style, typed_entity) {
+            (Some(type_id), Some(typed_entity))
+               if has_shared_style_sheet_of(world, child, typed_entity) =>
+            {
+               vec![type_id]
+            }
+            _ => Vec::<TypeId>::new(),
+         };
+         let is_matched = selector.matches(&StyleSelectorTarget {
+            depth,
+            tag: world
+               .get::<ElementEntityExtraData>(child)
+               .unwrap()
+               .element_type
+               .tag_name(),
+            index,
+            sibling_count: element_children.len(),
+            typed_styles: &typed_styles,
+         });
+         if is_matched {
+            targets.push(child);
+         }
+      }
+      if selector.combinator == StyleCombinator::Descendant {
+         stack.extend(children.iter().map(|n| (*n, depth)));
+      }
+   }
+   targets
+}
+
+/// Whether the node applied the style sheets of the shared style entity itself
+fn has_shared_style_sheet_of(world: &World, entity: Entity, shared_entity: Entity) -> bool {
+   let Some(style_sheets_state) = world.get::<RendererState<NodeStyleSheetsState>>(entity) else {
+      return false;
+   };
+   let selected_indices = world
+      .get::<NodeSelectorStyleState>(entity)
+      .map(|n| n.selected_indices.as_slice())
+      .unwrap_or_default();
+   style_sheets_state
+      .shared_style_sheet_ids
+      .iter()
+      .enumerate()
+      .filter(|(index, _)| !selected_indices.contains(&(*index as _)))
+      .any(|(_, n)| n.as_ref().is_some_and(|n| n.node_id == shared_entity))
+}
+
+/// Adds the style sheet to the shared style sheets of the target and returns its index, reusing
+/// the slot of a removed selector target if any
+fn add_selector_target(
+   world: &mut World,
+   entity: Entity,
+   style_sheet_id: StyleSheetId<BevyRenderer>,
+) -> Result<StyleSheetIndex> {
+   let mut entity_world_mut = world.entity_mut(entity);
+   entity_world_mut.insert_if_not_exist(RendererState(NodeStyleAttrInfos::default()));
+   entity_world_mut.insert_if_not_exist(Interaction::default());
+   entity_world_mut.insert_if_not_exist(Previous(Interaction::default()));
+   entity_world_mut.insert_if_not_exist(NodeSelectorStyleState::default());
+
+   let free_index = entity_world_mut
+      .get_mut::<NodeSelectorStyleState>()
+      .unwrap()
+      .free_indices
+      .pop();
+   let style_sheets_state = entity_world_mut.get_or_default::<NodeStyleSheetsState>();
+   let style_sheet_index = match free_index {
+      Some(style_sheet_index) => {
+         style_sheets_state
+            .set_applied_style_sheet(style_sheet_index, AppliedStyleSheet::Shared(style_sheet_id));
+         style_sheet_index
+      }
+      None => {
+         let style_sheet_index =
+            style_sheets_state.get_style_sheet_len(StyleSheetLocation::Shared)?;
+         style_sheets_state.push_applied_style_sheet(AppliedStyleSheet::Shared(style_sheet_id));
+         style_sheet_index
+      }
+   };
+   entity_world_mut
+      .get_mut::<NodeSelectorStyleState>()
+      .unwrap()
+      .selected_indices
+      .push(style_sheet_index);
+   Ok(style_sheet_index)
+}
+
+fn remove_selector_targets(
+   world: &mut World,
+   selector_style_sheet: &SelectorStyleSheetTargets,
+) -> Result {
+   for target in selector_style_sheet.targets.iter().cloned() {
+      remove_selector_target(world, &selector_style_sheet.definition, target)?;
+   }
+   Ok(())
+}
+
+fn remove_selector_target(
+   world: &mut World,
+   style_sheet_definition: &StyleSheetDefinition,
+   target: SelectorStyleTarget,
+) -> Result {
+   if world.get_entity(target.entity).is_none() {
+      return Ok(());
+   }
+   if target.applied {
+      set_selector_target_applied(world, style_sheet_definition, target, false)?;
+   }
+   let mut entity_world_mut = world.entity_mut(target.entity);
+   if let Some(mut selector_style_state) = entity_world_mut.get_mut::<NodeSelectorStyleState>() {
+      selector_style_state
+         .selected_indices
+         .retain(|n| *n != target.style_sheet_index);
+      selector_style_state
+         .free_indices
+         .push(target.style_sheet_index);
+   }
+   entity_world_mut
+      .get_style_sheets_state()?
+      .shared_style_sheet_ids[target.style_sheet_index as usize] = None;
+   Ok(())
+}
+
+fn set_selector_target_applied(
+   world: &mut World,
+   style_sheet_definition: &StyleSheetDefinition,
+   target: SelectorStyleTarget,
+   applied: bool,
+) -> Result {
+   let mut entity_world_mut = world.entity_mut(target.entity);
+   let attr_is_set = entity_world_mut
+      .get::<ElementEntityExtraData>()
+      .map(|n| n.attr_is_set)
+      .unwrap_or_default();
+   let mut reset_attr_ids = vec![];
+   entity_world_mut.scoped_style_state(|entity_world_mut, style_state| {
+      set_style_sheet_items_applied(
+         entity_world_mut,
+         style_state,
+         style_sheet_definition,
+         NodeStyleSheetId {
+            index: target.style_sheet_index,
+            location: StyleSheetLocation::Shared,
+         },
+         applied,
+         &mut reset_attr_ids,
+      )
+   })??;
+
+   entity_world_mut.world_scope(|world: &mut World| {
+      for attr_index in reset_attr_ids.iter().cloned() {
+         if !ElementEntityExtraData::static_is_set_attr(attr_is_set, attr_index) {
+            set_style_attr_value(world, target.entity, attr_index, None);
+         }
+      }
+   });
+
+   let changed_attr_ids = style_sheet_definition
+      .items
+      .iter()
+      .map(|n| n.attr_id)
+      .collect::<Vec<_>>();
+   EntityStyleAttrInfoIterArgs {
+      limit_attr_ids: Some(changed_attr_ids.as_slice()),
+      ..EntityStyleAttrInfoIterArgs::all_kind()
+   }
+   .iter_and_sync_set(entity_world_mut)
+}
@@ -10,7 +10,7 @@ use rxy_core::style::{StyleSheetCtx, StyleSheets};
 use rxy_core::{IntoView, RendererNodeId, View, ViewCtx};
 
 use super::node_style_state::NodeStyleSheetsState;
-use super::plugin::RxySharedStyleContainer;
+use super::plugin::{RxySharedStyleContainer, TypedStyleLabels};
 use super::rxy_bevy_crate::SchemaCtx;
 use super::rxy_bevy_crate::{BevyRenderer, RendererState};
 use super::{SharedStyleState, StyleWorldExt};
@@ -105,6 +105,12 @@ impl TypedStyleWorldExt for World {
       if let Some(entity) = self.get_typed_entity(typed_style.type_id()) {
          return (false, entity);
       }
+      fn label_name_of<L: TypedStyleLabel>(_typed_style: &L) -> &'static str {
+         L::label_name()
+      }
+      self
+         .resource_mut::<TypedStyleLabels>()
+         .insert(label_name_of(&typed_style), typed_style.type_id());
       (
          true,
          self.spawn_typed_style(None, typed_style.type_id(), style_f()),
@@ -118,6 +124,7 @@ where
    SS: StyleSheets<BevyRenderer>,
 {
    type_id: TypeId,
+   label_name: &'static str,
    style_sheets: SS,
 }
 
@@ -133,6 +140,9 @@ where
       _reserve_key: Option<Self::Key>,
       _will_rebuild: bool,
    ) -> Self::Key {
+      ctx.world
+         .resource_mut::<TypedStyleLabels>()
+         .insert(self.label_name, self.type_id);
       ctx.world
          .spawn_typed_style(None, self.type_id, self.style_sheets);
    }
@@ -147,12 +157,19 @@ pub trait DefaultStyleDef {
 }
 
 pub trait TypedStyleLabel: Copy + Clone + Send + 'static {
+   /// The name of the label in style sheet assets, the type name without its path by default
+   fn label_name() -> &'static str {
+      let type_name = core::any::type_name::<Self>();
+      type_name.rsplit("::").next().unwrap_or(type_name)
+   }
+
    fn def<SS>(style: SS) -> TypedSharedStyleView<SS>
    where
       SS: StyleSheets<BevyRenderer>,
    {
       TypedSharedStyleView {
          type_id: TypeId::of::<Self>(),
+         label_name: Self::label_name(),
          style_sheets: style,
       }
    }
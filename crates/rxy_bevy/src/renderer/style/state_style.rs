@@ -0,0 +1,122 @@
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::prelude::{Changed, Commands, EntityWorldMut, Query, Res, With};
+use bevy_ui::Interaction;
+
+use rxy_core::style::{NodeInterStyleAttrInfos, NodeStyleAttrInfos, StyleInteraction};
+
+use super::interaction_style::{node_style_interaction, AttrSetBitsIterExt, SetAttrValuesCommand};
+use super::node_style_state::NodeStyleSheetsState;
+use super::rxy_bevy_crate::{
+   AttrSetBits, ElementEntityExtraData, EntityWorldMutExt, FocusedEntity, RendererState,
+};
+use super::style_state_owner::{EntityStyleWorldQuery, NodeStyleStateOwner};
+use super::Previous;
+
+/// The user states of the node, like [`StyleInteraction::Disabled`] or a custom state.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Deref, DerefMut)]
+pub struct NodeStyleStates(pub StyleInteraction);
+
+impl Default for NodeStyleStates {
+   fn default() -> Self {
+      Self(StyleInteraction::empty())
+   }
+}
+
+pub trait StyleStatesEntityWorldMutExt {
+   /// Add or remove `states` of the node, only the user states of `states` are kept.
+   fn set_style_states(&mut self, states: StyleInteraction, value: bool);
+}
+
+impl StyleStatesEntityWorldMutExt for EntityWorldMut<'_> {
+   fn set_style_states(&mut self, states: StyleInteraction, value: bool) {
+      let states = states & StyleInteraction::user_states();
+      let prev_states = self.get::<NodeStyleStates>().cloned().unwrap_or_default();
+      let mut new_states = prev_states;
+      new_states.set(states, value);
+      if new_states == prev_states && self.contains::<NodeStyleStates>() {
+         return;
+      }
+      self.insert_if_not_exist(Previous(NodeStyleStates::default()));
+      self.insert(new_states);
+   }
+}
+
+pub fn update_state_styles(
+   mut commands: Commands,
+   style_sheets_query: Query<&RendererState<NodeStyleSheetsState>>,
+   mut state_styled_query: Query<
+      (
+         Entity,
+         &ElementEntityExtraData,
+         &RendererState<NodeInterStyleAttrInfos>,
+         &RendererState<NodeStyleAttrInfos>,
+         &NodeStyleStates,
+         &mut Previous<NodeStyleStates>,
+         Option<&Interaction>,
+      ),
+      (
+         Changed<NodeStyleStates>,
+         With<RendererState<NodeStyleSheetsState>>,
+      ),
+   >,
+   focus: Res<FocusedEntity>,
+) {
+   if state_styled_query.is_empty() {
+      return;
+   }
+   let mut set_attrs_cmd = SetAttrValuesCommand::default();
+
+   let mut style_sheets_query = Some(style_sheets_query);
+   let mut attr_bits: AttrSetBits;
+   for (
+      entity,
+      entity_extra_data,
+      RendererState(entity_inter_style_state),
+      RendererState(entity_style_state),
+      states,
+      mut previous_states,
+      interaction,
+   ) in state_styled_query.iter_mut()
+   {
+      let changed_states = previous_states.0 .0 ^ states.0;
+      *previous_states = Previous(*states);
+      if changed_states.is_empty() || entity_inter_style_state.is_empty() {
+         continue;
+      }
+
+      let entity_style_world_query = EntityStyleWorldQuery {
+         query: style_sheets_query.take().unwrap(),
+         current_entity: entity,
+      };
+      attr_bits = 0;
+
+      let node_interaction =
+         node_style_interaction(interaction.cloned(), Some(states), focus.0 == Some(entity));
+      for (attr_index, _) in entity_inter_style_state
+         .iter()
+         .filter(|(interaction, _)| interaction.intersects(changed_states))
+         .flat_map(|(_, attr_infos)| attr_infos.keys().map(|attr_index| (*attr_index, ())))
+         .filter_attr_already_set(entity_extra_data.attr_is_set | attr_bits)
+      {
+         if (attr_bits >> attr_index) & 1 == 1 {
+            continue;
+         }
+         attr_bits |= 1 << attr_index;
+         let value = entity_inter_style_state
+            .match_attr(attr_index, node_interaction, false)
+            .or_else(|| entity_style_state.get(&attr_index))
+            .map(|attr_info| {
+               entity_style_world_query
+                  .get_current_style_item_value(attr_info.top_item_id())
+                  .unwrap()
+            });
+         set_attrs_cmd.add(entity, attr_index, value);
+      }
+
+      style_sheets_query = Some(entity_style_world_query.query);
+   }
+
+   commands.add(set_attrs_cmd);
+}
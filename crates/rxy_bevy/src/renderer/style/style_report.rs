@@ -0,0 +1,91 @@
+use bevy_ecs::prelude::{Entity, World};
+use bevy_ui::Interaction;
+
+use rxy_core::style::{NodeInterStyleAttrInfos, NodeStyleAttrInfos, StyleAttrReport};
+use rxy_core::AttrIndex;
+
+use crate::attrs::get_attr_by_index;
+
+use super::interaction_style::node_style_interaction;
+use super::rxy_bevy_crate::{ElementEntityExtraData, FocusedEntity, RendererState};
+use super::state_style::NodeStyleStates;
+use super::style_state_owner::StyleStateOwner;
+use super::{Result, StyleError};
+
+/// Introspection of the style items of a node, for debugging why a style does not apply.
+///
+/// ```ignore
+/// for report in world.style_attr_reports(entity)? {
+///    info!("{}", report);
+/// }
+/// ```
+pub trait StyleReportWorldExt {
+   /// All the candidate style items of the attr of the node, and the one that currently wins
+   fn style_attr_report(&self, node_id: Entity, attr_index: AttrIndex) -> Result<StyleAttrReport>;
+
+   /// The reports of all the attrs the node has style items for, ordered by attr index
+   fn style_attr_reports(&self, node_id: Entity) -> Result<Vec<StyleAttrReport>>;
+}
+
+impl StyleReportWorldExt for World {
+   fn style_attr_report(&self, node_id: Entity, attr_index: AttrIndex) -> Result<StyleAttrReport> {
+      let entity_ref = self
+         .get_entity(node_id)
+         .ok_or(StyleError::NoFoundNode { node_id })?;
+      let is_focused = self.get_resource::<FocusedEntity>().and_then(|n| n.0) == Some(node_id);
+      let node_interaction = node_style_interaction(
+         entity_ref.get::<Interaction>().cloned(),
+         entity_ref.get::<NodeStyleStates>(),
+         is_focused,
+      );
+      let is_set_on_element = entity_ref
+         .get::<ElementEntityExtraData>()
+         .map_or(false, |n| {
+            ElementEntityExtraData::static_is_set_attr(n.attr_is_set, attr_index)
+         });
+
+      let mut report = StyleAttrReport::new(
+         attr_index,
+         get_attr_by_index(attr_index).attr_name(),
+         node_interaction,
+         is_set_on_element,
+         entity_ref
+            .get::<RendererState<NodeStyleAttrInfos>>()
+            .and_then(|n| n.0.get(&attr_index)),
+         entity_ref
+            .get::<RendererState<NodeInterStyleAttrInfos>>()
+            .map(|n| &n.0),
+      );
+      for candidate in report.candidates.iter_mut() {
+         candidate.value = self
+            .get_style_item_value(node_id, candidate.item_id)
+            .ok()
+            .map(|n| n.value.clone());
+      }
+      Ok(report)
+   }
+
+   fn style_attr_reports(&self, node_id: Entity) -> Result<Vec<StyleAttrReport>> {
+      let entity_ref = self
+         .get_entity(node_id)
+         .ok_or(StyleError::NoFoundNode { node_id })?;
+      let mut attr_ids = entity_ref
+         .get::<RendererState<NodeStyleAttrInfos>>()
+         .into_iter()
+         .flat_map(|n| n.0.keys().copied())
+         .chain(
+            entity_ref
+               .get::<RendererState<NodeInterStyleAttrInfos>>()
+               .into_iter()
+               .flat_map(|n| n.0.values().flat_map(|n| n.keys().copied())),
+         )
+         .collect::<Vec<_>>();
+      attr_ids.sort_unstable();
+      attr_ids.dedup();
+
+      attr_ids
+         .into_iter()
+         .map(|attr_index| self.style_attr_report(node_id, attr_index))
+         .collect()
+   }
+}
@@ -0,0 +1,404 @@
+use std::fmt::{Display, Formatter};
+
+use bevy_asset::io::Reader;
+use bevy_asset::{Asset, AssetEvent, AssetLoader, Assets, LoadContext};
+use bevy_ecs::component::Component;
+use bevy_ecs::event::{Events, ManualEventReader};
+use bevy_ecs::prelude::{AppTypeRegistry, Entity, EntityWorldMut, Local, World};
+use bevy_ecs::world::FromWorld;
+use bevy_reflect::TypePath;
+use bevy_utils::tracing::warn;
+use futures_lite::AsyncReadExt;
+
+use rxy_core::style::{
+   to_style_sheet_index, AppliedStyleSheet, NodeStyleSheetId, StyleMedia, StyleSheetDefinition,
+   StyleSheetId, StyleSheetIndex, StyleSheetLocation,
+};
+use rxy_core::AttrIndex;
+
+use super::media_style::set_style_sheet_items_applied;
+use super::plugin::{TypedEntities, TypedStyleLabels};
+use super::rxy_bevy_crate::{ElementEntityExtraData, EntityWorldMutExt};
+use super::style_sheet_parser::{parse_style_sheets, LabeledStyleSheets, StyleSheetParseError};
+use super::var_style::set_style_attr_value;
+use super::{
+   EntityStyleAttrInfoIterArgs, Result, StyleEntityMutExt, StyleEntityWorldMutExt, StyleError,
+   StyleWorldExt,
+};
+
+/// A style sheet file (`.rxss`), see [`parse_style_sheets`] for the format.
+///
+/// The style sheets of each label replace the ones of the `TypedStyle` with the same name, also
+/// when the file is modified while the app is running.
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct StyleSheetAsset(pub Vec<LabeledStyleSheets>);
+
+pub struct StyleSheetAssetLoader {
+   type_registry: AppTypeRegistry,
+}
+
+impl FromWorld for StyleSheetAssetLoader {
+   fn from_world(world: &mut World) -> Self {
+      Self {
+         type_registry: world.resource::<AppTypeRegistry>().clone(),
+      }
+   }
+}
+
+#[derive(Debug)]
+pub enum StyleSheetAssetLoaderError {
+   Io(std::io::Error),
+   Utf8(std::str::Utf8Error),
+   Parse(StyleSheetParseError),
+}
+
+impl Display for StyleSheetAssetLoaderError {
+   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      match self {
+         StyleSheetAssetLoaderError::Io(err) => write!(f, "read style sheet failed: {}", err),
+         StyleSheetAssetLoaderError::Utf8(err) => write!(f, "invalid style sheet text: {}", err),
+         StyleSheetAssetLoaderError::Parse(err) => write!(f, "parse style sheet failed: {}", err),
+      }
+   }
+}
+
+impl std::error::Error for StyleSheetAssetLoaderError {}
+
+impl From<std::io::Error> for StyleSheetAssetLoaderError {
+   fn from(value: std::io::Error) -> Self {
+      StyleSheetAssetLoaderError::Io(value)
+   }
+}
+
+impl From<std::str::Utf8Error> for StyleSheetAssetLoaderError {
+   fn from(value: std::str::Utf8Error) -> Self {
+      StyleSheetAssetLoaderError::Utf8(value)
+   }
+}
+
+impl From<StyleSheetParseError> for StyleSheetAssetLoaderError {
+   fn from(value: StyleSheetParseError) -> Self {
+      StyleSheetAssetLoaderError::Parse(value)
+   }
+}
+
+impl AssetLoader for StyleSheetAssetLoader {
+   type Asset = StyleSheetAsset;
+   type Settings = ();
+   type Error = StyleSheetAssetLoaderError;
+
+   async fn load<'a>(
+      &'a self,
+      reader: &'a mut Reader<'_>,
+      _settings: &'a Self::Settings,
+      _load_context: &'a mut LoadContext<'_>,
+   ) -> core::result::Result<Self::Asset, Self::Error> {
+      let mut bytes = vec![];
+      reader.read_to_end(&mut bytes).await?;
+      let text = std::str::from_utf8(&bytes)?;
+      let style_sheets = parse_style_sheets(text, &self.type_registry.read())?;
+      Ok(StyleSheetAsset(style_sheets))
+   }
+
+   fn extensions(&self) -> &[&str] {
+      &["rxss"]
+   }
+}
+
+/// Marks a typed style entity whose style sheets came from a [`StyleSheetAsset`]
+#[derive(Component)]
+pub struct StyleSheetAssetApplied;
+
+/// The shared style sheet slots of a node for the style sheets a reload added to the shared
+/// styles it subscribes
+#[derive(Component, Default, Debug)]
+pub struct NodeAddedStyleSheetsState {
+   /// The shared style entity and the index of the slot
+   added: Vec<(Entity, StyleSheetIndex)>,
+   /// The slots of the shared styles the node no longer subscribes, reused by the next ones
+   free_indices: Vec<StyleSheetIndex>,
+}
+
+/// Applies added or modified style sheet assets, and loaded assets to typed styles defined later.
+pub fn apply_style_sheet_assets(
+   world: &mut World,
+   mut asset_event_reader: Local<ManualEventReader<AssetEvent<StyleSheetAsset>>>,
+) {
+   let modified_asset_ids = asset_event_reader
+      .read(world.resource::<Events<AssetEvent<StyleSheetAsset>>>())
+      .filter_map(|event| match event {
+         AssetEvent::Added { id } | AssetEvent::Modified { id } => Some(*id),
+         _ => None,
+      })
+      .collect::<Vec<_>>();
+   if modified_asset_ids.is_empty() && !world.is_resource_changed::<TypedEntities>() {
+      return;
+   }
+
+   let labels = world.resource::<TypedStyleLabels>();
+   let typed_entities = world.resource::<TypedEntities>();
+   let mut replaced = vec![];
+   for (asset_id, asset) in world.resource::<Assets<StyleSheetAsset>>().iter() {
+      let is_modified = modified_asset_ids.contains(&asset_id);
+      for labeled_style_sheets in asset.0.iter() {
+         let Some(entity) = labels
+            .get(labeled_style_sheets.label.as_str())
+            .and_then(|type_id| typed_entities.get(type_id))
+            .cloned()
+         else {
+            continue;
+         };
+         if is_modified || !world.entity(entity).contains::<StyleSheetAssetApplied>() {
+            replaced.push((entity, labeled_style_sheets.style_sheets.clone()));
+         }
+      }
+   }
+
+   for (entity, style_sheets) in replaced {
+      if let Err(err) = replace_shared_style_sheets(world, entity, style_sheets) {
+         warn!("apply style sheet asset failed: {:?}", err);
+      }
+   }
+}
+
+/// Replaces the style sheets of a shared style entity and updates the style of its subscribers.
+pub fn replace_shared_style_sheets(
+   world: &mut World,
+   shared_entity: Entity,
+   style_sheets: Vec<StyleSheetDefinition>,
+) -> Result {
+   let style_media = world.applied_style_media();
+   let mut entity_world_mut = world.entity_mut(shared_entity);
+   entity_world_mut.insert(StyleSheetAssetApplied);
+
+   let style_sheets_state = entity_world_mut.get_style_sheets_state()?;
+   let mut style_sheets = style_sheets.into_iter().map(Some).collect::<Vec<_>>();
+   // subscribers keep their indices of the previous style sheets, so the list never shrinks
+   let prev_len = style_sheets_state.inline_style_sheet.len();
+   if style_sheets.len() < prev_len {
+      style_sheets.resize_with(prev_len, || Some(StyleSheetDefinition::default()));
+   }
+   let prev_style_sheets = core::mem::replace(
+      &mut style_sheets_state.inline_style_sheet,
+      style_sheets.clone(),
+   );
+
+   let subscribers = entity_world_mut
+      .get_shared_style_state()?
+      .subscribers
+      .iter()
+      .cloned()
+      .collect::<Vec<_>>();
+   for subscriber in subscribers {
+      let Some(entity_world_mut) = world.get_entity_mut(subscriber) else {
+         continue;
+      };
+      resubscribe_shared_style_sheets(
+         entity_world_mut,
+         shared_entity,
+         &prev_style_sheets,
+         &style_sheets,
+         &style_media,
+      )?;
+   }
+   Ok(())
+}
+
+fn resubscribe_shared_style_sheets(
+   mut entity_world_mut: EntityWorldMut,
+   shared_entity: Entity,
+   prev_style_sheets: &[Option<StyleSheetDefinition>],
+   style_sheets: &[Option<StyleSheetDefinition>],
+   style_media: &StyleMedia,
+) -> Result {
+   let node_id = entity_world_mut.id();
+   let attr_is_set = entity_world_mut
+      .get::<ElementEntityExtraData>()
+      .map(|n| n.attr_is_set)
+      .unwrap_or_default();
+   let mut changed_attr_ids = vec![];
+   let mut reset_attr_ids = vec![];
+   let added_indices =
+      add_shared_style_sheet_slots(&mut entity_world_mut, shared_entity, style_sheets.len())?;
+
+   entity_world_mut.scoped_style_sheets_state(|entity_world_mut, style_sheets_state| {
+      entity_world_mut.scoped_style_state(|entity_world_mut, style_state| {
+         for (style_sheet_index, style_sheet_id) in
+            style_sheets_state.shared_style_sheet_ids.iter().enumerate()
+         {
+            let Some(style_sheet_id) = style_sheet_id else {
+               continue;
+            };
+            if style_sheet_id.node_id != shared_entity {
+               continue;
+            }
+            let node_style_sheet_id = NodeStyleSheetId {
+               index: style_sheet_index as _,
+               location: StyleSheetLocation::Shared,
+            };
+            for (style_sheets, applied) in [(prev_style_sheets, false), (style_sheets, true)] {
+               // the items of the previous style sheet were never added to a new slot
+               if !applied && added_indices.contains(&(style_sheet_index as _)) {
+                  continue;
+               }
+               let Some(Some(style_sheet_definition)) =
+                  style_sheets.get(style_sheet_id.index as usize)
+               else {
+                  continue;
+               };
+               if !style_sheet_definition.is_applied_to_owner(style_media) {
+                  continue;
+               }
+               changed_attr_ids.extend(style_sheet_definition.items.iter().map(|n| n.attr_id));
+               set_style_sheet_items_applied(
+                  entity_world_mut,
+                  style_state,
+                  style_sheet_definition,
+                  node_style_sheet_id,
+                  applied,
+                  &mut reset_attr_ids,
+               )?;
+            }
+         }
+         Ok::<(), StyleError>(())
+      })??;
+      Ok::<(), StyleError>(())
+   })??;
+
+   if changed_attr_ids.is_empty() {
+      return Ok(());
+   }
+
+   entity_world_mut.world_scope(|world: &mut World| {
+      for attr_index in reset_attr_ids.iter().cloned() {
+         if !ElementEntityExtraData::static_is_set_attr(attr_is_set, attr_index) {
+            set_style_attr_value(world, node_id, attr_index, None);
+         }
+      }
+   });
+
+   EntityStyleAttrInfoIterArgs {
+      limit_attr_ids: Some(changed_attr_ids.as_slice()),
+      ..EntityStyleAttrInfoIterArgs::all_kind()
+   }
+   .iter_and_sync_set(entity_world_mut)?;
+   Ok(())
+}
+
+/// Gives the node a shared style sheet slot for each style sheet of `shared_entity` it does not
+/// subscribe yet, and returns the indices of the new slots
+fn add_shared_style_sheet_slots(
+   entity_world_mut: &mut EntityWorldMut,
+   shared_entity: Entity,
+   style_sheets_len: usize,
+) -> Result<Vec<StyleSheetIndex>> {
+   let subscribed_indices = entity_world_mut
+      .get_style_sheets_state()?
+      .shared_style_sheet_ids
+      .iter()
+      .flatten()
+      .filter(|n| n.node_id == shared_entity)
+      .map(|n| n.index)
+      .collect::<Vec<_>>();
+   let mut added_indices = vec![];
+   for index in 0..style_sheets_len {
+      let index = to_style_sheet_index(index, StyleSheetLocation::Inline)?;
+      if subscribed_indices.contains(&index) {
+         continue;
+      }
+      entity_world_mut.insert_if_not_exist(NodeAddedStyleSheetsState::default());
+      let free_index = entity_world_mut
+         .get_mut::<NodeAddedStyleSheetsState>()
+         .unwrap()
+         .free_indices
+         .pop();
+      let applied_style_sheet = AppliedStyleSheet::Shared(StyleSheetId {
+         node_style_sheet_id: NodeStyleSheetId {
+            index,
+            location: StyleSheetLocation::Inline,
+         },
+         node_id: shared_entity,
+      });
+      let style_sheets_state = entity_world_mut.get_style_sheets_state()?;
+      let style_sheet_index = match free_index {
+         Some(style_sheet_index) => {
+            style_sheets_state.set_applied_style_sheet(style_sheet_index, applied_style_sheet);
+            style_sheet_index
+         }
+         None => {
+            let style_sheet_index =
+               style_sheets_state.get_style_sheet_len(StyleSheetLocation::Shared)?;
+            style_sheets_state.push_applied_style_sheet(applied_style_sheet);
+            style_sheet_index
+         }
+      };
+      entity_world_mut
+         .get_mut::<NodeAddedStyleSheetsState>()
+         .unwrap()
+         .added
+         .push((shared_entity, style_sheet_index));
+      added_indices.push(style_sheet_index);
+   }
+   Ok(added_indices)
+}
+
+/// Removes the slots [`replace_shared_style_sheets`] gave the node for the style sheets of
+/// `shared_entities`, once it no longer subscribes them
+pub(super) fn remove_added_shared_style_sheets(
+   entity_world_mut: &mut EntityWorldMut,
+   shared_entities: &[Entity],
+   style_media: &StyleMedia,
+   reset_attr_ids: &mut Vec<AttrIndex>,
+) -> Result {
+   let Some(mut added_style_sheets_state) = entity_world_mut.get_mut::<NodeAddedStyleSheetsState>()
+   else {
+      return Ok(());
+   };
+   let mut removed_indices = vec![];
+   added_style_sheets_state
+      .added
+      .retain(|(shared_entity, style_sheet_index)| {
+         let is_removed = shared_entities.contains(shared_entity);
+         if is_removed {
+            removed_indices.push(*style_sheet_index);
+         }
+         !is_removed
+      });
+   added_style_sheets_state
+      .free_indices
+      .extend(removed_indices.iter().cloned());
+
+   for style_sheet_index in removed_indices {
+      let Some(style_sheet_id) = entity_world_mut
+         .get_style_sheets_state()?
+         .shared_style_sheet_ids[style_sheet_index as usize]
+         .take()
+      else {
+         continue;
+      };
+      let style_sheet_definition = entity_world_mut.world_scope(|world: &mut World| {
+         world
+            .entity_mut(style_sheet_id.node_id)
+            .get_style_sheets_state()?
+            .get_inline_style_sheet(style_sheet_id.index)
+            .cloned()
+      })?;
+      if !style_sheet_definition.is_applied_to_owner(style_media) {
+         continue;
+      }
+      entity_world_mut.scoped_style_state(|entity_world_mut, style_state| {
+         set_style_sheet_items_applied(
+            entity_world_mut,
+            style_state,
+            &style_sheet_definition,
+            NodeStyleSheetId {
+               index: style_sheet_index,
+               location: StyleSheetLocation::Shared,
+            },
+            false,
+            reset_attr_ids,
+         )
+      })??;
+   }
+   Ok(())
+}
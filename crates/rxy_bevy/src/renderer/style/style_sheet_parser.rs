@@ -0,0 +1,669 @@
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+use bevy_color::{Color, Srgba};
+use bevy_reflect::std_traits::ReflectDefault;
+use bevy_reflect::{
+   DynamicEnum, DynamicTuple, DynamicVariant, Reflect, ReflectMut, TypeInfo, TypeRegistry,
+   VariantInfo,
+};
+
+use rxy_core::style::{
+   var, StyleInteraction, StyleItemValue, StyleMediaQuery, StyleOrientation, StyleSheetDefinition,
+};
+
+use rxy_core::AttrValue;
+
+use crate::attrs::ALL_ATTRS;
+
+#[derive(Debug)]
+pub struct StyleSheetParseError {
+   pub line: usize,
+   pub message: String,
+}
+
+impl Display for StyleSheetParseError {
+   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      write!(f, "line {}: {}", self.line, self.message)
+   }
+}
+
+impl std::error::Error for StyleSheetParseError {}
+
+/// The style sheets of a `TypedStyle` label, in the order they appear in the file.
+#[derive(Clone, Debug)]
+pub struct LabeledStyleSheets {
+   pub label: String,
+   pub style_sheets: Vec<StyleSheetDefinition>,
+}
+
+/// Parses a css like style sheet file:
+///
+/// ```text
+/// // rules are keyed by `TypedStyle` label names
+/// ButtonStyle {
+///    width: 120px;
+///    bg_color: #3366aa;
+///    justify_content: center;
+/// }
+/// ButtonStyle:hover {
+///    bg_color: rgb(80, 120, 200);
+/// }
+/// @media (max-width: 600px) and (orientation: portrait) {
+///    ButtonStyle {
+///       width: 100%;
+///    }
+/// }
+/// ```
+///
+/// Attr names are the names of [`ALL_ATTRS`] (`-` may be used instead of `_`), values are parsed
+/// into the attr value through reflection, except `var(--name)` that references a style variable.
+pub fn parse_style_sheets(
+   text: &str,
+   type_registry: &TypeRegistry,
+) -> Result<Vec<LabeledStyleSheets>, StyleSheetParseError> {
+   let mut parser = StyleSheetParser {
+      text,
+      pos: 0,
+      type_registry,
+      result: vec![],
+   };
+   parser.parse_rules(None, false)?;
+   Ok(parser.result)
+}
+
+struct StyleSheetParser<'a> {
+   text: &'a str,
+   pos: usize,
+   type_registry: &'a TypeRegistry,
+   result: Vec<LabeledStyleSheets>,
+}
+
+impl<'a> StyleSheetParser<'a> {
+   fn line(&self) -> usize {
+      self.text[..self.pos].matches('\n').count() + 1
+   }
+
+   fn error(&self, message: impl Into<String>) -> StyleSheetParseError {
+      StyleSheetParseError {
+         line: self.line(),
+         message: message.into(),
+      }
+   }
+
+   fn rest(&self) -> &'a str {
+      &self.text[self.pos..]
+   }
+
+   fn skip_whitespace_and_comments(&mut self) -> Result<(), StyleSheetParseError> {
+      loop {
+         let rest = self.rest();
+         let trimmed = rest.trim_start();
+         self.pos += rest.len() - trimmed.len();
+         if trimmed.starts_with("//") {
+            self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+         } else if trimmed.starts_with("/*") {
+            let Some(end) = trimmed.find("*/") else {
+               return Err(self.error("unterminated comment"));
+            };
+            self.pos += end + 2;
+         } else {
+            return Ok(());
+         }
+      }
+   }
+
+   fn eat(&mut self, c: char) -> bool {
+      if self.rest().starts_with(c) {
+         self.pos += c.len_utf8();
+         true
+      } else {
+         false
+      }
+   }
+
+   fn expect(&mut self, c: char) -> Result<(), StyleSheetParseError> {
+      self.skip_whitespace_and_comments()?;
+      if !self.eat(c) {
+         return Err(self.error(format!("expected `{}`", c)));
+      }
+      Ok(())
+   }
+
+   /// Consumes until one of `stops` (not included)
+   fn take_until(&mut self, stops: &[char]) -> &'a str {
+      let rest = self.rest();
+      let len = rest.find(|c| stops.contains(&c)).unwrap_or(rest.len());
+      self.pos += len;
+      rest[..len].trim()
+   }
+
+   fn parse_rules(
+      &mut self,
+      media: Option<StyleMediaQuery>,
+      in_block: bool,
+   ) -> Result<(), StyleSheetParseError> {
+      loop {
+         self.skip_whitespace_and_comments()?;
+         if self.rest().is_empty() {
+            if in_block {
+               return Err(self.error("expected `}`"));
+            }
+            return Ok(());
+         }
+         if in_block && self.eat('}') {
+            return Ok(());
+         }
+         if self.rest().starts_with("@media") {
+            if media.is_some() {
+               return Err(self.error("nested `@media` is not supported"));
+            }
+            self.pos += "@media".len();
+            let condition = self.take_until(&['{']);
+            let media = self.parse_media_query(condition)?;
+            self.expect('{')?;
+            self.parse_rules(Some(media), true)?;
+            continue;
+         }
+         self.parse_rule(media)?;
+      }
+   }
+
+   fn parse_rule(&mut self, media: Option<StyleMediaQuery>) -> Result<(), StyleSheetParseError> {
+      let selector = self.take_until(&['{', '}', ';']);
+      let (label, interaction) = match selector.split_once(':') {
+         None => (selector, None),
+         Some((label, interaction)) => (
+            label.trim(),
+            Some(match interaction.trim() {
+               "hover" => StyleInteraction::Hover,
+               "active" => StyleInteraction::Active,
+               "focus" => StyleInteraction::Focus,
+               "disabled" => StyleInteraction::Disabled,
+               "checked" => StyleInteraction::Checked,
+               "selected" => StyleInteraction::Selected,
+               "invalid" => StyleInteraction::Invalid,
+               n => return Err(self.error(format!("unknown interaction `{}`", n))),
+            }),
+         ),
+      };
+      if label.is_empty() || !label.chars().all(|c| c.is_alphanumeric() || c == '_') {
+         return Err(self.error(format!("invalid label `{}`", label)));
+      }
+      self.expect('{')?;
+
+      let mut items = vec![];
+      loop {
+         self.skip_whitespace_and_comments()?;
+         if self.eat('}') {
+            break;
+         }
+         if self.rest().is_empty() {
+            return Err(self.error("expected `}`"));
+         }
+         let name = self.take_until(&[':', ';', '}']);
+         self.expect(':')?;
+         let value = self.take_until(&[';', '}']);
+         items.push(self.parse_item(name, value)?);
+         self.eat(';');
+      }
+
+      let style_sheet = StyleSheetDefinition {
+         interaction,
+         media,
+         selector: None,
+         items,
+      };
+      match self.result.iter_mut().find(|n| n.label == label) {
+         None => self.result.push(LabeledStyleSheets {
+            label: label.to_string(),
+            style_sheets: vec![style_sheet],
+         }),
+         Some(n) => n.style_sheets.push(style_sheet),
+      }
+      Ok(())
+   }
+
+   fn parse_item(&self, name: &str, value: &str) -> Result<StyleItemValue, StyleSheetParseError> {
+      let attr_name = name.replace('-', "_");
+      let Some(attr) = ALL_ATTRS
+         .iter()
+         .flat_map(|n| n.iter())
+         .find(|n| n.attr_name() == attr_name)
+      else {
+         return Err(self.error(format!("unknown attr `{}`", name)));
+      };
+      if let ("var", Some(var_name)) = split_call(value) {
+         return Ok(StyleItemValue {
+            attr_id: attr.index(),
+            value: var(var_name.trim().to_string()).clone_att_value(),
+         });
+      }
+      let mut attr_value = attr.default_value();
+      if !parse_reflect_value(attr_value.as_reflect_mut(), value, self.type_registry) {
+         return Err(self.error(format!("invalid value `{}` of attr `{}`", value, name)));
+      }
+      Ok(StyleItemValue {
+         attr_id: attr.index(),
+         value: attr_value,
+      })
+   }
+
+   fn parse_media_query(&self, text: &str) -> Result<StyleMediaQuery, StyleSheetParseError> {
+      let mut query = StyleMediaQuery::default();
+      for condition in text.split(" and ") {
+         let condition = condition
+            .trim()
+            .strip_prefix('(')
+            .and_then(|n| n.strip_suffix(')'))
+            .ok_or_else(|| self.error(format!("invalid media condition `{}`", condition)))?;
+         let (name, value) = match condition.split_once(':') {
+            None => (condition.trim(), None),
+            Some((name, value)) => (name.trim(), Some(value.trim())),
+         };
+         let invalid = || self.error(format!("invalid media condition `{}`", condition));
+         let number = || {
+            value
+               .map(|n| n.trim_end_matches(|c: char| c.is_alphabetic()))
+               .and_then(|n| n.parse::<f32>().ok())
+               .ok_or_else(invalid)
+         };
+         match name {
+            "min-width" => query.min_width = Some(number()?),
+            "max-width" => query.max_width = Some(number()?),
+            "min-height" => query.min_height = Some(number()?),
+            "max-height" => query.max_height = Some(number()?),
+            "min-resolution" => query.min_scale_factor = Some(number()?),
+            "max-resolution" => query.max_scale_factor = Some(number()?),
+            "orientation" => {
+               query.orientation = Some(match value {
+                  Some("portrait") => StyleOrientation::Portrait,
+                  Some("landscape") => StyleOrientation::Landscape,
+                  _ => return Err(invalid()),
+               })
+            }
+            "prefers-reduced-motion" => {
+               query.reduced_motion = Some(match value {
+                  None | Some("reduce") => true,
+                  Some("no-preference") => false,
+                  _ => return Err(invalid()),
+               })
+            }
+            "prefers-color-scheme" => {
+               query.dark = Some(match value {
+                  Some("dark") => true,
+                  Some("light") => false,
+                  _ => return Err(invalid()),
+               })
+            }
+            _ => return Err(invalid()),
+         }
+      }
+      Ok(query)
+   }
+}
+
+fn normalize_name(name: &str) -> String {
+   name
+      .chars()
+      .filter(|c| *c != '-' && *c != '_')
+      .flat_map(|c| c.to_lowercase())
+      .collect()
+}
+
+/// Splits on `separator` outside of parentheses
+fn split_args(text: &str, separator: impl Fn(char) -> bool) -> Vec<&str> {
+   let mut args = vec![];
+   let mut depth = 0;
+   let mut start = 0;
+   for (i, c) in text.char_indices() {
+      match c {
+         '(' => depth += 1,
+         ')' => depth -= 1,
+         c if depth == 0 && separator(c) => {
+            args.push(text[start..i].trim());
+            start = i + c.len_utf8();
+         }
+         _ => {}
+      }
+   }
+   args.push(text[start..].trim());
+   args.retain(|n| !n.is_empty());
+   args
+}
+
+/// `name(args)` or `name`
+fn split_call(text: &str) -> (&str, Option<&str>) {
+   match text.split_once('(') {
+      Some((name, args)) if text.ends_with(')') => (name.trim(), Some(&args[..args.len() - 1])),
+      _ => (text, None),
+   }
+}
+
+/// `10px` -> (10, "px"), `50%` -> (50, "percent")
+fn split_number_unit(text: &str) -> Option<(f32, &str)> {
+   let unit_start = text
+      .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+      .unwrap_or(text.len());
+   let number = text[..unit_start].parse::<f32>().ok()?;
+   let unit = match &text[unit_start..] {
+      "%" => "percent",
+      unit => unit,
+   };
+   Some((number, unit))
+}
+
+fn default_reflect(type_id: TypeId, type_registry: &TypeRegistry) -> Option<Box<dyn Reflect>> {
+   type_registry
+      .get_type_data::<ReflectDefault>(type_id)
+      .map(|n| n.default())
+}
+
+pub fn parse_color(text: &str) -> Option<Color> {
+   let color = match text {
+      "transparent" => Color::NONE,
+      "white" => Color::WHITE,
+      "black" => Color::BLACK,
+      text if text.starts_with('#') => Srgba::hex(text).ok()?.into(),
+      text => {
+         let (name, args) = split_call(text);
+         let args = split_args(args?, |c| c == ',' || c.is_whitespace())
+            .into_iter()
+            .map(|n| n.parse::<f32>().ok())
+            .collect::<Option<Vec<_>>>()?;
+         match (name, args.as_slice()) {
+            ("rgb", &[r, g, b]) => Color::srgb_u8(r as _, g as _, b as _),
+            ("rgba", &[r, g, b, a]) => Color::srgba_u8(r as _, g as _, b as _, (a * 255.) as _),
+            _ => return None,
+         }
+      }
+   };
+   Some(color)
+}
+
+macro_rules! parse_from_str_values {
+    ($value:ident, $text:ident; $($ty:ty)*) => {
+        $(
+            if let Some(value) = $value.downcast_mut::<$ty>() {
+                return match $text.parse::<$ty>() {
+                    Ok(n) => {
+                        *value = n;
+                        true
+                    }
+                    Err(_) => false,
+                };
+            }
+        )*
+    };
+}
+
+/// Parses `text` into `value` in place, returns `false` if `text` does not fit its type.
+///
+/// Enums accept their variant names in any case (`flex-start`, `FlexStart`), `name(args)` for
+/// tuple variants and `<number><variant>` for variants with a single number like `Val::Px`. A
+/// value that does not name a variant is parsed into the single field of the current variant, so
+/// `Option<T>` and other newtype like enums accept the inner value directly.
+pub fn parse_reflect_value(
+   value: &mut dyn Reflect,
+   text: &str,
+   type_registry: &TypeRegistry,
+) -> bool {
+   let text = text.trim();
+   if let Some(color) = value.downcast_mut::<Color>() {
+      return match parse_color(text) {
+         None => false,
+         Some(n) => {
+            *color = n;
+            true
+         }
+      };
+   }
+   if let Some(value) = value.downcast_mut::<Cow<'static, str>>() {
+      *value = Cow::Owned(text.trim_matches('"').to_string());
+      return true;
+   }
+   if let Some(value) = value.downcast_mut::<String>() {
+      *value = text.trim_matches('"').to_string();
+      return true;
+   }
+   parse_from_str_values!(value, text; f32 f64 i8 i16 i32 i64 u8 u16 u32 u64 isize usize bool);
+
+   match value.reflect_mut() {
+      ReflectMut::Struct(value) => {
+         let args = split_args(text, |c| c == ',' || c.is_whitespace());
+         args.len() == value.field_len()
+            && args.into_iter().enumerate().all(|(i, arg)| {
+               parse_reflect_value(value.field_at_mut(i).unwrap(), arg, type_registry)
+            })
+      }
+      ReflectMut::TupleStruct(value) => {
+         let args = split_args(text, |c| c == ',' || c.is_whitespace());
+         args.len() == value.field_len()
+            && args
+               .into_iter()
+               .enumerate()
+               .all(|(i, arg)| parse_reflect_value(value.field_mut(i).unwrap(), arg, type_registry))
+      }
+      ReflectMut::Tuple(value) => {
+         let args = split_args(text, |c| c == ',' || c.is_whitespace());
+         args.len() == value.field_len()
+            && args
+               .into_iter()
+               .enumerate()
+               .all(|(i, arg)| parse_reflect_value(value.field_mut(i).unwrap(), arg, type_registry))
+      }
+      ReflectMut::Enum(_) => parse_enum_value(value, text, type_registry),
+      _ => false,
+   }
+}
+
+fn parse_enum_value(value: &mut dyn Reflect, text: &str, type_registry: &TypeRegistry) -> bool {
+   let Some(TypeInfo::Enum(enum_info)) = value.get_represented_type_info() else {
+      return false;
+   };
+   let find_variant = |name: &str| {
+      let name = normalize_name(name);
+      enum_info
+         .iter()
+         .find(|variant| normalize_name(variant.name()) == name)
+   };
+
+   let build_variant = |variant: &VariantInfo, args: &[&str]| -> Option<DynamicEnum> {
+      match variant {
+         VariantInfo::Unit(variant) if args.is_empty() => {
+            Some(DynamicEnum::new(variant.name(), DynamicVariant::Unit))
+         }
+         VariantInfo::Tuple(variant) if args.len() == variant.field_len() => {
+            let mut tuple = DynamicTuple::default();
+            for (i, arg) in args.iter().enumerate() {
+               let mut field = default_reflect(variant.field_at(i)?.type_id(), type_registry)?;
+               if !parse_reflect_value(field.as_mut(), arg, type_registry) {
+                  return None;
+               }
+               tuple.insert_boxed(field);
+            }
+            Some(DynamicEnum::new(
+               variant.name(),
+               DynamicVariant::Tuple(tuple),
+            ))
+         }
+         _ => None,
+      }
+   };
+
+   let (name, args) = split_call(text);
+   let dynamic_enum = find_variant(name)
+      .and_then(|variant| {
+         let args = args.map(|n| split_args(n, |c| c == ',')).unwrap_or_default();
+         build_variant(variant, &args)
+      })
+      .or_else(|| {
+         let (number, unit) = split_number_unit(text)?;
+         let variant = if unit.is_empty() {
+            // a bare number uses the first variant that holds a single number
+            enum_info.iter().find(|variant| {
+               matches!(variant, VariantInfo::Tuple(n) if n.field_len() == 1 && n.field_at(0).unwrap().is::<f32>())
+            })?
+         } else {
+            find_variant(unit)?
+         };
+         build_variant(variant, &[&number.to_string()])
+      });
+   if let Some(dynamic_enum) = dynamic_enum {
+      return value.try_apply(&dynamic_enum).is_ok();
+   }
+
+   let ReflectMut::Enum(enum_value) = value.reflect_mut() else {
+      unreachable!()
+   };
+   if enum_value.field_len() == 1 {
+      if let Some(field) = enum_value.field_at_mut(0) {
+         if parse_reflect_value(field, text, type_registry) {
+            return true;
+         }
+      }
+   }
+
+   // newtype like variants, e.g. `Some(T)` of `Option<T>`
+   let mut single_field_variants = enum_info
+      .iter()
+      .filter(|variant| matches!(variant, VariantInfo::Tuple(n) if n.field_len() == 1));
+   match (single_field_variants.next(), single_field_variants.next()) {
+      (Some(variant), None) => build_variant(variant, &[text])
+         .map(|dynamic_enum| value.try_apply(&dynamic_enum).is_ok())
+         .unwrap_or(false),
+      _ => false,
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use bevy_ui::{JustifyContent, Val};
+
+   use rxy_core::style::StyleVar;
+   use rxy_core::HasIndex;
+
+   use crate::attrs::{bg_color, justify_content, width};
+
+   use super::*;
+
+   fn parse(text: &str) -> Result<Vec<LabeledStyleSheets>, StyleSheetParseError> {
+      parse_style_sheets(text, &TypeRegistry::new())
+   }
+
+   fn value<T: Reflect>(item: &StyleItemValue) -> &T {
+      item.value.as_reflect().downcast_ref::<T>().unwrap()
+   }
+
+   #[test]
+   fn group_rules_by_label() {
+      let result = parse(
+         "
+         ButtonStyle { width: 120px; }
+         // a comment
+         PanelStyle { width: 50%; }
+         ButtonStyle { bg_color: #3366aa; }
+         ",
+      )
+      .unwrap();
+      assert_eq!(result.len(), 2);
+      assert_eq!(result[0].label, "ButtonStyle");
+      assert_eq!(result[0].style_sheets.len(), 2);
+      assert_eq!(result[1].label, "PanelStyle");
+
+      let item = &result[0].style_sheets[0].items[0];
+      assert_eq!(item.attr_id, width::INDEX);
+      assert_eq!(value::<Val>(item), &Val::Px(120.));
+      let item = &result[0].style_sheets[1].items[0];
+      assert_eq!(item.attr_id, bg_color::INDEX);
+      assert_eq!(
+         value::<Color>(item),
+         &Color::from(Srgba::hex("#3366aa").unwrap())
+      );
+      assert_eq!(
+         value::<Val>(&result[1].style_sheets[0].items[0]),
+         &Val::Percent(50.)
+      );
+   }
+
+   #[test]
+   fn parse_interactions() {
+      let result =
+         parse("ButtonStyle:hover { width: 1px; } ButtonStyle : focus { width: 2px; }").unwrap();
+      let style_sheets = &result[0].style_sheets;
+      assert_eq!(style_sheets[0].interaction, Some(StyleInteraction::Hover));
+      assert_eq!(style_sheets[1].interaction, Some(StyleInteraction::Focus));
+
+      let err = parse("ButtonStyle:pressed { width: 1px; }").unwrap_err();
+      assert_eq!(err.message, "unknown interaction `pressed`");
+   }
+
+   #[test]
+   fn parse_media_blocks() {
+      let result = parse(
+         "
+         @media (max-width: 600px) and (orientation: portrait) {
+            ButtonStyle { width: 100%; }
+         }
+         ButtonStyle { width: 120px; }
+         ",
+      )
+      .unwrap();
+      let style_sheets = &result[0].style_sheets;
+      assert_eq!(
+         style_sheets[0].media,
+         Some(StyleMediaQuery {
+            max_width: Some(600.),
+            orientation: Some(StyleOrientation::Portrait),
+            ..Default::default()
+         })
+      );
+      assert_eq!(style_sheets[1].media, None);
+   }
+
+   #[test]
+   fn parse_var_values() {
+      let result = parse("ButtonStyle { bg_color: var(--primary); width: var(gap); }").unwrap();
+      let items = &result[0].style_sheets[0].items;
+      assert_eq!(items[0].attr_id, bg_color::INDEX);
+      assert_eq!(value::<StyleVar>(&items[0]), &var("primary"));
+      assert_eq!(items[1].attr_id, width::INDEX);
+      assert_eq!(value::<StyleVar>(&items[1]), &var("gap"));
+   }
+
+   #[test]
+   fn accept_dashes_in_attr_names() {
+      let result =
+         parse("ButtonStyle { justify-content: space-between; justify_content: center; }").unwrap();
+      let items = &result[0].style_sheets[0].items;
+      assert_eq!(items[0].attr_id, justify_content::INDEX);
+      assert_eq!(
+         value::<JustifyContent>(&items[0]),
+         &JustifyContent::SpaceBetween
+      );
+      assert_eq!(items[1].attr_id, justify_content::INDEX);
+      assert_eq!(value::<JustifyContent>(&items[1]), &JustifyContent::Center);
+   }
+
+   #[test]
+   fn report_the_line_of_errors() {
+      let err = parse("ButtonStyle {\n   width: 1px;\n   widht: 2px;\n}").unwrap_err();
+      assert_eq!(err.line, 3);
+      assert_eq!(err.message, "unknown attr `widht`");
+
+      let err = parse("ButtonStyle {\n\n   width: wide;\n}").unwrap_err();
+      assert_eq!(err.line, 3);
+      assert_eq!(err.message, "invalid value `wide` of attr `width`");
+
+      let err = parse("ButtonStyle {\n   width: 1px;\n").unwrap_err();
+      assert_eq!(err.line, 3);
+      assert_eq!(err.message, "expected `}`");
+
+      let err = parse("\n@media (min-width: wide) {\n}").unwrap_err();
+      assert_eq!(err.line, 2);
+
+      let err = parse("Button-Style { width: 1px; }").unwrap_err();
+      assert_eq!(err.message, "invalid label `Button-Style`");
+   }
+}
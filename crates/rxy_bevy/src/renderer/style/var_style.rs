@@ -0,0 +1,239 @@
+use std::borrow::Cow;
+
+use bevy_derive::{Deref, DerefMut};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::EntityHashSet;
+use bevy_ecs::prelude::{Changed, Entity, QueryState, World};
+use bevy_ecs::world::EntityWorldMut;
+use bevy_hierarchy::{Children, Parent};
+use bevy_utils::tracing::warn;
+use bevy_utils::HashMap;
+
+use rxy_core::style::{style_var_name, StyleAttrValue, StyleVar};
+use rxy_core::AttrIndex;
+
+use crate::attrs::get_attr_by_index;
+
+use super::EntityStyleAttrInfoIterArgs;
+
+/// The style variables defined on the node, visible to the node and its descendants.
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut)]
+pub struct NodeStyleVars(pub HashMap<Cow<'static, str>, StyleAttrValue>);
+
+/// The attrs of the node currently set from a [`StyleVar`], by the name of the variable.
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut)]
+pub struct NodeStyleVarDependencies(pub HashMap<AttrIndex, Cow<'static, str>>);
+
+pub trait StyleVarsEntityWorldMutExt {
+   /// Define the style variable `name` on the node, or remove it when `value` is `None`.
+   ///
+   /// The attrs of the node and its descendants referencing the variable are synced again.
+   fn set_style_var(&mut self, name: impl Into<Cow<'static, str>>, value: Option<StyleAttrValue>);
+}
+
+impl StyleVarsEntityWorldMutExt for EntityWorldMut<'_> {
+   fn set_style_var(&mut self, name: impl Into<Cow<'static, str>>, value: Option<StyleAttrValue>) {
+      let name = style_var_name(name.into());
+      match value {
+         Some(value) => {
+            if !self.contains::<NodeStyleVars>() {
+               self.insert(NodeStyleVars::default());
+            }
+            self
+               .get_mut::<NodeStyleVars>()
+               .unwrap()
+               .insert(name.clone(), value);
+         }
+         None => {
+            let Some(mut vars) = self.get_mut::<NodeStyleVars>() else {
+               return;
+            };
+            if vars.remove(&name).is_none() {
+               return;
+            }
+         }
+      }
+      let entity = self.id();
+      self.world_scope(|world| sync_style_var_dependents(world, entity, &name));
+   }
+}
+
+/// Set the value of a style item to the attr of the node, the [`StyleVar`] values are resolved
+/// through the node and its ancestors first.
+pub(super) fn set_style_attr_value(
+   world: &mut World,
+   node_id: Entity,
+   attr_index: AttrIndex,
+   value: Option<StyleAttrValue>,
+) {
+   let attr = get_attr_by_index(attr_index);
+   let var_name = value
+      .as_ref()
+      .and_then(StyleVar::from_value)
+      .map(|n| n.0.clone());
+   let value = match var_name {
+      None => {
+         if let Some(mut dependencies) = world.get_mut::<NodeStyleVarDependencies>(node_id) {
+            dependencies.remove(&attr_index);
+         }
+         value
+      }
+      Some(var_name) => {
+         let var_value = resolve_style_var(world, node_id, &var_name).filter(|var_value| {
+            let is_matched =
+               (*var_value.as_any()).type_id() == (*attr.default_value().as_any()).type_id();
+            if !is_matched {
+               warn!(
+                  "the value of style var `{}` does not match the value type of attr `{}`",
+                  var_name,
+                  attr.attr_name()
+               );
+            }
+            is_matched
+         });
+         let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
+            return;
+         };
+         if !entity_world_mut.contains::<NodeStyleVarDependencies>() {
+            entity_world_mut.insert(NodeStyleVarDependencies::default());
+         }
+         entity_world_mut
+            .get_mut::<NodeStyleVarDependencies>()
+            .unwrap()
+            .insert(attr_index, var_name);
+         var_value
+      }
+   };
+   attr.set_value(world, node_id, value);
+}
+
+/// The value of the variable `name` defined on the node or its nearest ancestor
+pub fn resolve_style_var(world: &World, node_id: Entity, name: &str) -> Option<StyleAttrValue> {
+   let mut entity = Some(node_id);
+   while let Some(current) = entity {
+      if let Some(value) = world
+         .get::<NodeStyleVars>(current)
+         .and_then(|vars| vars.get(name))
+      {
+         return Some(value.clone());
+      }
+      entity = world.get::<Parent>(current).map(|n| n.get());
+   }
+   None
+}
+
+fn sync_style_var_attrs(world: &mut World, entity: Entity, attr_ids: &[AttrIndex]) {
+   let Some(entity_world_mut) = world.get_entity_mut(entity) else {
+      return;
+   };
+   let iter_args = EntityStyleAttrInfoIterArgs {
+      limit_attr_ids: Some(attr_ids),
+      ..EntityStyleAttrInfoIterArgs::all_kind()
+   };
+   if let Err(err) = iter_args.iter_and_sync_set(entity_world_mut) {
+      warn!("sync style var attrs of {:?} failed: {:?}", entity, err);
+   }
+}
+
+/// Syncs the attrs of `root` and its descendants that reference a variable matching `is_matched`
+fn sync_subtree_style_var_dependents(
+   world: &mut World,
+   root: Entity,
+   is_matched: impl Fn(&str) -> bool,
+) {
+   let mut dependents = vec![];
+   let mut stack = vec![root];
+   while let Some(entity) = stack.pop() {
+      if let Some(dependencies) = world.get::<NodeStyleVarDependencies>(entity) {
+         let attr_ids = dependencies
+            .iter()
+            .filter_map(|(attr_index, var_name)| is_matched(var_name).then_some(*attr_index))
+            .collect::<Vec<_>>();
+         if !attr_ids.is_empty() {
+            dependents.push((entity, attr_ids));
+         }
+      }
+      if let Some(children) = world.get::<Children>(entity) {
+         stack.extend(children.iter().copied());
+      }
+   }
+
+   for (entity, attr_ids) in dependents {
+      sync_style_var_attrs(world, entity, &attr_ids);
+   }
+}
+
+fn sync_style_var_dependents(world: &mut World, root: Entity, name: &str) {
+   sync_subtree_style_var_dependents(world, root, |var_name| var_name == name);
+}
+
+/// The ancestors of a moved node are different, so the variables referenced in its subtree are
+/// resolved again.
+pub fn update_moved_style_var_dependents(
+   world: &mut World,
+   moved_query: &mut QueryState<Entity, Changed<Parent>>,
+) {
+   let moved = moved_query.iter(world).collect::<EntityHashSet>();
+   for entity in moved.iter().copied() {
+      // The subtree of a moved ancestor already includes the node
+      let mut ancestor = world.get::<Parent>(entity).map(|n| n.get());
+      let mut is_in_moved_subtree = false;
+      while let Some(current) = ancestor {
+         if moved.contains(&current) {
+            is_in_moved_subtree = true;
+            break;
+         }
+         ancestor = world.get::<Parent>(current).map(|n| n.get());
+      }
+      if !is_in_moved_subtree {
+         sync_subtree_style_var_dependents(world, entity, |_| true);
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use bevy_hierarchy::BuildWorldChildren;
+
+   use rxy_core::AttrValue;
+
+   use super::*;
+
+   fn resolve_f32(world: &World, node_id: Entity, name: &str) -> Option<f32> {
+      resolve_style_var(world, node_id, name)
+         .map(|value| *value.as_any().downcast_ref::<f32>().unwrap())
+   }
+
+   #[test]
+   fn resolve_through_the_nearest_ancestor() {
+      let mut world = World::new();
+      let mut child = None;
+      let mut grandchild = None;
+      let root = world
+         .spawn_empty()
+         .with_children(|parent| {
+            let mut child_world_mut = parent.spawn_empty();
+            child_world_mut.with_children(|parent| {
+               grandchild = Some(parent.spawn_empty().id());
+            });
+            child = Some(child_world_mut.id());
+         })
+         .id();
+      let (child, grandchild) = (child.unwrap(), grandchild.unwrap());
+
+      let mut root_world_mut = world.entity_mut(root);
+      root_world_mut.set_style_var("--gap", Some(1f32.clone_att_value()));
+      root_world_mut.set_style_var("radius", Some(4f32.clone_att_value()));
+      world
+         .entity_mut(child)
+         .set_style_var("gap", Some(2f32.clone_att_value()));
+
+      assert_eq!(resolve_f32(&world, grandchild, "gap"), Some(2.));
+      assert_eq!(resolve_f32(&world, grandchild, "radius"), Some(4.));
+      assert_eq!(resolve_f32(&world, root, "gap"), Some(1.));
+      assert_eq!(resolve_f32(&world, grandchild, "missing"), None);
+
+      world.entity_mut(child).set_style_var("--gap", None);
+      assert_eq!(resolve_f32(&world, grandchild, "gap"), Some(1.));
+   }
+}
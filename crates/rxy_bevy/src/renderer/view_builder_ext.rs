@@ -2,7 +2,7 @@ use bevy_ecs::bundle::Bundle;
 
 use rxy_core::{ElementView, MemberOwner};
 
-use crate::{BevyRenderer, XBundle};
+use crate::{AutoFocus, BevyRenderer, FocusScope, XBundle};
 
 macro_rules! impl_view_builder_ext {
    ($name:ident;$ty:ident) => {
@@ -14,6 +14,22 @@ macro_rules! impl_view_builder_ext {
          {
             self.member(XBundle(bundle))
          }
+
+         #[inline]
+         fn focus_scope(self) -> Self::AddMember<XBundle<FocusScope>>
+         where
+            Self: Sized,
+         {
+            self.member(XBundle(FocusScope::default()))
+         }
+
+         #[inline]
+         fn autofocus(self) -> Self::AddMember<XBundle<AutoFocus>>
+         where
+            Self: Sized,
+         {
+            self.member(XBundle(AutoFocus))
+         }
       }
 
       impl<T> $name for T where T: $ty<BevyRenderer> + Sized {}
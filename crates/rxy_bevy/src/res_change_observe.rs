@@ -5,10 +5,13 @@ use bevy_ecs::prelude::*;
 
 use crate::add_system;
 
+type ResSelector<T> = Box<dyn FnMut(&T) -> bool + Send + Sync>;
+
 #[derive(Resource)]
 pub struct ResChangeObserve<T: Resource> {
    sender: async_broadcast::Sender<()>,
-   receiver: async_broadcast::Receiver<()>,
+   receiver: async_broadcast::InactiveReceiver<()>,
+   selectors: Vec<ResSelector<T>>,
    _marker: PhantomData<T>,
 }
 
@@ -20,7 +23,8 @@ where
       let (sender, receiver) = async_broadcast::broadcast(1024);
       Self {
          sender,
-         receiver,
+         receiver: receiver.deactivate(),
+         selectors: vec![],
          _marker: Default::default(),
       }
    }
@@ -30,12 +34,28 @@ impl<T: Resource> ResChangeObserve<T> {
    pub fn new() -> Self {
       Self::default()
    }
+
+   fn is_observed(&self) -> bool {
+      self.receiver.receiver_count() > 0 || !self.selectors.is_empty()
+   }
+
+   fn system_observe(mut res_change: ResMut<Self>, res: Res<T>) {
+      if res_change.receiver.receiver_count() > 0 {
+         let _ = res_change.sender.try_broadcast(());
+      }
+      res_change.selectors.retain_mut(|selector| selector(&res));
+   }
 }
 
 pub trait ResChangeWorldExt {
    fn get_res_change_receiver<T>(&mut self) -> async_broadcast::Receiver<()>
    where
       T: Resource;
+
+   /// Runs `selector` every time the resource changes, until it returns false.
+   fn add_res_selector<T>(&mut self, selector: impl FnMut(&T) -> bool + Send + Sync + 'static)
+   where
+      T: Resource;
 }
 
 impl ResChangeWorldExt for World {
@@ -43,23 +63,39 @@ impl ResChangeWorldExt for World {
    where
       T: Resource,
    {
-      let is_add_system = self.contains_resource::<ResChangeObserve<T>>();
-      let res_change_observe = self.get_resource_or_insert_with(ResChangeObserve::<T>::new);
+      res_change_observe_scoped::<T, _>(self, |res_change| res_change.receiver.activate_cloned())
+   }
 
-      let receiver = res_change_observe.receiver.clone();
-      fn res_observe<T: Resource>(res_change: Res<ResChangeObserve<T>>) {
-         let _ = res_change.sender.try_broadcast(());
-      }
+   fn add_res_selector<T>(&mut self, selector: impl FnMut(&T) -> bool + Send + Sync + 'static)
+   where
+      T: Resource,
+   {
+      res_change_observe_scoped::<T, _>(self, |res_change| {
+         res_change.selectors.push(Box::new(selector));
+      })
+   }
+}
 
-      if !is_add_system {
-         add_system(
-            self,
-            PreUpdate,
-            res_observe::<T>.run_if(|res_change: Res<ResChangeObserve<T>>, res: Res<T>| {
-               res_change.receiver.receiver_count() > 0 && res.is_changed()
-            }),
-         );
-      }
-      receiver
+fn res_change_observe_scoped<T, U>(
+   world: &mut World,
+   f: impl FnOnce(&mut ResChangeObserve<T>) -> U,
+) -> U
+where
+   T: Resource,
+{
+   let is_add_system = world.contains_resource::<ResChangeObserve<T>>();
+   let u = f(&mut world.get_resource_or_insert_with(ResChangeObserve::<T>::new));
+
+   if !is_add_system {
+      add_system(
+         world,
+         PreUpdate,
+         ResChangeObserve::<T>::system_observe.run_if(
+            |res_change: Res<ResChangeObserve<T>>, res: Res<T>| {
+               res_change.is_observed() && res.is_changed()
+            },
+         ),
+      );
    }
+   u
 }
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use bevy_ecs::prelude::Resource;
+use xy_reactive::prelude::{use_rw_signal, ReadSignal, SignalSet, SignalWithUntracked};
+
+use crate::{ResChangeWorldExt, SchemaCtx};
+
+pub trait ResMemoSchemaCtxExt {
+   /// A signal of the part of the resource selected by `f`.
+   ///
+   /// `f` runs every time the resource changes, but subscribers are only notified when the
+   /// selected value is not equal to the previous one. Unlike `x_res`, views reading the signal
+   /// are not rebuilt as a whole.
+   fn use_res_memo<T, U>(&mut self, f: impl Fn(&T) -> U + Send + Sync + 'static) -> ReadSignal<U>
+   where
+      T: Resource,
+      U: PartialEq + Send + Sync + 'static;
+}
+
+impl ResMemoSchemaCtxExt for SchemaCtx {
+   fn use_res_memo<T, U>(&mut self, f: impl Fn(&T) -> U + Send + Sync + 'static) -> ReadSignal<U>
+   where
+      T: Resource,
+      U: PartialEq + Send + Sync + 'static,
+   {
+      self.mut_scoped(|ctx| {
+         let signal = use_rw_signal(f(ctx.world.resource::<T>()));
+
+         // The selector is dropped with the schema: it lives as long as this guard
+         let guard = Arc::new(());
+         let weak_guard = Arc::downgrade(&guard);
+         ctx.world.add_res_selector::<T>(move |res| {
+            if weak_guard.strong_count() == 0 {
+               return false;
+            }
+            let value = f(res);
+            if signal.with_untracked(|n| *n != value) {
+               signal.set(value);
+            }
+            true
+         });
+         ctx.add_drop_guard(guard);
+
+         signal.read_only()
+      })
+   }
+}
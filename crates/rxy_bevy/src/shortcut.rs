@@ -0,0 +1,239 @@
+use bevy_app::PreUpdate;
+use bevy_ecs::prelude::{Commands, Entity, Query, Res, Resource, World};
+use bevy_ecs::schedule::IntoSystemConfigs;
+use bevy_ecs::system::SystemId;
+use bevy_hierarchy::Parent;
+use bevy_input::keyboard::KeyCode;
+use bevy_input::{ButtonInput, InputSystem};
+use bevy_reflect::Reflect;
+
+use crate::{add_system, FocusedEntity};
+
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyModifiers {
+   pub ctrl: bool,
+   pub shift: bool,
+   pub alt: bool,
+   pub super_key: bool,
+}
+
+impl KeyModifiers {
+   pub fn pressed(input: &ButtonInput<KeyCode>) -> Self {
+      Self {
+         ctrl: input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+         shift: input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+         alt: input.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+         super_key: input.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]),
+      }
+   }
+}
+
+/// A key combined with the modifiers that must be held, e.g. `KeyChord::new(KeyCode::KeyS).ctrl()`.
+///
+/// Modifiers are matched exactly: `Ctrl+S` does not fire while `Ctrl+Shift` is held.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+   pub key: KeyCode,
+   pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+   pub fn new(key: KeyCode) -> Self {
+      Self {
+         key,
+         modifiers: KeyModifiers::default(),
+      }
+   }
+
+   pub fn ctrl(mut self) -> Self {
+      self.modifiers.ctrl = true;
+      self
+   }
+
+   pub fn shift(mut self) -> Self {
+      self.modifiers.shift = true;
+      self
+   }
+
+   pub fn alt(mut self) -> Self {
+      self.modifiers.alt = true;
+      self
+   }
+
+   pub fn super_key(mut self) -> Self {
+      self.modifiers.super_key = true;
+      self
+   }
+
+   pub fn just_pressed(&self, input: &ButtonInput<KeyCode>) -> bool {
+      input.just_pressed(self.key) && KeyModifiers::pressed(input) == self.modifiers
+   }
+}
+
+impl From<KeyCode> for KeyChord {
+   fn from(key: KeyCode) -> Self {
+      Self::new(key)
+   }
+}
+
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShortcutScope {
+   /// Active while the view is mounted
+   #[default]
+   Mounted,
+   /// Active while the node or one of its descendants is focused
+   Focused,
+}
+
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShortcutOptions {
+   pub scope: ShortcutScope,
+   /// When several shortcuts match the same chord, only the one with the highest priority runs.
+   pub priority: i32,
+}
+
+impl ShortcutOptions {
+   pub fn focused() -> Self {
+      Self {
+         scope: ShortcutScope::Focused,
+         priority: 0,
+      }
+   }
+
+   pub fn priority(mut self, priority: i32) -> Self {
+      self.priority = priority;
+      self
+   }
+}
+
+#[derive(Debug, Clone)]
+pub struct ShortcutEntry {
+   pub node_id: Entity,
+   pub chord: KeyChord,
+   pub options: ShortcutOptions,
+   pub system_id: SystemId,
+   order: u64,
+}
+
+/// Registered shortcuts of all mounted views.
+#[derive(Resource, Default, Debug)]
+pub struct Shortcuts {
+   entries: Vec<ShortcutEntry>,
+   next_order: u64,
+}
+
+impl Shortcuts {
+   pub fn entries(&self) -> &[ShortcutEntry] {
+      &self.entries
+   }
+
+   pub fn register(
+      &mut self,
+      node_id: Entity,
+      chord: KeyChord,
+      options: ShortcutOptions,
+      system_id: SystemId,
+   ) {
+      self.entries.push(ShortcutEntry {
+         node_id,
+         chord,
+         options,
+         system_id,
+         order: self.next_order,
+      });
+      self.next_order += 1;
+   }
+
+   pub fn unregister(&mut self, system_id: SystemId) {
+      self.entries.retain(|n| n.system_id != system_id);
+   }
+
+   /// Picks the shortcut that handles `chord`.
+   ///
+   /// Conflicts are resolved by priority first, then focused scopes closer to the focused node
+   /// win over farther ones and over mounted scopes, and finally the latest registered wins.
+   pub fn resolve(
+      &self,
+      chord: &KeyChord,
+      focus: Option<Entity>,
+      parents: &Query<&Parent>,
+   ) -> Option<&ShortcutEntry> {
+      self
+         .entries
+         .iter()
+         .filter(|n| n.chord == *chord)
+         .filter_map(|n| {
+            let focus_depth = match n.options.scope {
+               ShortcutScope::Mounted => None,
+               ShortcutScope::Focused => Some(focus_depth(n.node_id, focus?, parents)?),
+            };
+            Some((n, focus_depth))
+         })
+         .max_by(|(a, a_depth), (b, b_depth)| {
+            a.options
+               .priority
+               .cmp(&b.options.priority)
+               .then_with(|| match (a_depth, b_depth) {
+                  (Some(a), Some(b)) => b.cmp(a),
+                  (a, b) => a.is_some().cmp(&b.is_some()),
+               })
+               .then_with(|| a.order.cmp(&b.order))
+         })
+         .map(|(n, _)| n)
+   }
+
+   pub fn system_handle(
+      shortcuts: Res<Shortcuts>,
+      keyboard_input: Res<ButtonInput<KeyCode>>,
+      focus: Res<FocusedEntity>,
+      parents: Query<&Parent>,
+      mut commands: Commands,
+   ) {
+      let modifiers = KeyModifiers::pressed(&keyboard_input);
+      for key in keyboard_input.get_just_pressed() {
+         let chord = KeyChord {
+            key: *key,
+            modifiers,
+         };
+         if let Some(entry) = shortcuts.resolve(&chord, focus.0, &parents) {
+            commands.run_system(entry.system_id);
+         }
+      }
+   }
+}
+
+/// The number of ancestors between the focused entity and `node_id`, if it is focused or contains the focus.
+fn focus_depth(node_id: Entity, mut focus: Entity, parents: &Query<&Parent>) -> Option<usize> {
+   let mut depth = 0;
+   loop {
+      if focus == node_id {
+         return Some(depth);
+      }
+      focus = parents.get(focus).ok()?.get();
+      depth += 1;
+   }
+}
+
+pub(crate) fn add_shortcut(
+   world: &mut World,
+   node_id: Entity,
+   chord: KeyChord,
+   options: ShortcutOptions,
+   system_id: SystemId,
+) {
+   let is_add_system = world.contains_resource::<Shortcuts>();
+
+   world
+      .get_resource_or_insert_with(Shortcuts::default)
+      .register(node_id, chord, options, system_id);
+
+   if !is_add_system {
+      add_system(
+         world,
+         PreUpdate,
+         Shortcuts::system_handle
+            .after(InputSystem)
+            .run_if(|shortcuts: Res<Shortcuts>| !shortcuts.entries.is_empty()),
+      );
+   }
+}
@@ -0,0 +1,601 @@
+use core::f32::consts::{PI, TAU};
+use core::time::Duration;
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::{Entity, EventReader, EventWriter, Local, Res, Resource};
+use bevy_ecs::schedule::IntoSystemConfigs;
+use bevy_ecs::system::SystemParam;
+use bevy_input::touch::{TouchInput, TouchPhase};
+use bevy_math::Vec2;
+use bevy_mod_picking::focus::HoverMap;
+use bevy_mod_picking::picking_core::PickSet;
+use bevy_mod_picking::pointer::{Location, PointerId};
+use bevy_mod_picking::prelude::*;
+use bevy_reflect::Reflect;
+use bevy_render::camera::NormalizedRenderTarget;
+use bevy_utils::Instant;
+use bevy_window::WindowRef;
+
+/// Thresholds of the touch gesture recognizers.
+#[derive(Resource, Reflect, Debug, Clone)]
+pub struct TouchGestureSettings {
+   /// The max time a finger may be down for a tap
+   pub tap_max_duration: Duration,
+   /// How far the touches may move before a pan starts, a tap is no longer recognized past it
+   pub pan_threshold: f32,
+   /// The min speed, in logical pixels per second, of a one-finger pan release to count as a swipe
+   pub swipe_min_velocity: f32,
+   /// How much the distance between two fingers must change, relative to the start, for a pinch
+   pub pinch_threshold: f32,
+   /// How much two fingers must turn, in radians, for a rotation
+   pub rotate_threshold: f32,
+}
+
+impl Default for TouchGestureSettings {
+   fn default() -> Self {
+      Self {
+         tap_max_duration: Duration::from_millis(300),
+         pan_threshold: 10.,
+         swipe_min_velocity: 500.,
+         pinch_threshold: 0.05,
+         rotate_threshold: 0.1,
+      }
+   }
+}
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GesturePhase {
+   Started,
+   Changed,
+   Ended,
+   /// The touches were canceled by the system, the gesture should be reverted
+   Canceled,
+}
+
+/// A single finger touched and released the target without moving.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct Tap;
+
+/// The touches moved together. The event position is the centroid of the touches.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct Pan {
+   pub phase: GesturePhase,
+   /// The movement since the last `Pan` event
+   pub delta: Vec2,
+   /// The movement since the pan started
+   pub translation: Vec2,
+   pub touches: usize,
+}
+
+/// Two fingers moved apart or closer. The event position is the midpoint of the fingers.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct Pinch {
+   pub phase: GesturePhase,
+   /// The distance between the fingers relative to when they touched down
+   pub scale: f32,
+   /// The scale relative to the last `Pinch` event
+   pub delta: f32,
+}
+
+/// Two fingers turned around their midpoint. The event position is the midpoint of the fingers.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct Rotate {
+   pub phase: GesturePhase,
+   /// The clockwise angle in radians since the fingers touched down
+   pub angle: f32,
+   /// The angle since the last `Rotate` event
+   pub delta: f32,
+}
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+   Left,
+   Right,
+   Up,
+   Down,
+}
+
+/// A one-finger pan released fast, sent after the `Pan` ended.
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct Swipe {
+   pub direction: SwipeDirection,
+   pub velocity: Vec2,
+}
+
+/// Recognizes gestures from [`TouchInput`], sent as bubbling events to the entity under the
+/// first finger.
+///
+/// Gestures don't replace pointer events: the picking `Drag` events of each finger are still sent,
+/// so handlers decide which one to react to.
+pub struct TouchGesturePlugin;
+
+impl Plugin for TouchGesturePlugin {
+   fn build(&self, app: &mut App) {
+      app.register_type::<TouchGestureSettings>()
+         .init_resource::<TouchGestureSettings>()
+         .add_event::<Pointer<Tap>>()
+         .add_event::<Pointer<Pan>>()
+         .add_event::<Pointer<Pinch>>()
+         .add_event::<Pointer<Rotate>>()
+         .add_event::<Pointer<Swipe>>()
+         .add_systems(PreUpdate, touch_gesture_events.in_set(PickSet::PostFocus));
+   }
+}
+
+#[derive(SystemParam)]
+struct GestureWriters<'w> {
+   tap: EventWriter<'w, Pointer<Tap>>,
+   pan: EventWriter<'w, Pointer<Pan>>,
+   pinch: EventWriter<'w, Pointer<Pinch>>,
+   rotate: EventWriter<'w, Pointer<Rotate>>,
+   swipe: EventWriter<'w, Pointer<Swipe>>,
+}
+
+struct TwoTouchState {
+   start_distance: f32,
+   start_angle: f32,
+   scale: f32,
+   angle: f32,
+   is_pinching: bool,
+   is_rotating: bool,
+}
+
+struct GestureSession {
+   pointer_id: PointerId,
+   target: Option<Entity>,
+   render_target: Option<NormalizedRenderTarget>,
+   /// In touch down order, for two-finger angles to be stable
+   touches: Vec<(u64, Vec2)>,
+   started_at: Instant,
+   max_touches: usize,
+   centroid: Vec2,
+   translation: Vec2,
+   velocity: Vec2,
+   last_moved_at: Option<Instant>,
+   is_panning: bool,
+   two_touch: Option<TwoTouchState>,
+}
+
+fn topmost_hovered(hover_map: &HoverMap, pointer_id: PointerId) -> Option<Entity> {
+   hover_map
+      .get(&pointer_id)?
+      .iter()
+      .min_by(|(_, a), (_, b)| a.depth.total_cmp(&b.depth))
+      .map(|(entity, _)| *entity)
+}
+
+fn centroid(touches: &[(u64, Vec2)]) -> Vec2 {
+   touches.iter().map(|(_, position)| *position).sum::<Vec2>() / touches.len().max(1) as f32
+}
+
+/// The distance and angle between two touches.
+fn two_touch_metrics(touches: &[(u64, Vec2)]) -> Option<(f32, f32)> {
+   let [(_, a), (_, b)] = touches else {
+      return None;
+   };
+   let d = *b - *a;
+   Some((d.length(), d.y.atan2(d.x)))
+}
+
+fn wrap_angle(angle: f32) -> f32 {
+   (angle + PI).rem_euclid(TAU) - PI
+}
+
+fn swipe_direction(velocity: Vec2) -> SwipeDirection {
+   if velocity.x.abs() >= velocity.y.abs() {
+      if velocity.x > 0. {
+         SwipeDirection::Right
+      } else {
+         SwipeDirection::Left
+      }
+   } else if velocity.y > 0. {
+      SwipeDirection::Down
+   } else {
+      SwipeDirection::Up
+   }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReleaseGesture {
+   Tap,
+   Swipe(SwipeDirection),
+}
+
+impl GestureSession {
+   fn new(touch: &TouchInput, now: Instant) -> Self {
+      Self {
+         pointer_id: PointerId::Touch(touch.id),
+         target: None,
+         render_target: WindowRef::Entity(touch.window)
+            .normalize(None)
+            .map(NormalizedRenderTarget::Window),
+         touches: vec![],
+         started_at: now,
+         max_touches: 0,
+         centroid: touch.position,
+         translation: Vec2::ZERO,
+         velocity: Vec2::ZERO,
+         last_moved_at: None,
+         is_panning: false,
+         two_touch: None,
+      }
+   }
+
+   fn send<E>(&self, writer: &mut EventWriter<Pointer<E>>, event: E)
+   where
+      E: core::fmt::Debug + Clone + Reflect,
+   {
+      let (Some(target), Some(render_target)) = (self.target, self.render_target.clone()) else {
+         return;
+      };
+      writer.send(Pointer::new(
+         self.pointer_id,
+         Location {
+            target: render_target,
+            position: self.centroid,
+         },
+         target,
+         event,
+      ));
+   }
+
+   fn send_pan(&self, writers: &mut GestureWriters, phase: GesturePhase, delta: Vec2) {
+      self.send(
+         &mut writers.pan,
+         Pan {
+            phase,
+            delta,
+            translation: self.translation,
+            touches: self.touches.len(),
+         },
+      );
+   }
+
+   /// Ends the two-finger gestures, and starts tracking them again if two fingers are down.
+   fn reset_two_touch(&mut self, writers: &mut GestureWriters, end_phase: GesturePhase) {
+      if let Some(two_touch) = self.two_touch.take() {
+         if two_touch.is_pinching {
+            self.send(
+               &mut writers.pinch,
+               Pinch {
+                  phase: end_phase,
+                  scale: two_touch.scale,
+                  delta: 1.,
+               },
+            );
+         }
+         if two_touch.is_rotating {
+            self.send(
+               &mut writers.rotate,
+               Rotate {
+                  phase: end_phase,
+                  angle: two_touch.angle,
+                  delta: 0.,
+               },
+            );
+         }
+      }
+      self.two_touch = two_touch_metrics(&self.touches).map(|(distance, angle)| TwoTouchState {
+         start_distance: distance.max(f32::EPSILON),
+         start_angle: angle,
+         scale: 1.,
+         angle: 0.,
+         is_pinching: false,
+         is_rotating: false,
+      });
+   }
+
+   /// The centroid jumps when a finger is added or removed, it is not a movement.
+   fn on_touches_changed(&mut self, writers: &mut GestureWriters, end_phase: GesturePhase) {
+      if !self.touches.is_empty() {
+         self.centroid = centroid(&self.touches);
+      }
+      self.reset_two_touch(writers, end_phase);
+   }
+
+   fn update(
+      &mut self,
+      now: Instant,
+      settings: &TouchGestureSettings,
+      writers: &mut GestureWriters,
+   ) {
+      if self.touches.is_empty() {
+         return;
+      }
+      let centroid = centroid(&self.touches);
+      let delta = centroid - self.centroid;
+      self.centroid = centroid;
+      if delta != Vec2::ZERO {
+         self.translation += delta;
+         if let Some(last_moved_at) = self.last_moved_at {
+            let dt = now.duration_since(last_moved_at).as_secs_f32();
+            if dt > 0. {
+               self.velocity = delta / dt;
+            }
+         }
+         self.last_moved_at = Some(now);
+      }
+
+      if !self.is_panning && self.translation.length() > settings.pan_threshold {
+         self.is_panning = true;
+         self.send_pan(writers, GesturePhase::Started, self.translation);
+      } else if self.is_panning && delta != Vec2::ZERO {
+         self.send_pan(writers, GesturePhase::Changed, delta);
+      }
+
+      let (Some(mut two_touch), Some((distance, angle))) =
+         (self.two_touch.take(), two_touch_metrics(&self.touches))
+      else {
+         return;
+      };
+
+      let scale = distance / two_touch.start_distance;
+      let scale_delta = scale / two_touch.scale;
+      two_touch.scale = scale;
+      if !two_touch.is_pinching && (scale - 1.).abs() > settings.pinch_threshold {
+         two_touch.is_pinching = true;
+         self.send(
+            &mut writers.pinch,
+            Pinch {
+               phase: GesturePhase::Started,
+               scale,
+               delta: scale,
+            },
+         );
+      } else if two_touch.is_pinching && scale_delta != 1. {
+         self.send(
+            &mut writers.pinch,
+            Pinch {
+               phase: GesturePhase::Changed,
+               scale,
+               delta: scale_delta,
+            },
+         );
+      }
+
+      let angle = wrap_angle(angle - two_touch.start_angle);
+      let angle_delta = wrap_angle(angle - two_touch.angle);
+      two_touch.angle = angle;
+      if !two_touch.is_rotating && angle.abs() > settings.rotate_threshold {
+         two_touch.is_rotating = true;
+         self.send(
+            &mut writers.rotate,
+            Rotate {
+               phase: GesturePhase::Started,
+               angle,
+               delta: angle,
+            },
+         );
+      } else if two_touch.is_rotating && angle_delta != 0. {
+         self.send(
+            &mut writers.rotate,
+            Rotate {
+               phase: GesturePhase::Changed,
+               angle,
+               delta: angle_delta,
+            },
+         );
+      }
+
+      self.two_touch = Some(two_touch);
+   }
+
+   /// What the release of the last finger is recognized as, besides the end of the pan.
+   fn release_gesture(
+      &self,
+      now: Instant,
+      settings: &TouchGestureSettings,
+   ) -> Option<ReleaseGesture> {
+      if self.max_touches != 1 {
+         return None;
+      }
+      if self.is_panning {
+         // A finger resting before the release is not a swipe
+         let is_moving = self.last_moved_at.map_or(false, |n| {
+            now.duration_since(n) < Duration::from_millis(100)
+         });
+         (is_moving && self.velocity.length() >= settings.swipe_min_velocity)
+            .then(|| ReleaseGesture::Swipe(swipe_direction(self.velocity)))
+      } else {
+         (now.duration_since(self.started_at) <= settings.tap_max_duration)
+            .then_some(ReleaseGesture::Tap)
+      }
+   }
+
+   /// Called when the last finger is released.
+   fn end(
+      &mut self,
+      now: Instant,
+      settings: &TouchGestureSettings,
+      writers: &mut GestureWriters,
+      phase: GesturePhase,
+   ) {
+      if self.is_panning {
+         self.send_pan(writers, phase, Vec2::ZERO);
+      }
+      if phase != GesturePhase::Canceled {
+         match self.release_gesture(now, settings) {
+            Some(ReleaseGesture::Tap) => self.send(&mut writers.tap, Tap),
+            Some(ReleaseGesture::Swipe(direction)) => self.send(
+               &mut writers.swipe,
+               Swipe {
+                  direction,
+                  velocity: self.velocity,
+               },
+            ),
+            None => {}
+         }
+      }
+      self.is_panning = false;
+   }
+}
+
+fn touch_gesture_events(
+   settings: Res<TouchGestureSettings>,
+   mut touch_inputs: EventReader<TouchInput>,
+   hover_map: Res<HoverMap>,
+   mut session: Local<Option<GestureSession>>,
+   mut writers: GestureWriters,
+) {
+   let now = Instant::now();
+   for touch in touch_inputs.read() {
+      if touch.phase == TouchPhase::Started
+         && session.as_ref().map_or(true, |n| n.touches.is_empty())
+      {
+         *session = Some(GestureSession::new(touch, now));
+      }
+      let Some(session) = session.as_mut() else {
+         continue;
+      };
+      match touch.phase {
+         TouchPhase::Started => {
+            if session.target.is_none() {
+               session.target = topmost_hovered(&hover_map, PointerId::Touch(touch.id));
+            }
+            session.touches.push((touch.id, touch.position));
+            session.max_touches = session.max_touches.max(session.touches.len());
+            session.on_touches_changed(&mut writers, GesturePhase::Ended);
+         }
+         TouchPhase::Moved => {
+            if let Some((_, position)) = session.touches.iter_mut().find(|(id, _)| *id == touch.id)
+            {
+               *position = touch.position;
+            }
+         }
+         TouchPhase::Ended | TouchPhase::Canceled => {
+            let phase = if touch.phase == TouchPhase::Canceled {
+               GesturePhase::Canceled
+            } else {
+               GesturePhase::Ended
+            };
+            // Flush the last movement of the finger before it is removed
+            if let Some((_, position)) = session.touches.iter_mut().find(|(id, _)| *id == touch.id)
+            {
+               *position = touch.position;
+            }
+            session.update(now, &settings, &mut writers);
+            session.touches.retain(|(id, _)| *id != touch.id);
+            session.on_touches_changed(&mut writers, phase);
+            if session.touches.is_empty() {
+               session.end(now, &settings, &mut writers, phase);
+            }
+         }
+      }
+   }
+
+   let Some(session) = session.as_mut() else {
+      return;
+   };
+   if session.target.is_none() {
+      // The first finger may not be picked yet in the frame it touched down
+      session.target = session
+         .touches
+         .iter()
+         .find_map(|(id, _)| topmost_hovered(&hover_map, PointerId::Touch(*id)));
+   }
+   session.update(now, &settings, &mut writers);
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   fn session(started_at: Instant) -> GestureSession {
+      GestureSession {
+         pointer_id: PointerId::Touch(0),
+         target: None,
+         render_target: None,
+         touches: vec![],
+         started_at,
+         max_touches: 1,
+         centroid: Vec2::ZERO,
+         translation: Vec2::ZERO,
+         velocity: Vec2::ZERO,
+         last_moved_at: None,
+         is_panning: false,
+         two_touch: None,
+      }
+   }
+
+   #[test]
+   fn classify_a_short_touch_as_tap() {
+      let settings = TouchGestureSettings::default();
+      let started_at = Instant::now();
+      let session = session(started_at);
+
+      assert_eq!(
+         session.release_gesture(started_at + Duration::from_millis(100), &settings),
+         Some(ReleaseGesture::Tap)
+      );
+      assert_eq!(
+         session.release_gesture(started_at + Duration::from_millis(400), &settings),
+         None
+      );
+
+      let two_fingers = GestureSession {
+         max_touches: 2,
+         ..session
+      };
+      assert_eq!(
+         two_fingers.release_gesture(started_at + Duration::from_millis(100), &settings),
+         None
+      );
+   }
+
+   #[test]
+   fn classify_a_fast_pan_as_swipe() {
+      let settings = TouchGestureSettings::default();
+      let started_at = Instant::now();
+      let released_at = started_at + Duration::from_millis(200);
+      let pan = GestureSession {
+         is_panning: true,
+         velocity: Vec2::new(-800., 100.),
+         last_moved_at: Some(released_at - Duration::from_millis(16)),
+         ..session(started_at)
+      };
+      assert_eq!(
+         pan.release_gesture(released_at, &settings),
+         Some(ReleaseGesture::Swipe(SwipeDirection::Left))
+      );
+
+      let slow = GestureSession {
+         velocity: Vec2::new(-100., 0.),
+         ..pan
+      };
+      assert_eq!(slow.release_gesture(released_at, &settings), None);
+
+      let resting = GestureSession {
+         velocity: Vec2::new(-800., 0.),
+         last_moved_at: Some(released_at - Duration::from_millis(300)),
+         ..slow
+      };
+      assert_eq!(resting.release_gesture(released_at, &settings), None);
+   }
+
+   #[test]
+   fn swipe_direction_follows_the_main_axis() {
+      assert_eq!(
+         swipe_direction(Vec2::new(600., 100.)),
+         SwipeDirection::Right
+      );
+      assert_eq!(
+         swipe_direction(Vec2::new(-600., 100.)),
+         SwipeDirection::Left
+      );
+      // y grows downwards
+      assert_eq!(swipe_direction(Vec2::new(100., 600.)), SwipeDirection::Down);
+      assert_eq!(swipe_direction(Vec2::new(100., -600.)), SwipeDirection::Up);
+   }
+
+   #[test]
+   fn two_touch_metrics_and_wrapped_angles() {
+      let (distance, angle) =
+         two_touch_metrics(&[(0, Vec2::new(10., 10.)), (1, Vec2::new(10., 30.))]).unwrap();
+      assert_eq!(distance, 20.);
+      assert!((angle - PI / 2.).abs() < 1e-6);
+      assert_eq!(two_touch_metrics(&[(0, Vec2::ZERO)]), None);
+
+      // Turning past the negative x axis is a small rotation, not almost a full turn
+      assert!((wrap_angle(PI - 0.1 - (-PI + 0.1)) + 0.2).abs() < 1e-5);
+   }
+}
@@ -0,0 +1,119 @@
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
+use bevy_ecs::prelude::{Commands, Entity, Event, World};
+use bevy_hierarchy::Parent;
+
+/// A custom event targeted at a UI entity, listened to with `on_event` and observed through Bevy
+/// observers.
+///
+/// The event is triggered on the target first, then on each of its ancestors, until a listener
+/// calls [`propagate(false)`](UiEvent::propagate). Observers of Bevy 0.14 do not propagate by
+/// themselves, so the event walks up the `Parent`s here and the commands queued by the observers
+/// of each entity are applied before moving to its parent.
+/// Identifies one triggered [`UiEvent`], the same for the target and each of its ancestors.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UiEventId(u64);
+
+impl UiEventId {
+   fn next() -> Self {
+      static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+      Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+   }
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct UiEvent<E>
+where
+   E: Send + Sync + 'static,
+{
+   pub event: E,
+   pub id: UiEventId,
+   /// The entity the event was triggered on
+   pub target: Entity,
+   /// The entity whose listeners are running
+   pub current_target: Entity,
+   stopped: Arc<AtomicBool>,
+}
+
+impl<E> UiEvent<E>
+where
+   E: Send + Sync + 'static,
+{
+   /// Sets whether the event keeps bubbling to the parent of `current_target`.
+   pub fn propagate(&self, should_propagate: bool) {
+      self.stopped.store(!should_propagate, Ordering::Relaxed);
+   }
+
+   pub fn stop_propagation(&self) {
+      self.propagate(false);
+   }
+
+   pub fn is_propagation_stopped(&self) -> bool {
+      self.stopped.load(Ordering::Relaxed)
+   }
+}
+
+impl<E> Deref for UiEvent<E>
+where
+   E: Send + Sync + 'static,
+{
+   type Target = E;
+
+   fn deref(&self) -> &Self::Target {
+      &self.event
+   }
+}
+
+pub trait UiEventWorldExt {
+   fn trigger_ui_event<E>(&mut self, target: Entity, event: E)
+   where
+      E: Clone + Send + Sync + 'static;
+}
+
+impl UiEventWorldExt for World {
+   fn trigger_ui_event<E>(&mut self, target: Entity, event: E)
+   where
+      E: Clone + Send + Sync + 'static,
+   {
+      let id = UiEventId::next();
+      let stopped = Arc::new(AtomicBool::new(false));
+      let mut current_target = Some(target);
+      while let Some(entity) = current_target {
+         if self.get_entity(entity).is_none() {
+            break;
+         }
+         self.trigger_targets(
+            UiEvent {
+               event: event.clone(),
+               id,
+               target,
+               current_target: entity,
+               stopped: stopped.clone(),
+            },
+            entity,
+         );
+         self.flush_commands();
+         if stopped.load(Ordering::Relaxed) {
+            break;
+         }
+         current_target = self.get::<Parent>(entity).map(|n| n.get());
+      }
+   }
+}
+
+pub trait UiEventCommandsExt {
+   fn trigger_ui_event<E>(&mut self, target: Entity, event: E)
+   where
+      E: Clone + Send + Sync + 'static;
+}
+
+impl UiEventCommandsExt for Commands<'_, '_> {
+   fn trigger_ui_event<E>(&mut self, target: Entity, event: E)
+   where
+      E: Clone + Send + Sync + 'static,
+   {
+      self.add(move |world: &mut World| world.trigger_ui_event(target, event));
+   }
+}
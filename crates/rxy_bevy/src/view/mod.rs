@@ -1,7 +1,9 @@
+pub use query::*;
 pub use res::*;
 pub use system::*;
 pub use system_once::*;
 
+mod query;
 mod res;
 mod system;
 mod system_once;
@@ -0,0 +1,594 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use async_channel::{Receiver, Sender};
+use bevy_app::PostUpdate;
+use bevy_ecs::change_detection::Ref;
+use bevy_ecs::component::{Component, ComponentId, Tick};
+use bevy_ecs::entity::EntityHashSet;
+use bevy_ecs::prelude::{Entity, Mut, Res, Resource, World};
+use bevy_ecs::query::{
+   Added, Changed, FilteredAccess, Or, QueryFilter, QueryState, ROQueryItem, ReadOnlyQueryData,
+   WorldQuery,
+};
+use bevy_ecs::schedule::IntoSystemConfigs;
+
+use hooked_collection::VecOperation;
+use rxy_core::{
+   x_iter_source, DataOrPlaceholderNodeId, ForSource, IntoView, RendererWorld, VecDataSource, View,
+   ViewCtx,
+};
+
+use crate::{add_system, BevyRenderer};
+
+/// A change of the entities matching a query, sent to each [`XQuery`] list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryChange {
+   Added(Entity),
+   Changed(Entity),
+   Removed(Entity),
+}
+
+struct QuerySubscriber {
+   sender: Sender<QueryChange>,
+   entities: EntityHashSet,
+}
+
+/// The query data of an [`XQuery`], with the filters matching the entities whose components read
+/// by the data were added or changed since the last frame.
+pub trait QueryChangeFilter: ReadOnlyQueryData {
+   type Added: QueryFilter;
+   type Changed: QueryFilter;
+}
+
+impl<'a, T: Component> QueryChangeFilter for &'a T {
+   type Added = Added<T>;
+   type Changed = Changed<T>;
+}
+
+impl<'a, T: Component> QueryChangeFilter for Ref<'a, T> {
+   type Added = Added<T>;
+   type Changed = Changed<T>;
+}
+
+impl<'a, T: Component> QueryChangeFilter for Option<&'a T> {
+   type Added = Added<T>;
+   type Changed = Changed<T>;
+}
+
+macro_rules! impl_query_change_filter {
+   ($($d:ident),*) => {
+      impl<$($d: QueryChangeFilter),*> QueryChangeFilter for ($($d,)*) {
+         type Added = Or<($($d::Added,)*)>;
+         type Changed = Or<($($d::Changed,)*)>;
+      }
+   };
+}
+
+impl_query_change_filter!(D0);
+impl_query_change_filter!(D0, D1);
+impl_query_change_filter!(D0, D1, D2);
+impl_query_change_filter!(D0, D1, D2, D3);
+impl_query_change_filter!(D0, D1, D2, D3, D4);
+impl_query_change_filter!(D0, D1, D2, D3, D4, D5);
+impl_query_change_filter!(D0, D1, D2, D3, D4, D5, D6);
+impl_query_change_filter!(D0, D1, D2, D3, D4, D5, D6, D7);
+
+/// Sends the changes of the entities matching `(D, F)` to the mounted [`XQuery`] lists.
+///
+/// Only the entities reported by the `Added`/`Changed` filters of `D` and by the removed
+/// components read by `D` (including despawns) are checked each frame. Entities that start or
+/// stop matching only because of a component checked by `F` are not tracked.
+#[derive(Resource)]
+pub struct QueryChangeObserve<D, F>
+where
+   D: QueryChangeFilter + 'static,
+   F: QueryFilter + 'static,
+{
+   state: QueryState<(Entity, D), F>,
+   added: QueryState<Entity, (F, D::Added)>,
+   changed: QueryState<Entity, (F, D::Changed)>,
+   component_ids: Vec<ComponentId>,
+   last_run: Tick,
+   subscribers: Vec<QuerySubscriber>,
+}
+
+impl<D, F> QueryChangeObserve<D, F>
+where
+   D: QueryChangeFilter + 'static,
+   F: QueryFilter + 'static,
+{
+   fn new(world: &mut World) -> Self {
+      let mut access = FilteredAccess::<ComponentId>::default();
+      let data_state = D::init_state(world);
+      D::update_component_access(&data_state, &mut access);
+      Self {
+         state: QueryState::new(world),
+         added: QueryState::new(world),
+         changed: QueryState::new(world),
+         component_ids: access.access().reads_and_writes().collect(),
+         last_run: world.read_change_tick(),
+         subscribers: vec![],
+      }
+   }
+
+   fn system_observe(world: &mut World) {
+      world.resource_scope(|world, mut observe: Mut<Self>| {
+         let observe = &mut *observe;
+         observe.subscribers.retain(|n| !n.sender.is_closed());
+
+         let this_run = world.read_change_tick();
+         let last_run = std::mem::replace(&mut observe.last_run, this_run);
+
+         let (added, mut changed) = world.last_change_tick_scope(last_run, |world| {
+            let added = observe.added.iter(world).collect::<EntityHashSet>();
+            let changed = observe
+               .changed
+               .iter(world)
+               .filter(|entity| !added.contains(entity))
+               .collect::<EntityHashSet>();
+            (added, changed)
+         });
+
+         // Despawned entities show up as removed components too.
+         let mut removed = EntityHashSet::default();
+         for entity in observe
+            .component_ids
+            .iter()
+            .flat_map(|id| world.removed_with_id(*id))
+         {
+            if observe.state.get(world, entity).is_ok() {
+               // An optional component was removed, the entity still matches.
+               changed.insert(entity);
+            } else {
+               removed.insert(entity);
+            }
+         }
+
+         for subscriber in observe.subscribers.iter_mut() {
+            for entity in removed.iter() {
+               if subscriber.entities.remove(entity) {
+                  let _ = subscriber.sender.try_send(QueryChange::Removed(*entity));
+               }
+            }
+            for entity in added.iter().chain(changed.iter()) {
+               let change = if subscriber.entities.insert(*entity) {
+                  QueryChange::Added(*entity)
+               } else {
+                  QueryChange::Changed(*entity)
+               };
+               let _ = subscriber.sender.try_send(change);
+            }
+         }
+      });
+   }
+}
+
+fn subscribe_query_changes<D, F>(
+   world: &mut World,
+   entities: impl IntoIterator<Item = Entity>,
+) -> Receiver<QueryChange>
+where
+   D: QueryChangeFilter + 'static,
+   F: QueryFilter + 'static,
+{
+   let is_add_system = world.contains_resource::<QueryChangeObserve<D, F>>();
+   if !is_add_system {
+      let observe = QueryChangeObserve::<D, F>::new(world);
+      world.insert_resource(observe);
+   }
+
+   let (sender, receiver) = async_channel::unbounded();
+   world
+      .resource_mut::<QueryChangeObserve<D, F>>()
+      .subscribers
+      .push(QuerySubscriber {
+         sender,
+         entities: entities.into_iter().collect(),
+      });
+
+   if !is_add_system {
+      add_system(
+         world,
+         PostUpdate,
+         QueryChangeObserve::<D, F>::system_observe
+            .run_if(|observe: Res<QueryChangeObserve<D, F>>| !observe.subscribers.is_empty()),
+      );
+   }
+   receiver
+}
+
+type QuerySortFn<D, F> =
+   Box<dyn Fn(&World, &QueryState<(Entity, D), F>, Entity, Entity) -> Ordering + Send + Sync>;
+
+struct QueryShared<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   state: Mutex<QueryState<(Entity, D), F>>,
+   view_f: VF,
+   sort: Option<QuerySortFn<D, F>>,
+}
+
+impl<D, F, VF> QueryShared<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   fn view<IV>(&self, world: &World, entity: Entity) -> Option<IV>
+   where
+      VF: Fn(ROQueryItem<'_, D>) -> IV,
+   {
+      let mut state = self.state.lock().unwrap();
+      state.update_archetypes(world);
+      let (_, item) = state.get_manual(world, entity).ok()?;
+      Some((self.view_f)(item))
+   }
+
+   /// The index to insert `entity` at, after all items that are not sorted after it.
+   fn insert_index(
+      &self,
+      world: &World,
+      items: &[QueryListItem<D, F, VF>],
+      entity: Entity,
+   ) -> usize {
+      let Some(sort) = &self.sort else {
+         return items.len();
+      };
+      let mut state = self.state.lock().unwrap();
+      state.update_archetypes(world);
+      items.partition_point(|n| sort(world, &state, n.entity, entity) != Ordering::Greater)
+   }
+
+   fn is_in_order(&self, world: &World, items: &[QueryListItem<D, F, VF>], index: usize) -> bool {
+      let Some(sort) = &self.sort else {
+         return true;
+      };
+      let mut state = self.state.lock().unwrap();
+      state.update_archetypes(world);
+      let entity = items[index].entity;
+      let after_prev =
+         index == 0 || sort(world, &state, items[index - 1].entity, entity) != Ordering::Greater;
+      let before_next = index + 1 == items.len()
+         || sort(world, &state, entity, items[index + 1].entity) != Ordering::Greater;
+      after_prev && before_next
+   }
+}
+
+/// An item of an [`XQuery`] list, keyed by its entity.
+pub struct QueryListItem<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   pub entity: Entity,
+   shared: Arc<QueryShared<D, F, VF>>,
+}
+
+impl<D, F, VF> Clone for QueryListItem<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   fn clone(&self) -> Self {
+      Self {
+         entity: self.entity,
+         shared: self.shared.clone(),
+      }
+   }
+}
+
+impl<D, F, VF> Debug for QueryListItem<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+      f.debug_tuple("QueryListItem").field(&self.entity).finish()
+   }
+}
+
+/// The view of a single entity. Builds nothing once the entity no longer matches the query.
+pub struct QueryItemView<D, F, VF>(QueryListItem<D, F, VF>)
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static;
+
+impl<D, F, VF, IV> View<BevyRenderer> for QueryItemView<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+   VF: Fn(ROQueryItem<'_, D>) -> IV + Send + Sync + 'static,
+   IV: IntoView<BevyRenderer>,
+{
+   type Key = <<Option<IV> as IntoView<BevyRenderer>>::View as View<BevyRenderer>>::Key;
+
+   fn build(
+      self,
+      ctx: ViewCtx<BevyRenderer>,
+      reserve_key: Option<Self::Key>,
+      will_rebuild: bool,
+   ) -> Self::Key {
+      let view = self.0.shared.view(&*ctx.world, self.0.entity);
+      view.into_view().build(ctx, reserve_key, will_rebuild)
+   }
+
+   fn rebuild(self, ctx: ViewCtx<BevyRenderer>, key: Self::Key) {
+      let view = self.0.shared.view(&*ctx.world, self.0.entity);
+      view.into_view().rebuild(ctx, key)
+   }
+}
+
+impl<D, F, VF, IV> IntoView<BevyRenderer> for QueryItemView<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+   VF: Fn(ROQueryItem<'_, D>) -> IV + Send + Sync + 'static,
+   IV: IntoView<BevyRenderer>,
+{
+   type View = Self;
+
+   fn into_view(self) -> Self::View {
+      self
+   }
+}
+
+pub type QueryItemViewFn<D, F, VF> =
+   fn(Cow<QueryListItem<D, F, VF>>, usize) -> QueryItemView<D, F, VF>;
+
+fn query_item_view<D, F, VF>(
+   item: Cow<QueryListItem<D, F, VF>>,
+   _index: usize,
+) -> QueryItemView<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   QueryItemView(item.into_owned())
+}
+
+/// A list of views, one per entity matching the query `D` with the filter `F`.
+///
+/// Item views are inserted, rebuilt and removed as entities start matching, change the
+/// components read by `D`, or stop matching. `D` is a component reference or a tuple of them, see
+/// [`QueryChangeFilter`]. Items are in query order unless sorted with
+/// [`sort_by_key`](XQuery::sort_by_key).
+///
+/// ```ignore
+/// x_query::<(&Name, &Health), With<Player>, _>(|(name, health)| {
+///    span(format!("{name}: {}", health.0))
+/// })
+/// .sort_by_key(|(name, _)| name.to_string())
+/// ```
+pub struct XQuery<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   view_f: VF,
+   sort: Option<QuerySortFn<D, F>>,
+   _marker: PhantomData<fn() -> (D, F)>,
+}
+
+pub fn x_query<D, F, IV>(
+   view_f: impl Fn(ROQueryItem<'_, D>) -> IV + Send + Sync + 'static,
+) -> XQuery<D, F, impl Fn(ROQueryItem<'_, D>) -> IV + Send + Sync + 'static>
+where
+   D: QueryChangeFilter + 'static,
+   F: QueryFilter + 'static,
+   IV: IntoView<BevyRenderer>,
+{
+   XQuery {
+      view_f,
+      sort: None,
+      _marker: Default::default(),
+   }
+}
+
+impl<D, F, VF> XQuery<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   pub fn sort_by_key<K>(
+      mut self,
+      f: impl Fn(ROQueryItem<'_, D>) -> K + Send + Sync + 'static,
+   ) -> Self
+   where
+      K: Ord,
+   {
+      self.sort = Some(Box::new(move |world, state, a, b| {
+         let key = |entity| {
+            state
+               .get_manual(world, entity)
+               .ok()
+               .map(|(_, item)| f(item))
+         };
+         key(a).cmp(&key(b))
+      }));
+      self
+   }
+}
+
+pub struct QueryListState<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   items: Arc<Mutex<Vec<QueryListItem<D, F, VF>>>>,
+   shared: Arc<QueryShared<D, F, VF>>,
+}
+
+impl<D, F, VF> Clone for QueryListState<D, F, VF>
+where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   fn clone(&self) -> Self {
+      Self {
+         items: self.items.clone(),
+         shared: self.shared.clone(),
+      }
+   }
+}
+
+impl<D, F, VF, IV> VecDataSource<BevyRenderer> for XQuery<D, F, VF>
+where
+   D: QueryChangeFilter + 'static,
+   F: QueryFilter + 'static,
+   VF: Fn(ROQueryItem<'_, D>) -> IV + Send + Sync + 'static,
+   IV: IntoView<BevyRenderer>,
+{
+   type Item = QueryListItem<D, F, VF>;
+   type InitState = QueryListState<D, F, VF>;
+   type State = QueryListState<D, F, VF>;
+   type Op = QueryChange;
+
+   fn map_and_init_state<U>(
+      self,
+      world: &mut RendererWorld<BevyRenderer>,
+      mut map_f: impl FnMut(&Self::Item, &mut RendererWorld<BevyRenderer>, usize) -> U,
+   ) -> (Vec<U>, Option<(Self::InitState, Receiver<Self::Op>)>) {
+      let mut state = QueryState::<(Entity, D), F>::new(world);
+      let mut entities = state
+         .iter(world)
+         .map(|(entity, _)| entity)
+         .collect::<Vec<_>>();
+      if let Some(sort) = &self.sort {
+         entities.sort_by(|a, b| sort(world, &state, *a, *b));
+      }
+
+      let shared = Arc::new(QueryShared {
+         state: Mutex::new(state),
+         view_f: self.view_f,
+         sort: self.sort,
+      });
+      let items = entities
+         .iter()
+         .map(|&entity| QueryListItem {
+            entity,
+            shared: shared.clone(),
+         })
+         .collect::<Vec<_>>();
+
+      let receiver = subscribe_query_changes::<D, F>(world, entities);
+      let vec = items
+         .iter()
+         .enumerate()
+         .map(|(i, item)| map_f(item, world, i))
+         .collect::<Vec<_>>();
+      let state = QueryListState {
+         items: Arc::new(Mutex::new(items)),
+         shared,
+      };
+      (vec, Some((state, receiver)))
+   }
+
+   fn ready_state(state: &mut Self::InitState) -> Self::State {
+      state.clone()
+   }
+
+   fn apply_ops(
+      state: Self::State,
+      ops: Vec<Self::Op>,
+      world: &mut RendererWorld<BevyRenderer>,
+      _state_node_id: DataOrPlaceholderNodeId<BevyRenderer>,
+      mut f: impl FnMut(VecOperation<Cow<Self::Item>>, &[Self::Item], &mut RendererWorld<BevyRenderer>),
+   ) {
+      let QueryListState { items, shared } = state;
+      let mut items = items.lock().unwrap();
+      let position = |items: &[Self::Item], entity| items.iter().position(|n| n.entity == entity);
+
+      for op in ops {
+         match op {
+            QueryChange::Added(entity) => {
+               if position(&items, entity).is_none() {
+                  insert_query_item(&shared, &mut items, world, entity, &mut f);
+               }
+            }
+            QueryChange::Changed(entity) => {
+               let Some(index) = position(&items, entity) else {
+                  continue;
+               };
+               if shared.is_in_order(world, &items, index) {
+                  let item = items[index].clone();
+                  f(
+                     VecOperation::Update {
+                        index,
+                        item: Cow::Owned(item),
+                     },
+                     &items,
+                     world,
+                  );
+               } else {
+                  f(VecOperation::Remove { index }, &items, world);
+                  items.remove(index);
+                  insert_query_item(&shared, &mut items, world, entity, &mut f);
+               }
+            }
+            QueryChange::Removed(entity) => {
+               let Some(index) = position(&items, entity) else {
+                  continue;
+               };
+               f(VecOperation::Remove { index }, &items, world);
+               items.remove(index);
+            }
+         }
+      }
+   }
+}
+
+fn insert_query_item<D, F, VF>(
+   shared: &Arc<QueryShared<D, F, VF>>,
+   items: &mut Vec<QueryListItem<D, F, VF>>,
+   world: &mut World,
+   entity: Entity,
+   f: &mut impl FnMut(
+      VecOperation<Cow<QueryListItem<D, F, VF>>>,
+      &[QueryListItem<D, F, VF>],
+      &mut World,
+   ),
+) where
+   D: ReadOnlyQueryData + 'static,
+   F: QueryFilter + 'static,
+{
+   let index = shared.insert_index(world, items, entity);
+   let item = QueryListItem {
+      entity,
+      shared: shared.clone(),
+   };
+   let op = if index == items.len() {
+      VecOperation::Push {
+         item: Cow::Borrowed(&item),
+      }
+   } else {
+      VecOperation::Insert {
+         index,
+         item: Cow::Borrowed(&item),
+      }
+   };
+   f(op, items, world);
+   items.insert(index, item);
+}
+
+impl<D, F, VF, IV> IntoView<BevyRenderer> for XQuery<D, F, VF>
+where
+   D: QueryChangeFilter + 'static,
+   F: QueryFilter + 'static,
+   VF: Fn(ROQueryItem<'_, D>) -> IV + Send + Sync + 'static,
+   IV: IntoView<BevyRenderer>,
+{
+   type View = <ForSource<Self, QueryItemViewFn<D, F, VF>> as IntoView<BevyRenderer>>::View;
+
+   fn into_view(self) -> Self::View {
+      x_iter_source(
+         self,
+         query_item_view::<D, F, VF> as QueryItemViewFn<D, F, VF>,
+      )
+      .into_view()
+   }
+}
@@ -1,13 +1,17 @@
+use std::any::TypeId;
 use std::marker::PhantomData;
 
 use bevy_ecs::entity::Entity;
 use bevy_ecs::intern::Interned;
 use bevy_ecs::prelude::{In, IntoSystem, Resource, Schedules, World};
 use bevy_ecs::schedule::{ScheduleLabel, SystemConfigs};
-use bevy_utils::default;
+use bevy_ecs::system::SystemId;
+use bevy_reflect::Reflect;
+use bevy_utils::tracing::error;
+use bevy_utils::{default, HashMap};
 
 use rxy_bevy_macro::{bevy_force_dynamic_view, BevyIntoView};
-use rxy_core::{IntoView, View, ViewCtx};
+use rxy_core::{IntoView, NodeTree, View, ViewCtx, ViewKey};
 
 use crate::BevyRenderer;
 
@@ -34,13 +38,12 @@ where
    }
 }
 
-/// .
+/// A view rebuilt by `system` every time `label` runs.
 ///
-/// # Safety
-/// make sure that this view is always alive
-/// .
+/// The system lives as long as the view: once the view is removed, the system is unregistered and
+/// no longer runs in the schedule.
 #[bevy_force_dynamic_view]
-pub unsafe fn system<L, S, M, IV>(label: L, system: S) -> SystemView<L, S, M, IV>
+pub fn system<L, S, M, IV>(label: L, system: S) -> SystemView<L, S, M, IV>
 where
    M: Send + 'static,
    IV: IntoView<BevyRenderer> + Send + 'static,
@@ -50,13 +53,12 @@ where
    SystemView::<L, S, M, IV>(label, system, default())
 }
 
-/// .
+/// Same as [`system`], `config_f` configures the system in the schedule.
 ///
-/// # Safety
-/// make sure that this view is always alive
-/// .
+/// The systems of the views created at the same call site run together, configured by the
+/// `config_f` of the first view built there.
 #[bevy_force_dynamic_view]
-pub unsafe fn system_with_config<L, S, M, IV, F>(
+pub fn system_with_config<L, S, M, IV, F>(
    label: L,
    config_f: F,
    system: S,
@@ -73,10 +75,7 @@ where
 
 #[derive(Resource, Default)]
 pub struct ScheduleSystemAdds {
-   pub systems: Vec<(
-      Interned<dyn ScheduleLabel>,
-      SystemConfigs,
-   )>,
+   pub systems: Vec<(Interned<dyn ScheduleLabel>, SystemConfigs)>,
 }
 
 pub fn handle_schedule_event(world: &mut World) {
@@ -113,7 +112,7 @@ where
    L: ScheduleLabel,
    S: IntoSystem<(), IV, M> + Send + 'static,
 {
-   type Key = <IV::View as View<BevyRenderer>>::Key;
+   type Key = SystemViewKey<<IV::View as View<BevyRenderer>>::Key>;
 
    fn build(
       self,
@@ -121,13 +120,19 @@ where
       reserve_key: Option<Self::Key>,
       _will_rebuild: bool,
    ) -> Self::Key {
-      let (system, key) = build_system(self.1, world, parent, reserve_key);
-      add_system(world, self.0, system);
+      let dispatcher = SystemViewDispatcher::new(self.0, None);
+      let (system, key) = build_system(self.1, world, parent, dispatcher, reserve_key);
+      if let Some(system) = system {
+         add_system(world, dispatcher.label, system);
+      }
       key
    }
 
-   fn rebuild(self, _ctx: ViewCtx<BevyRenderer>, _key: Self::Key) {
-      todo!()
+   fn rebuild(self, ViewCtx { world, parent }: ViewCtx<BevyRenderer>, key: Self::Key) {
+      let dispatcher = SystemViewDispatcher::new(self.0, None);
+      if let Some(system) = rebuild_system(self.1, world, parent, dispatcher, key) {
+         add_system(world, dispatcher.label, system);
+      }
    }
 }
 
@@ -148,7 +153,7 @@ where
    S: IntoSystem<(), IV, M> + Send + 'static,
    F: FnOnce(SystemConfigs) -> SystemConfigs + Send + 'static,
 {
-   type Key = <IV::View as View<BevyRenderer>>::Key;
+   type Key = SystemViewKey<<IV::View as View<BevyRenderer>>::Key>;
 
    fn build(
       self,
@@ -156,23 +161,190 @@ where
       reserve_key: Option<Self::Key>,
       _will_rebuild: bool,
    ) -> Self::Key {
-      let (system, key) = build_system(self.1, world, parent, reserve_key);
-
-      add_system(world, self.0, self.2(system));
+      let dispatcher = SystemViewDispatcher::new(self.0, Some(TypeId::of::<F>()));
+      let (system, key) = build_system(self.1, world, parent, dispatcher, reserve_key);
+      if let Some(system) = system {
+         add_system(world, dispatcher.label, self.2(system));
+      }
       key
    }
 
-   fn rebuild(self, _ctx: ViewCtx<BevyRenderer>, _key: Self::Key) {
-      todo!()
+   fn rebuild(self, ViewCtx { world, parent }: ViewCtx<BevyRenderer>, key: Self::Key) {
+      let dispatcher = SystemViewDispatcher::new(self.0, Some(TypeId::of::<F>()));
+      if let Some(system) = rebuild_system(self.1, world, parent, dispatcher, key) {
+         add_system(world, dispatcher.label, self.2(system));
+      }
    }
 }
 
-fn build_system<M, IV, S>(
+/// Runs the registered systems of the system views of one schedule.
+///
+/// Bevy can not remove a system from a schedule, so each schedule gets a single dispatcher system
+/// when its first system view is built, and the views add and remove their registered systems in
+/// [`SystemViewRegistry`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct SystemViewDispatcher {
+   label: Interned<dyn ScheduleLabel>,
+   /// The type of the `config_f` of [`system_with_config`]
+   config: Option<TypeId>,
+}
+
+impl SystemViewDispatcher {
+   fn new(label: impl ScheduleLabel, config: Option<TypeId>) -> Self {
+      Self {
+         label: label.intern(),
+         config,
+      }
+   }
+
+   fn into_configs(self) -> SystemConfigs {
+      use bevy_ecs::schedule::IntoSystemConfigs;
+      (move |world: &mut World| {
+         let Some(system_ids) = world
+            .resource::<SystemViewRegistry>()
+            .dispatchers
+            .get(&self)
+            .cloned()
+         else {
+            return;
+         };
+         for system_id in system_ids {
+            // A system run before may have removed the view
+            if !world
+               .resource::<SystemViewRegistry>()
+               .contains(self, system_id)
+            {
+               continue;
+            }
+            if let Err(err) = world.run_system(system_id) {
+               error!("run system error: {:?}", err);
+            }
+         }
+      })
+      .into_configs()
+   }
+}
+
+/// The live registered systems of the system views, by the dispatcher running them.
+#[derive(Resource, Default)]
+struct SystemViewRegistry {
+   dispatchers: HashMap<SystemViewDispatcher, Vec<SystemId>>,
+}
+
+impl SystemViewRegistry {
+   fn contains(&self, dispatcher: SystemViewDispatcher, system_id: SystemId) -> bool {
+      self
+         .dispatchers
+         .get(&dispatcher)
+         .map_or(false, |system_ids| system_ids.contains(&system_id))
+   }
+
+   /// Returns the configs of the dispatcher to schedule, when it is not scheduled yet.
+   fn add(world: &mut World, slot: SystemViewSlot) -> Option<SystemConfigs> {
+      let mut registry = world.get_resource_or_insert_with(SystemViewRegistry::default);
+      let is_new_dispatcher = !registry.dispatchers.contains_key(&slot.dispatcher);
+      registry
+         .dispatchers
+         .entry(slot.dispatcher)
+         .or_default()
+         .push(slot.system_id);
+      is_new_dispatcher.then(|| slot.dispatcher.into_configs())
+   }
+
+   /// Removes the system from its dispatcher and from the world.
+   fn remove(world: &mut World, slot: SystemViewSlot) {
+      if let Some(mut registry) = world.get_resource_mut::<SystemViewRegistry>() {
+         if let Some(system_ids) = registry.dispatchers.get_mut(&slot.dispatcher) {
+            system_ids.retain(|system_id| *system_id != slot.system_id);
+         }
+      }
+      if let Err(err) = world.remove_system(slot.system_id) {
+         error!("remove_system error: {:?}", err);
+      }
+   }
+
+   /// Swaps the system of the slot in place, keeping the run order of the views.
+   fn replace(world: &mut World, slot: SystemViewSlot, system_id: SystemId) {
+      if let Some(mut registry) = world.get_resource_mut::<SystemViewRegistry>() {
+         if let Some(old_system_id) = registry
+            .dispatchers
+            .get_mut(&slot.dispatcher)
+            .and_then(|system_ids| system_ids.iter_mut().find(|n| **n == slot.system_id))
+         {
+            *old_system_id = system_id;
+         }
+      }
+      if let Err(err) = world.remove_system(slot.system_id) {
+         error!("remove_system error: {:?}", err);
+      }
+   }
+}
+
+/// The registered system of a system view, kept in the data node of its key.
+#[derive(Clone, Copy)]
+struct SystemViewSlot {
+   dispatcher: SystemViewDispatcher,
+   system_id: SystemId,
+}
+
+/// The key of the view built by the system, and the data node keeping the registered system.
+#[derive(Reflect, Clone, Debug)]
+pub struct SystemViewKey<K>
+where
+   K: ViewKey<BevyRenderer>,
+{
+   key: K,
+   system_state_node_id: Entity,
+}
+
+impl<K> ViewKey<BevyRenderer> for SystemViewKey<K>
+where
+   K: ViewKey<BevyRenderer>,
+{
+   fn remove(self, world: &mut World) {
+      if let Some(slot) = world.take_node_state::<SystemViewSlot>(&self.system_state_node_id) {
+         SystemViewRegistry::remove(world, slot);
+      }
+      world.remove_node(&self.system_state_node_id);
+      self.key.remove(world);
+   }
+
+   fn insert_before(
+      &self,
+      world: &mut World,
+      parent: Option<&Entity>,
+      before_node_id: Option<&Entity>,
+   ) {
+      self.key.insert_before(world, parent, before_node_id);
+   }
+
+   fn set_visibility(&self, world: &mut World, hidden: bool) {
+      self.key.set_visibility(world, hidden);
+   }
+
+   fn state_node_id(&self) -> Option<Entity> {
+      self.key.state_node_id()
+   }
+
+   fn reserve_key(world: &mut World, will_rebuild: bool, parent: Entity, spawn: bool) -> Self {
+      Self {
+         key: K::reserve_key(world, will_rebuild, parent, spawn),
+         system_state_node_id: world.spawn_data_node(),
+      }
+   }
+
+   fn first_node_id(&self, world: &World) -> Option<Entity> {
+      self.key.first_node_id(world)
+   }
+}
+
+/// Runs the system once to get the initial view, then registers it piped into the view rebuild.
+fn register_view_system<M, IV, S>(
    system: S,
    world: &mut World,
    parent: Entity,
-   reserve_key: Option<<<IV as IntoView<BevyRenderer>>::View as View<BevyRenderer>>::Key>,
-) -> (SystemConfigs, <IV::View as View<BevyRenderer>>::Key)
+   build_view: impl FnOnce(&mut World, IV::View) -> <IV::View as View<BevyRenderer>>::Key,
+) -> (SystemId, <IV::View as View<BevyRenderer>>::Key)
 where
    M: Send + 'static,
    IV: IntoView<BevyRenderer> + Send + 'static,
@@ -183,14 +355,91 @@ where
    system.initialize(world);
    let view: IV::View = system.run((), world).into_view();
    system.apply_deferred(world);
-   let key = view.build(ViewCtx { world, parent }, reserve_key, true);
-   use bevy_ecs::schedule::IntoSystemConfigs;
-   let system1 = system.pipe({
+   let key = build_view(world, view);
+   let system_id = world.register_system(system.pipe({
       let key = key.clone();
       move |In(r): In<IV>, world: &mut World| {
          let view = r.into_view();
          view.rebuild(ViewCtx { world, parent }, key.clone());
       }
+   }));
+   (system_id, key)
+}
+
+/// Builds the view and registers its system. Returns the configs of the dispatcher to schedule,
+/// when it is not scheduled yet.
+fn build_system<M, IV, S>(
+   system: S,
+   world: &mut World,
+   parent: Entity,
+   dispatcher: SystemViewDispatcher,
+   reserve_key: Option<SystemViewKey<<IV::View as View<BevyRenderer>>::Key>>,
+) -> (
+   Option<SystemConfigs>,
+   SystemViewKey<<IV::View as View<BevyRenderer>>::Key>,
+)
+where
+   M: Send + 'static,
+   IV: IntoView<BevyRenderer> + Send + 'static,
+   S: IntoSystem<(), IV, M> + Send + 'static,
+{
+   let (reserve_key, system_state_node_id) =
+      reserve_key.map(|n| (n.key, n.system_state_node_id)).unzip();
+   let (system_id, key) = register_view_system(system, world, parent, |world, view| {
+      view.build(ViewCtx { world, parent }, reserve_key, true)
+   });
+   let system_state_node_id = system_state_node_id.unwrap_or_else(|| world.spawn_data_node());
+   let slot = SystemViewSlot {
+      dispatcher,
+      system_id,
+   };
+   world.set_node_state(&system_state_node_id, slot);
+   (
+      SystemViewRegistry::add(world, slot),
+      SystemViewKey {
+         key,
+         system_state_node_id,
+      },
+   )
+}
+
+/// Swaps the registered system of the view. Returns the configs of the dispatcher to schedule,
+/// when it is not scheduled yet.
+fn rebuild_system<M, IV, S>(
+   system: S,
+   world: &mut World,
+   parent: Entity,
+   dispatcher: SystemViewDispatcher,
+   SystemViewKey {
+      key,
+      system_state_node_id,
+   }: SystemViewKey<<IV::View as View<BevyRenderer>>::Key>,
+) -> Option<SystemConfigs>
+where
+   M: Send + 'static,
+   IV: IntoView<BevyRenderer> + Send + 'static,
+   S: IntoSystem<(), IV, M> + Send + 'static,
+{
+   let (system_id, _) = register_view_system(system, world, parent, |world, view| {
+      view.rebuild(ViewCtx { world, parent }, key.clone());
+      key
    });
-   (system1.into_configs(), key)
+   let slot = SystemViewSlot {
+      dispatcher,
+      system_id,
+   };
+   let old_slot = world.take_node_state::<SystemViewSlot>(&system_state_node_id);
+   world.set_node_state(&system_state_node_id, slot);
+   match old_slot {
+      Some(old_slot) if old_slot.dispatcher == dispatcher => {
+         SystemViewRegistry::replace(world, old_slot, system_id);
+         None
+      }
+      old_slot => {
+         if let Some(old_slot) = old_slot {
+            SystemViewRegistry::remove(world, old_slot);
+         }
+         SystemViewRegistry::add(world, slot)
+      }
+   }
 }
@@ -0,0 +1,119 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::{IntoSystem, Observer, Trigger};
+use bevy_ecs::system::BoxedSystem;
+
+use rxy_core::common_renderer::CommonKeyEvent;
+use rxy_core::{ViewMember, ViewMemberCtx, ViewMemberOrigin};
+
+use crate::event::IntoEventHandler;
+use crate::{BevyRenderer, InputValue, ToggleValue, UiEvent, UiEventMemberState};
+
+/// A closure without parameters used as the pointer click handler of
+/// [`CommonEvents::on_click`](rxy_core::common_renderer::CommonEvents::on_click).
+pub struct CommonClickHandler<F>(pub F);
+
+pub struct CommonClickHandlerMarker;
+
+impl<F> IntoEventHandler<CommonClickHandlerMarker> for CommonClickHandler<F>
+where
+   F: FnMut() + Send + Sync + 'static,
+{
+   fn into_event_handler(self) -> BoxedSystem {
+      let mut f = self.0;
+      Box::new(IntoSystem::into_system(move || f()))
+   }
+}
+
+/// Calls the closure with the payload of the [`UiEvent`].
+pub trait CommonEventHandler<E>: Send + Sync + 'static {
+   fn handle(&mut self, event: &E);
+}
+
+impl<F> CommonEventHandler<InputValue> for F
+where
+   F: FnMut(String) + Send + Sync + 'static,
+{
+   fn handle(&mut self, event: &InputValue) {
+      self(event.0.clone())
+   }
+}
+
+impl<F> CommonEventHandler<ToggleValue> for F
+where
+   F: FnMut(bool) + Send + Sync + 'static,
+{
+   fn handle(&mut self, event: &ToggleValue) {
+      self(event.0)
+   }
+}
+
+impl<F> CommonEventHandler<CommonKeyEvent> for F
+where
+   F: FnMut(CommonKeyEvent) + Send + Sync + 'static,
+{
+   fn handle(&mut self, event: &CommonKeyEvent) {
+      self(event.clone())
+   }
+}
+
+/// Observes the [`UiEvent<E>`] of the node with a closure taking the payload instead of a system.
+pub struct CommonEventViewMember<E, F> {
+   pub handler: F,
+   pub _marker: PhantomData<E>,
+}
+
+impl<E, F> ViewMemberOrigin<BevyRenderer> for CommonEventViewMember<E, F>
+where
+   E: Clone + Send + Sync + 'static,
+   F: CommonEventHandler<E>,
+{
+   type Origin = Self;
+}
+
+impl<E, F> ViewMember<BevyRenderer> for CommonEventViewMember<E, F>
+where
+   E: Clone + Send + Sync + 'static,
+   F: CommonEventHandler<E>,
+{
+   fn count() -> rxy_core::ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(mut ctx: ViewMemberCtx<BevyRenderer>, _view_removed: bool) {
+      let Some(UiEventMemberState(observer)) =
+         ctx.take_indexed_view_member_state::<UiEventMemberState>()
+      else {
+         return;
+      };
+      if let Some(entity_world_mut) = ctx.world.get_entity_mut(observer) {
+         entity_world_mut.despawn();
+      }
+   }
+
+   fn build(self, mut ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      let mut handler = self.handler;
+      let observer = ctx
+         .world
+         .spawn(
+            Observer::new(move |trigger: Trigger<UiEvent<E>>| {
+               handler.handle(&trigger.event().event)
+            })
+            .with_entity(ctx.node_id),
+         )
+         .id();
+      ctx.set_indexed_view_member_state(UiEventMemberState(observer));
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      Self::unbuild(
+         ViewMemberCtx {
+            index: ctx.index,
+            world: &mut *ctx.world,
+            node_id: ctx.node_id,
+         },
+         false,
+      );
+      self.build(ctx, true);
+   }
+}
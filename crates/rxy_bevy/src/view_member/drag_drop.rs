@@ -0,0 +1,321 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use bevy_ecs::prelude::{Entity, IntoSystem, World};
+use bevy_ecs::system::SystemId;
+use bevy_math::Vec2;
+use bevy_mod_picking::prelude::PointerButton;
+use bevy_utils::tracing::error;
+#[cfg(feature = "xy_reactive")]
+use xy_reactive::prelude::WriteSignal;
+
+use rxy_core::{IntoView, ViewMember, ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin};
+
+use crate::drag_drop::{
+   drag_preview_fn, end_drag, move_drag, set_drop_hover, start_drag, take_payload, DragPreviewFn,
+};
+use crate::event::{
+   BubblePointerEvent, ElementEventId, FocusEventWorldExt, ListenerInputPointerDrag,
+   ListenerInputPointerDragStart, PointerEventDispatch,
+};
+use crate::{BevyRenderer, DragDropState, DropHoverState, DropTargetHover, EventSystem};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DragDropMemberState(pub smallvec::SmallVec<[(SystemId, BubblePointerEvent); 3]>);
+
+fn add_drag_drop_systems(
+   ctx: &mut ViewMemberCtx<BevyRenderer>,
+   systems: impl IntoIterator<Item = (BubblePointerEvent, SystemId, bool)>,
+) {
+   let mut state = DragDropMemberState(Default::default());
+   for (event, system_id, stop_propagation) in systems {
+      ctx.world.entity_mut(system_id.entity()).insert(EventSystem);
+      ctx.world
+         .add_bubble_event(ctx.node_id, event, stop_propagation, system_id);
+      state.0.push((system_id, event));
+   }
+   ctx.set_indexed_view_member_state(state);
+}
+
+fn remove_drag_drop_systems(ctx: &mut ViewMemberCtx<BevyRenderer>) {
+   let Some(state) = ctx.take_indexed_view_member_state::<DragDropMemberState>() else {
+      return;
+   };
+   for (system_id, event) in state.0 {
+      ctx.world.remove_event(
+         ctx.node_id,
+         ElementEventId::Bubble {
+            event,
+            stop_propagation: false,
+         },
+         system_id,
+      );
+      if let Err(err) = ctx.world.remove_system(system_id) {
+         error!("remove_system error: {:?}", err);
+      }
+   }
+}
+
+/// Makes the element draggable, carrying `payload` to [`DropTarget`]s that accept `T`.
+pub struct Draggable<T> {
+   payload: T,
+   preview: Option<DragPreviewFn>,
+}
+
+pub fn draggable<T>(payload: T) -> Draggable<T>
+where
+   T: Clone + Send + Sync + 'static,
+{
+   Draggable {
+      payload,
+      preview: None,
+   }
+}
+
+impl<T> Draggable<T>
+where
+   T: Clone + Send + Sync + 'static,
+{
+   /// A view that follows the cursor while dragging.
+   pub fn preview<IV>(mut self, f: impl Fn(&T) -> IV + Send + Sync + 'static) -> Self
+   where
+      IV: IntoView<BevyRenderer>,
+   {
+      self.preview = Some(drag_preview_fn(f));
+      self
+   }
+}
+
+impl<T> ViewMemberOrigin<BevyRenderer> for Draggable<T>
+where
+   T: Clone + Send + Sync + 'static,
+{
+   type Origin = Self;
+}
+
+impl<T> ViewMember<BevyRenderer> for Draggable<T>
+where
+   T: Clone + Send + Sync + 'static,
+{
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(mut ctx: ViewMemberCtx<BevyRenderer>, _view_removed: bool) {
+      remove_drag_drop_systems(&mut ctx);
+      let node_id = ctx.node_id;
+      let is_dragging = ctx
+         .world
+         .resource::<DragDropState>()
+         .active()
+         .map_or(false, |n| n.source == node_id);
+      if is_dragging {
+         end_drag(ctx.world);
+      }
+   }
+
+   fn build(self, mut ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      let node_id = ctx.node_id;
+      let Draggable { payload, preview } = self;
+      let button = Some(PointerButton::Primary);
+
+      let drag_start = ctx.world.register_system(move |world: &mut World| {
+         if world
+            .resource::<PointerEventDispatch>()
+            .is_default_prevented()
+         {
+            return;
+         }
+         let position = world
+            .resource::<ListenerInputPointerDragStart>()
+            .pointer_location
+            .position;
+         start_drag(world, node_id, payload.clone(), preview.clone(), position);
+      });
+      let drag = ctx.world.register_system(|world: &mut World| {
+         let position = world
+            .resource::<ListenerInputPointerDrag>()
+            .pointer_location
+            .position;
+         move_drag(world, position);
+      });
+      let drag_end = ctx.world.register_system(end_drag);
+
+      add_drag_drop_systems(
+         &mut ctx,
+         [
+            (BubblePointerEvent::DragStart(button), drag_start, true),
+            (BubblePointerEvent::Drag(button), drag, true),
+            (BubblePointerEvent::DragEnd(button), drag_end, true),
+         ],
+      );
+   }
+
+   fn rebuild(self, mut ctx: ViewMemberCtx<BevyRenderer>) {
+      remove_drag_drop_systems(&mut ctx);
+      self.build(ctx, true);
+   }
+}
+
+/// The data passed to the `on_drop` system of a [`DropTarget`].
+#[derive(Debug, Clone)]
+pub struct DropEvent<T> {
+   pub payload: T,
+   pub source: Entity,
+   pub target: Entity,
+   pub position: Vec2,
+}
+
+/// Accepts payloads of type `T` dropped on the element.
+pub struct DropTarget<T, S, M> {
+   on_drop: S,
+   accept: Option<Arc<dyn Fn(&T) -> bool + Send + Sync>>,
+   #[cfg(feature = "xy_reactive")]
+   hover_signal: Option<WriteSignal<DropHoverState>>,
+   _marker: PhantomData<(T, M)>,
+}
+
+pub fn drop_target<T, S, M>(on_drop: S) -> DropTarget<T, S, M>
+where
+   T: Clone + Send + Sync + 'static,
+   S: IntoSystem<DropEvent<T>, (), M> + Send + 'static,
+   M: Send + 'static,
+{
+   DropTarget {
+      on_drop,
+      accept: None,
+      #[cfg(feature = "xy_reactive")]
+      hover_signal: None,
+      _marker: Default::default(),
+   }
+}
+
+impl<T, S, M> DropTarget<T, S, M>
+where
+   T: Clone + Send + Sync + 'static,
+{
+   /// Rejects payloads for which `f` returns false. All payloads of type `T` are accepted by default.
+   pub fn accept(mut self, f: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self {
+      self.accept = Some(Arc::new(f));
+      self
+   }
+
+   /// Writes the accept/reject state into `signal` while a payload hovers the element.
+   #[cfg(feature = "xy_reactive")]
+   pub fn hover_signal(mut self, signal: WriteSignal<DropHoverState>) -> Self {
+      self.hover_signal = Some(signal);
+      self
+   }
+}
+
+impl<T, S, M> ViewMemberOrigin<BevyRenderer> for DropTarget<T, S, M>
+where
+   T: Clone + Send + Sync + 'static,
+   S: IntoSystem<DropEvent<T>, (), M> + Send + 'static,
+   M: Send + 'static,
+{
+   type Origin = Self;
+}
+
+pub struct DropSystemState<T: 'static>(pub SystemId<DropEvent<T>>);
+
+impl<T, S, M> ViewMember<BevyRenderer> for DropTarget<T, S, M>
+where
+   T: Clone + Send + Sync + 'static,
+   S: IntoSystem<DropEvent<T>, (), M> + Send + 'static,
+   M: Send + 'static,
+{
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(mut ctx: ViewMemberCtx<BevyRenderer>, view_removed: bool) {
+      remove_drag_drop_systems(&mut ctx);
+      if let Some(DropSystemState(system_id)) =
+         ctx.take_indexed_view_member_state::<DropSystemState<T>>()
+      {
+         if let Err(err) = ctx.world.remove_system(system_id) {
+            error!("remove_system error: {:?}", err);
+         }
+      }
+      if !view_removed {
+         ctx.world
+            .entity_mut(ctx.node_id)
+            .remove::<DropTargetHover>();
+      }
+   }
+
+   fn build(self, mut ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      let node_id = ctx.node_id;
+      let on_drop = ctx.world.register_system(self.on_drop);
+      ctx.world.entity_mut(on_drop.entity()).insert(EventSystem);
+      ctx.set_indexed_view_member_state(DropSystemState::<T>(on_drop));
+
+      ctx.world.entity_mut(node_id).insert(DropTargetHover {
+         state: DropHoverState::None,
+         #[cfg(feature = "xy_reactive")]
+         signal: self.hover_signal,
+      });
+
+      let accept = self.accept;
+      let accepts = move |payload: &T| accept.as_ref().map_or(true, |f| f(payload));
+
+      let drag_enter = ctx.world.register_system({
+         let accepts = accepts.clone();
+         move |world: &mut World| {
+            let drag_drop_state = world.resource::<DragDropState>();
+            let state = match (
+               drag_drop_state.is_dragging(),
+               drag_drop_state.payload::<T>(),
+            ) {
+               (false, _) => DropHoverState::None,
+               (true, Some(payload)) if accepts(payload) => DropHoverState::Accept,
+               (true, _) => DropHoverState::Reject,
+            };
+            set_drop_hover(world, node_id, state);
+         }
+      });
+      let drag_leave = ctx.world.register_system(move |world: &mut World| {
+         set_drop_hover(world, node_id, DropHoverState::None);
+      });
+      let drop = ctx.world.register_system(move |world: &mut World| {
+         set_drop_hover(world, node_id, DropHoverState::None);
+         let Some((payload, source, position)) = take_payload::<T>(world) else {
+            return;
+         };
+         if !accepts(&payload) {
+            return;
+         }
+         let event = DropEvent {
+            payload,
+            source,
+            target: node_id,
+            position,
+         };
+         if let Err(err) = world.run_system_with_input(on_drop, event) {
+            error!("run system error: {:?}", err);
+         }
+      });
+
+      add_drag_drop_systems(
+         &mut ctx,
+         [
+            (BubblePointerEvent::DragEnter(None), drag_enter, false),
+            (BubblePointerEvent::DragLeave(None), drag_leave, false),
+            (BubblePointerEvent::Drop(None), drop, true),
+         ],
+      );
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      Self::unbuild(
+         ViewMemberCtx {
+            index: ctx.index,
+            world: &mut *ctx.world,
+            node_id: ctx.node_id,
+         },
+         false,
+      );
+      self.build(ctx, true);
+   }
+}
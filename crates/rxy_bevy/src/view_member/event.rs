@@ -10,7 +10,7 @@ use bevy_utils::tracing::error;
 use rxy_core::{MemberOwner, ViewMember, ViewMemberCtx, ViewMemberOrigin};
 
 use crate::event::*;
-use crate::BevyRenderer;
+use crate::{BevyRenderer, KeyChord, ShortcutOptions, ShortcutViewMember};
 
 #[derive(Component, Reflect, Debug, Copy, Clone)]
 pub struct EventSystem;
@@ -27,7 +27,7 @@ pub struct EventViewMember<T, S, M> {
 impl<T, S, M> ViewMemberOrigin<BevyRenderer> for EventViewMember<T, S, M>
 where
    T: ElementEventIds,
-   S: IntoSystem<(), (), M> + Send + 'static,
+   S: IntoEventHandler<M>,
    M: Send + 'static,
 {
    type Origin = Self;
@@ -36,7 +36,7 @@ where
 impl<T, S, M> ViewMember<BevyRenderer> for EventViewMember<T, S, M>
 where
    T: ElementEventIds,
-   S: IntoSystem<(), (), M> + Send + 'static,
+   S: IntoEventHandler<M>,
    M: Send + 'static,
 {
    fn count() -> rxy_core::ViewMemberIndex {
@@ -56,7 +56,9 @@ where
    }
 
    fn build(self, mut ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
-      let system_id = ctx.world.register_system(self.system);
+      let system_id = ctx
+         .world
+         .register_boxed_system(self.system.into_event_handler());
 
       ctx.world.entity_mut(system_id.entity()).insert(EventSystem);
 
@@ -93,7 +95,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
    ) -> Self::AddMember<EventViewMember<T, S, Marker>>
    where
       T: ElementEventIds,
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.member(EventViewMember {
@@ -110,7 +112,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
       T: Copy + Eq + Hash + Send + Sync + 'static,
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pressed(events), system)
@@ -121,7 +123,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on_just_pressed(KeyCode::Enter, system)
@@ -132,7 +134,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on_just_pressed(KeyCode::Escape, system)
@@ -144,7 +146,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_just_pressed(events), system)
@@ -156,18 +158,48 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_just_released(events), system)
    }
 
+   fn on_shortcut<S, Marker>(
+      self,
+      chord: impl Into<KeyChord>,
+      system: S,
+   ) -> Self::AddMember<ShortcutViewMember<S, Marker>>
+   where
+      S: IntoSystem<(), (), Marker> + Send + 'static,
+      Marker: Send + 'static,
+   {
+      self.on_shortcut_with(chord, ShortcutOptions::default(), system)
+   }
+
+   fn on_shortcut_with<S, Marker>(
+      self,
+      chord: impl Into<KeyChord>,
+      options: ShortcutOptions,
+      system: S,
+   ) -> Self::AddMember<ShortcutViewMember<S, Marker>>
+   where
+      S: IntoSystem<(), (), Marker> + Send + 'static,
+      Marker: Send + 'static,
+   {
+      self.member(ShortcutViewMember {
+         chord: chord.into(),
+         options,
+         system,
+         _marker: Default::default(),
+      })
+   }
+
    fn on_pointer_over<S, Marker>(
       self,
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_over(), system)
@@ -178,7 +210,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_out(), system)
@@ -189,7 +221,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_down(), system)
@@ -200,7 +232,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_up(), system)
@@ -211,7 +243,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_click(), system)
@@ -222,7 +254,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_move(), system)
@@ -232,7 +264,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_drag_start(), system)
@@ -242,7 +274,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_drag(), system)
@@ -252,7 +284,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_drag_end(), system)
@@ -262,7 +294,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_drag_enter(), system)
@@ -272,7 +304,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_drag_over(), system)
@@ -282,7 +314,7 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_drag_leave(), system)
@@ -292,9 +324,108 @@ pub trait ElementKeyboardEvents: MemberOwner<BevyRenderer> + Sized {
       system: S,
    ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
    where
-      S: IntoSystem<(), (), Marker> + Send + 'static,
+      S: IntoEventHandler<Marker>,
       Marker: Send + 'static,
    {
       self.on(x_pointer_drop(), system)
    }
+
+   fn on_pointer_wheel<S, Marker>(
+      self,
+      system: S,
+   ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+   where
+      S: IntoEventHandler<Marker>,
+      Marker: Send + 'static,
+   {
+      self.on(x_pointer_wheel(), system)
+   }
+
+   fn on_pointer_double_click<S, Marker>(
+      self,
+      system: S,
+   ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+   where
+      S: IntoEventHandler<Marker>,
+      Marker: Send + 'static,
+   {
+      self.on(x_pointer_double_click(), system)
+   }
+
+   fn on_pointer_long_press<S, Marker>(
+      self,
+      system: S,
+   ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+   where
+      S: IntoEventHandler<Marker>,
+      Marker: Send + 'static,
+   {
+      self.on(x_pointer_long_press(), system)
+   }
+
+   fn on_context_menu<S, Marker>(
+      self,
+      system: S,
+   ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+   where
+      S: IntoEventHandler<Marker>,
+      Marker: Send + 'static,
+   {
+      self.on(x_context_menu(), system)
+   }
+
+   fn on_tap<S, Marker>(
+      self,
+      system: S,
+   ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+   where
+      S: IntoEventHandler<Marker>,
+      Marker: Send + 'static,
+   {
+      self.on(x_tap(), system)
+   }
+
+   fn on_pan<S, Marker>(
+      self,
+      system: S,
+   ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+   where
+      S: IntoEventHandler<Marker>,
+      Marker: Send + 'static,
+   {
+      self.on(x_pan(), system)
+   }
+
+   fn on_pinch<S, Marker>(
+      self,
+      system: S,
+   ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+   where
+      S: IntoEventHandler<Marker>,
+      Marker: Send + 'static,
+   {
+      self.on(x_pinch(), system)
+   }
+
+   fn on_rotate<S, Marker>(
+      self,
+      system: S,
+   ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+   where
+      S: IntoEventHandler<Marker>,
+      Marker: Send + 'static,
+   {
+      self.on(x_rotate(), system)
+   }
+
+   fn on_swipe<S, Marker>(
+      self,
+      system: S,
+   ) -> Self::AddMember<EventViewMember<impl ElementEventIds, S, Marker>>
+   where
+      S: IntoEventHandler<Marker>,
+      Marker: Send + 'static,
+   {
+      self.on(x_swipe(), system)
+   }
 }
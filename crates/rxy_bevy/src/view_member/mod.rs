@@ -1,5 +1,13 @@
 pub use bundle::*;
+pub use common_event::*;
+pub use drag_drop::*;
 pub use event::*;
+pub use shortcut::*;
+pub use ui_event::*;
 
 mod bundle;
+mod common_event;
+mod drag_drop;
 mod event;
+mod shortcut;
+mod ui_event;
@@ -0,0 +1,73 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::IntoSystem;
+use bevy_ecs::system::SystemId;
+use bevy_utils::tracing::error;
+
+use rxy_core::{ViewMember, ViewMemberCtx, ViewMemberOrigin};
+
+use crate::{add_shortcut, BevyRenderer, EventSystem, KeyChord, ShortcutOptions, Shortcuts};
+
+pub struct ShortcutViewMember<S, M> {
+   pub chord: KeyChord,
+   pub options: ShortcutOptions,
+   pub system: S,
+   pub _marker: PhantomData<M>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShortcutMemberState(pub SystemId);
+
+impl<S, M> ViewMemberOrigin<BevyRenderer> for ShortcutViewMember<S, M>
+where
+   S: IntoSystem<(), (), M> + Send + 'static,
+   M: Send + 'static,
+{
+   type Origin = Self;
+}
+
+impl<S, M> ViewMember<BevyRenderer> for ShortcutViewMember<S, M>
+where
+   S: IntoSystem<(), (), M> + Send + 'static,
+   M: Send + 'static,
+{
+   fn count() -> rxy_core::ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(mut ctx: ViewMemberCtx<BevyRenderer>, _view_removed: bool) {
+      let Some(ShortcutMemberState(system_id)) =
+         ctx.take_indexed_view_member_state::<ShortcutMemberState>()
+      else {
+         return;
+      };
+      if let Some(mut shortcuts) = ctx.world.get_resource_mut::<Shortcuts>() {
+         shortcuts.unregister(system_id);
+      }
+      if let Err(err) = ctx.world.remove_system(system_id) {
+         error!("remove_system error: {:?}", err);
+      }
+   }
+
+   fn build(self, mut ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      let system_id = ctx.world.register_system(self.system);
+
+      ctx.world.entity_mut(system_id.entity()).insert(EventSystem);
+
+      add_shortcut(ctx.world, ctx.node_id, self.chord, self.options, system_id);
+
+      ctx.set_indexed_view_member_state(ShortcutMemberState(system_id));
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      Self::unbuild(
+         ViewMemberCtx {
+            index: ctx.index,
+            world: &mut *ctx.world,
+            node_id: ctx.node_id,
+         },
+         false,
+      );
+      self.build(ctx, true);
+   }
+}
@@ -0,0 +1,69 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::prelude::{Entity, Observer};
+use bevy_ecs::system::IntoObserverSystem;
+
+use rxy_core::{ViewMember, ViewMemberCtx, ViewMemberOrigin};
+
+use crate::{BevyRenderer, UiEvent};
+
+pub struct UiEventViewMember<E, S, M> {
+   pub system: S,
+   pub _marker: PhantomData<(E, M)>,
+}
+
+/// The observer entity watching the node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UiEventMemberState(pub Entity);
+
+impl<E, S, M> ViewMemberOrigin<BevyRenderer> for UiEventViewMember<E, S, M>
+where
+   E: Clone + Send + Sync + 'static,
+   S: IntoObserverSystem<UiEvent<E>, (), M>,
+   M: Send + 'static,
+{
+   type Origin = Self;
+}
+
+impl<E, S, M> ViewMember<BevyRenderer> for UiEventViewMember<E, S, M>
+where
+   E: Clone + Send + Sync + 'static,
+   S: IntoObserverSystem<UiEvent<E>, (), M>,
+   M: Send + 'static,
+{
+   fn count() -> rxy_core::ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(mut ctx: ViewMemberCtx<BevyRenderer>, _view_removed: bool) {
+      let Some(UiEventMemberState(observer)) =
+         ctx.take_indexed_view_member_state::<UiEventMemberState>()
+      else {
+         return;
+      };
+      // Observers of a despawned node are despawned with it
+      if let Some(entity_world_mut) = ctx.world.get_entity_mut(observer) {
+         entity_world_mut.despawn();
+      }
+   }
+
+   fn build(self, mut ctx: ViewMemberCtx<BevyRenderer>, _will_rebuild: bool) {
+      let observer = ctx
+         .world
+         .spawn(Observer::new(self.system).with_entity(ctx.node_id))
+         .id();
+      ctx.set_indexed_view_member_state(UiEventMemberState(observer));
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<BevyRenderer>) {
+      Self::unbuild(
+         ViewMemberCtx {
+            index: ctx.index,
+            world: &mut *ctx.world,
+            node_id: ctx.node_id,
+         },
+         false,
+      );
+      self.build(ctx, true);
+   }
+}
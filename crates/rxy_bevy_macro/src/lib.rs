@@ -30,8 +30,8 @@ pub fn bevy_struct_element_schema(input: TokenStream) -> TokenStream {
 }
 
 #[proc_macro_attribute]
-pub fn schema(_input: TokenStream, item: TokenStream) -> TokenStream {
-   fn_schema(_input, item)
+pub fn schema(input: TokenStream, item: TokenStream) -> TokenStream {
+   fn_schema(input, item)
 }
 
 #[proc_macro_attribute]
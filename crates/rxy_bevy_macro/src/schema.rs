@@ -58,16 +58,49 @@ pub fn schema_common<'a, U>(
       .type_params()
       .collect::<Punctuated<&TypeParam, syn::token::Comma>>();
 
-   let schema_generic_params_must_with_bound = generics
-      .type_params()
-      .filter(|n| !n.bounds.is_empty())
-      .collect::<Punctuated<&TypeParam, syn::token::Comma>>();
-
    let schema_generic_params_with_bound = if schema_generic_params_with_bound.is_empty() {
       None
    } else {
       Some(quote! { #schema_generic_params_with_bound , })
    };
+   // `#[schema(R)]` on a fn generic over `R` gives a schema generic over the renderer,
+   // the props trait then takes that param as its renderer
+   let renderer_param = generics
+      .type_params()
+      .find(|param| param.ident == renderer.to_string());
+   let is_renderer_param =
+      |param: &&TypeParam| renderer_param.is_some_and(|renderer| renderer.ident == param.ident);
+   let r = renderer_param.map_or_else(
+      || Ident::new("R", Span::call_site()),
+      |param| param.ident.clone(),
+   );
+   let renderer_bounds = renderer_param
+      .filter(|param| !param.bounds.is_empty())
+      .map(|param| {
+         let ident = &param.ident;
+         let bounds = &param.bounds;
+         quote! { #ident: #bounds, }
+      });
+
+   let trait_generic_params = generics
+      .type_params()
+      .filter(|param| !is_renderer_param(param))
+      .map(|param| &param.ident)
+      .collect::<Punctuated<&Ident, syn::token::Comma>>();
+   let trait_generic_params_with_bound = generics
+      .type_params()
+      .filter(|param| !is_renderer_param(param))
+      .collect::<Punctuated<&TypeParam, syn::token::Comma>>();
+   let trait_generic_params_must_with_bound = generics
+      .type_params()
+      .filter(|param| !param.bounds.is_empty() && !is_renderer_param(param))
+      .collect::<Vec<&TypeParam>>();
+   let trait_generic_params_with_bound = if trait_generic_params_with_bound.is_empty() {
+      None
+   } else {
+      Some(quote! { #trait_generic_params_with_bound , })
+   };
+
    let ty_generics_turbofish = ty_generics.as_turbofish();
    let schema_ident = Ident::new(&schema_pascal_name, Span::call_site());
    let schema_id = {
@@ -172,7 +205,7 @@ pub fn schema_common<'a, U>(
                     }
                 };
                 let static_token = quote! {
-                fn #name<ISP: rxy_ui::IntoSchemaProp<R, #inner_ty>>(
+                fn #name<ISP: rxy_ui::IntoSchemaProp<#r, #inner_ty>>(
                     self,
                     value: ISP,
                 ) -> Self
@@ -182,7 +215,7 @@ pub fn schema_common<'a, U>(
                     quote! {
                     fn #name(
                         self,
-                        value: impl rxy_ui::IntoView<R>,
+                        value: impl rxy_ui::IntoView<#r>,
                     ) -> Self
                 }
                 };
@@ -191,28 +224,28 @@ pub fn schema_common<'a, U>(
                     quote! {
                     fn #name(
                         self,
-                        value: impl rxy_ui::IntoCloneableView<R>,
+                        value: impl rxy_ui::IntoCloneableView<#r>,
                     ) -> Self
                 }
                 };
                 let prop_fn_sig = if is_element {
                     match param_type {
                         ParamType::Prop => quote! {
-                        fn #name<ISP: rxy_ui::IntoSchemaProp<R, #inner_ty>>(
+                        fn #name<ISP: rxy_ui::IntoSchemaProp<#r, #inner_ty>>(
                             self,
                             value: ISP,
-                        ) -> rxy_ui::RendererSchemaElementView<R, U, VM, P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>,#schema_id>
+                        ) -> rxy_ui::RendererSchemaElementView<#r, U, VM, P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>,#schema_id>
                         where
-                            P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>: rxy_ui::SchemaProps<R>
+                            P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>: rxy_ui::SchemaProps<#r>
                     },
                         ParamType::Static => static_token,
                         ParamType::Event => quote! {
-                        fn #name<ISP: rxy_ui::IntoSchemaProp<R, rxy_ui::EventHandler<#inner_ty>>>(
+                        fn #name<ISP: rxy_ui::IntoSchemaProp<#r, rxy_ui::EventHandler<#inner_ty>>>(
                             self,
                             value: ISP,
-                        ) -> rxy_ui::RendererSchemaElementView<R, U, VM, P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>,#schema_id>
+                        ) -> rxy_ui::RendererSchemaElementView<#r, U, VM, P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>,#schema_id>
                         where
-                            P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>: rxy_ui::SchemaProps<R>
+                            P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>: rxy_ui::SchemaProps<#r>
                     },
                         ParamType::Slot => slot_token,
                         ParamType::CloneableSlot => cloneable_slot_token,
@@ -220,21 +253,21 @@ pub fn schema_common<'a, U>(
                 } else {
                     match param_type {
                         ParamType::Prop => quote! {
-                        fn #name<ISP: rxy_ui::IntoSchemaProp<R, #inner_ty>>(
+                        fn #name<ISP: rxy_ui::IntoSchemaProp<#r, #inner_ty>>(
                             self,
                             value: ISP,
-                        ) -> rxy_ui::RendererSchemaView<R, U, P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>,#schema_id>
+                        ) -> rxy_ui::RendererSchemaView<#r, U, P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>,#schema_id>
                         where
-                            P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>: rxy_ui::SchemaProps<R>
+                            P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>: rxy_ui::SchemaProps<#r>
                     },
                         ParamType::Static => static_token,
                         ParamType::Event => quote! {
-                        fn #name<ISP: rxy_ui::IntoSchemaProp<R, rxy_ui::EventHandler<#inner_ty>>>(
+                        fn #name<ISP: rxy_ui::IntoSchemaProp<#r, rxy_ui::EventHandler<#inner_ty>>>(
                             self,
                             value: ISP,
-                        ) -> rxy_ui::RendererSchemaView<R, U, P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>,#schema_id>
+                        ) -> rxy_ui::RendererSchemaView<#r, U, P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>,#schema_id>
                         where
-                            P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>: rxy_ui::SchemaProps<R>
+                            P::Props<rxy_ui::ConstIndex<#index,ISP::Prop>>: rxy_ui::SchemaProps<#r>
                     },
                         ParamType::Slot => slot_token,
                         ParamType::CloneableSlot => cloneable_slot_token,
@@ -319,26 +352,28 @@ pub fn schema_common<'a, U>(
 
    let trait_def_and_impl = if is_element {
       quote! {
-          pub trait #trait_name<R, U, VM, P,#schema_generic_params_with_bound>
+          pub trait #trait_name<#r, U, VM, P,#trait_generic_params_with_bound>
           where
-              R: rxy_ui::Renderer,
-              VM: rxy_ui::ViewMember<R>,
-              U: rxy_ui::Schema<R>,
-              U::View: rxy_ui::ElementView<R>,
-              P: rxy_ui::SchemaProps<R>,
+              #r: rxy_ui::Renderer,
+              VM: rxy_ui::ViewMember<#r>,
+              U: rxy_ui::Schema<#r>,
+              U::View: rxy_ui::ElementView<#r>,
+              P: rxy_ui::SchemaProps<#r>,
+              #renderer_bounds
               #where_clause_predicates
           {
               #(#prop_fn_sig;)*
           }
 
-          impl<R, U, VM, P,#schema_generic_params> #trait_name<R, U, VM, P,#schema_generic_params> for rxy_ui::RendererSchemaElementView<R, U, VM, P,#schema_id>
+          impl<#r, U, VM, P,#trait_generic_params> #trait_name<#r, U, VM, P,#trait_generic_params> for rxy_ui::RendererSchemaElementView<#r, U, VM, P,#schema_id>
           where
-              R: rxy_ui::Renderer,
-              VM: rxy_ui::ViewMember<R>,
-              U: rxy_ui::Schema<R>,
-              U::View: rxy_ui::ElementView<R>,
-              P: rxy_ui::SchemaProps<R>,
-              #schema_generic_params_must_with_bound
+              #r: rxy_ui::Renderer,
+              VM: rxy_ui::ViewMember<#r>,
+              U: rxy_ui::Schema<#r>,
+              U::View: rxy_ui::ElementView<#r>,
+              P: rxy_ui::SchemaProps<#r>,
+              #(#trait_generic_params_must_with_bound,)*
+              #renderer_bounds
               #where_clause_predicates
           {
               #(#prop_fn_impl)*
@@ -346,22 +381,24 @@ pub fn schema_common<'a, U>(
       }
    } else {
       quote! {
-          pub trait #trait_name<R, U, P,#schema_generic_params_with_bound>
+          pub trait #trait_name<#r, U, P,#trait_generic_params_with_bound>
           where
-              R: rxy_ui::Renderer,
-              U: rxy_ui::Schema<R>,
-              P: rxy_ui::SchemaProps<R>,
+              #r: rxy_ui::Renderer,
+              U: rxy_ui::Schema<#r>,
+              P: rxy_ui::SchemaProps<#r>,
+              #renderer_bounds
               #where_clause_predicates
           {
               #(#prop_fn_sig;)*
           }
 
-          impl<R, U, P,#schema_generic_params> #trait_name<R, U, P,#schema_generic_params> for rxy_ui::RendererSchemaView<R, U, P,#schema_id>
+          impl<#r, U, P,#trait_generic_params> #trait_name<#r, U, P,#trait_generic_params> for rxy_ui::RendererSchemaView<#r, U, P,#schema_id>
           where
-              R: rxy_ui::Renderer,
-              U: rxy_ui::Schema<R>,
-              P: rxy_ui::SchemaProps<R>,
-              #schema_generic_params_with_bound
+              #r: rxy_ui::Renderer,
+              U: rxy_ui::Schema<#r>,
+              P: rxy_ui::SchemaProps<#r>,
+              #(#trait_generic_params_must_with_bound,)*
+              #renderer_bounds
               #where_clause_predicates
           {
               #(#prop_fn_impl)*
@@ -498,10 +535,17 @@ pub fn struct_schema(
    .into()
 }
 
-pub fn fn_schema(_input: TokenStream, item: TokenStream) -> TokenStream {
+pub fn fn_schema(input: TokenStream, item: TokenStream) -> TokenStream {
    let input_item: ItemFn = parse_macro_input!(item as ItemFn);
 
-   let renderer = quote!(BevyRenderer);
+   // `#[schema]` is for `BevyRenderer`, other renderers are given like `#[schema(WebRenderer)]`,
+   // and a type param of the fn like `#[schema(R)]` makes the schema work on every renderer `R`
+   let renderer = if input.is_empty() {
+      quote!(BevyRenderer)
+   } else {
+      let renderer = parse_macro_input!(input as Type);
+      renderer.into_token_stream()
+   };
 
    let is_element = {
       let return_type_error = "schema attribute only support fn with return type 'impl IntoElementView' or 'impl IntoView'";
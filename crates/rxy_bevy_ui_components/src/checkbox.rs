@@ -15,7 +15,7 @@ pub fn schema_checkbox(
    readonly: ReadSignal<bool>,
    onchange: Sender<bool>,
 ) -> impl IntoElementView<BevyRenderer> {
-   let is_checked = ctx.use_controlled_state(value, onchange);
+   let is_checked = ctx.use_form_field(value, onchange).value;
    ctx.default_typed_style(CheckboxStyle, || {
       let size = 20;
       (
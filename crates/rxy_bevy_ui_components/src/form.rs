@@ -0,0 +1,21 @@
+use rxy_ui::prelude::*;
+
+#[derive(TypedStyle)]
+pub struct FormStyle;
+
+/// Provides a [`Form`](rxy_ui::Form) context to the fields in `content`.
+///
+/// Fields join the form through `use_form_field`; descendants can submit or reset it through
+/// `ctx.context::<Form>()`. `onsubmit` is notified after a submit whose validation passed.
+#[schema]
+pub fn schema_form(
+   mut ctx: SchemaCtx,
+   content: Slot,
+   onsubmit: Sender<()>,
+) -> impl IntoElementView<BevyRenderer> {
+   let form = ctx.use_form();
+   form.set_on_submit(onsubmit);
+   ctx.default_typed_style(FormStyle, || x().flex_col().gap(8));
+
+   provide_context(form, div().name("form").style(FormStyle).children(content))
+}
@@ -7,6 +7,7 @@ use rxy_ui::{x_world, MaybeReflect, MaybeSend, RendererWorld, XWorld};
 use std::sync::Arc;
 
 mod checkbox;
+mod form;
 // mod select;
 // mod slider;
 
@@ -0,0 +1,413 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use smallvec::SmallVec;
+
+use crate::smallbox::{SmallBox, S1};
+use crate::{
+   impl_x_value_wrappers, smallbox, AttrValue, MaybeFromReflect, MaybeGetTypeRegistration,
+   MaybeTypePath, XValueWrapper,
+};
+
+/// The unit of a [`Calc`] operand.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum CalcUnit {
+   Px,
+   /// Percent of the parent length along the axis of the property
+   Percent,
+   Vw,
+   Vh,
+   VMin,
+   VMax,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum CalcToken {
+   Value(f32, CalcUnit),
+   Add,
+   Sub,
+   Mul(f32),
+   Div(f32),
+   Min,
+   Max,
+   Clamp,
+}
+
+/// A `calc()` like length expression, kept in reverse polish notation.
+///
+/// ```ignore
+/// div().width(Calc::percent(100.) - Calc::px(20.))
+/// div().height(Calc::clamp(Calc::px(100.), Calc::vh(50.), Calc::percent(80.)))
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct Calc(pub Vec<CalcToken>);
+
+/// The lengths a [`Calc`] is resolved against.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct CalcContext {
+   /// The parent length along the axis of the property
+   pub parent: f32,
+   pub viewport_width: f32,
+   pub viewport_height: f32,
+}
+
+impl Calc {
+   #[inline]
+   pub fn value(value: f32, unit: CalcUnit) -> Self {
+      let mut tokens = Vec::with_capacity(1);
+      tokens.push(CalcToken::Value(value, unit));
+      Self(tokens)
+   }
+
+   #[inline]
+   pub fn px(value: f32) -> Self {
+      Self::value(value, CalcUnit::Px)
+   }
+
+   #[inline]
+   pub fn percent(value: f32) -> Self {
+      Self::value(value, CalcUnit::Percent)
+   }
+
+   #[inline]
+   pub fn vw(value: f32) -> Self {
+      Self::value(value, CalcUnit::Vw)
+   }
+
+   #[inline]
+   pub fn vh(value: f32) -> Self {
+      Self::value(value, CalcUnit::Vh)
+   }
+
+   #[inline]
+   pub fn vmin(value: f32) -> Self {
+      Self::value(value, CalcUnit::VMin)
+   }
+
+   #[inline]
+   pub fn vmax(value: f32) -> Self {
+      Self::value(value, CalcUnit::VMax)
+   }
+
+   pub fn min(self, other: impl Into<Calc>) -> Self {
+      self.binary(other.into(), CalcToken::Min)
+   }
+
+   pub fn max(self, other: impl Into<Calc>) -> Self {
+      self.binary(other.into(), CalcToken::Max)
+   }
+
+   /// Same as css `clamp(min, value, max)`
+   pub fn clamp(min: impl Into<Calc>, value: impl Into<Calc>, max: impl Into<Calc>) -> Self {
+      let mut tokens = min.into().0;
+      tokens.extend(value.into().0);
+      tokens.extend(max.into().0);
+      tokens.push(CalcToken::Clamp);
+      Self(tokens)
+   }
+
+   fn binary(mut self, other: Calc, op: CalcToken) -> Self {
+      self.0.extend(other.0);
+      self.0.push(op);
+      self
+   }
+
+   fn unary(mut self, op: CalcToken) -> Self {
+      self.0.push(op);
+      self
+   }
+
+   pub fn resolve(&self, context: CalcContext) -> f32 {
+      let mut stack = SmallVec::<[f32; 4]>::new();
+      for token in self.0.iter() {
+         let value = match *token {
+            CalcToken::Value(value, unit) => match unit {
+               CalcUnit::Px => value,
+               CalcUnit::Percent => context.parent * value / 100.,
+               CalcUnit::Vw => context.viewport_width * value / 100.,
+               CalcUnit::Vh => context.viewport_height * value / 100.,
+               CalcUnit::VMin => context.viewport_width.min(context.viewport_height) * value / 100.,
+               CalcUnit::VMax => context.viewport_width.max(context.viewport_height) * value / 100.,
+            },
+            CalcToken::Mul(rhs) => stack.pop().unwrap_or_default() * rhs,
+            CalcToken::Div(rhs) => stack.pop().unwrap_or_default() / rhs,
+            CalcToken::Clamp => {
+               let max = stack.pop().unwrap_or_default();
+               let value = stack.pop().unwrap_or_default();
+               let min = stack.pop().unwrap_or_default();
+               value.min(max).max(min)
+            }
+            op => {
+               let rhs = stack.pop().unwrap_or_default();
+               let lhs = stack.pop().unwrap_or_default();
+               match op {
+                  CalcToken::Add => lhs + rhs,
+                  CalcToken::Sub => lhs - rhs,
+                  CalcToken::Min => lhs.min(rhs),
+                  CalcToken::Max => lhs.max(rhs),
+                  _ => unreachable!(),
+               }
+            }
+         };
+         stack.push(value);
+      }
+      stack.pop().unwrap_or_default()
+   }
+}
+
+impl From<f32> for Calc {
+   fn from(value: f32) -> Self {
+      Calc::px(value)
+   }
+}
+
+impl Add for Calc {
+   type Output = Calc;
+
+   fn add(self, rhs: Self) -> Self::Output {
+      self.binary(rhs, CalcToken::Add)
+   }
+}
+
+impl Sub for Calc {
+   type Output = Calc;
+
+   fn sub(self, rhs: Self) -> Self::Output {
+      self.binary(rhs, CalcToken::Sub)
+   }
+}
+
+impl Mul<f32> for Calc {
+   type Output = Calc;
+
+   fn mul(self, rhs: f32) -> Self::Output {
+      self.unary(CalcToken::Mul(rhs))
+   }
+}
+
+impl Div<f32> for Calc {
+   type Output = Calc;
+
+   fn div(self, rhs: f32) -> Self::Output {
+      self.unary(CalcToken::Div(rhs))
+   }
+}
+
+impl Neg for Calc {
+   type Output = Calc;
+
+   fn neg(self) -> Self::Output {
+      self.unary(CalcToken::Mul(-1.))
+   }
+}
+
+/// A plain renderer value, or a [`Calc`] the renderer resolves to it every frame.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum CalcVal<V> {
+   Val(V),
+   Calc(Calc),
+}
+
+impl<V> From<V> for CalcVal<V> {
+   fn from(value: V) -> Self {
+      CalcVal::Val(value)
+   }
+}
+
+impl<V> Default for CalcVal<V>
+where
+   V: Default,
+{
+   fn default() -> Self {
+      CalcVal::Val(V::default())
+   }
+}
+
+impl_x_value_wrappers!(Calc);
+
+impl<V> Into<XValueWrapper<CalcVal<V>>> for Calc {
+   fn into(self) -> XValueWrapper<CalcVal<V>> {
+      XValueWrapper(CalcVal::Calc(self))
+   }
+}
+
+impl<V> Into<XValueWrapper<CalcVal<V>>> for CalcVal<V> {
+   fn into(self) -> XValueWrapper<CalcVal<V>> {
+      XValueWrapper(self)
+   }
+}
+
+impl<V> AttrValue for CalcVal<V>
+where
+   V: AttrValue + Clone + PartialEq + MaybeTypePath + MaybeFromReflect + MaybeGetTypeRegistration,
+{
+   fn clone_att_value(&self) -> SmallBox<dyn AttrValue, S1> {
+      smallbox!(self.clone())
+   }
+
+   fn default_value() -> Self
+   where
+      Self: Sized,
+   {
+      CalcVal::Val(V::default_value())
+   }
+
+   #[cfg(not(feature = "bevy_reflect"))]
+   fn as_any(&self) -> &dyn core::any::Any {
+      self
+   }
+
+   fn eq(&self, other: &Self) -> bool {
+      self == other
+   }
+}
+
+/// The style properties that accept a [`CalcVal`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum CalcProperty {
+   Left,
+   Right,
+   Top,
+   Bottom,
+   Width,
+   Height,
+   MinWidth,
+   MinHeight,
+   MaxWidth,
+   MaxHeight,
+   MarginLeft,
+   MarginRight,
+   MarginTop,
+   MarginBottom,
+   PaddingLeft,
+   PaddingRight,
+   PaddingTop,
+   PaddingBottom,
+}
+
+impl CalcProperty {
+   /// Whether percentages resolve against the parent height rather than its width.
+   ///
+   /// Like css, margin and padding always use the parent width.
+   pub fn is_vertical(&self) -> bool {
+      matches!(
+         self,
+         CalcProperty::Top
+            | CalcProperty::Bottom
+            | CalcProperty::Height
+            | CalcProperty::MinHeight
+            | CalcProperty::MaxHeight
+      )
+   }
+}
+
+/// The [`Calc`] values of a node, by the style property they are written to.
+///
+/// Renderers keep it in a component and write [`CalcValues::resolve`] into their style before each
+/// layout.
+#[derive(Clone, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct CalcValues(pub Vec<(CalcProperty, Calc)>);
+
+impl CalcValues {
+   pub fn get(&self, property: CalcProperty) -> Option<&Calc> {
+      self.0.iter().find(|n| n.0 == property).map(|n| &n.1)
+   }
+
+   pub fn insert(&mut self, property: CalcProperty, calc: Calc) {
+      match self.0.iter_mut().find(|n| n.0 == property) {
+         None => self.0.push((property, calc)),
+         Some(n) => n.1 = calc,
+      }
+   }
+
+   pub fn remove(&mut self, property: CalcProperty) -> Option<Calc> {
+      let index = self.0.iter().position(|n| n.0 == property)?;
+      Some(self.0.swap_remove(index).1)
+   }
+
+   /// Resolves every value. Sizes are `(width, height)`, in the unit of the result.
+   pub fn resolve(
+      &self,
+      parent_size: (f32, f32),
+      viewport_size: (f32, f32),
+   ) -> impl Iterator<Item = (CalcProperty, f32)> + '_ {
+      self.0.iter().map(move |(property, calc)| {
+         let value = calc.resolve(CalcContext {
+            parent: if property.is_vertical() {
+               parent_size.1
+            } else {
+               parent_size.0
+            },
+            viewport_width: viewport_size.0,
+            viewport_height: viewport_size.1,
+         });
+         (*property, value)
+      })
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   const CONTEXT: CalcContext = CalcContext {
+      parent: 200.,
+      viewport_width: 1000.,
+      viewport_height: 500.,
+   };
+
+   #[test]
+   fn resolve_arithmetic() {
+      assert_eq!((Calc::percent(100.) - Calc::px(20.)).resolve(CONTEXT), 180.);
+      assert_eq!((Calc::vw(10.) + Calc::vh(10.)).resolve(CONTEXT), 150.);
+      assert_eq!((Calc::percent(50.) * 2. / 4.).resolve(CONTEXT), 50.);
+      assert_eq!((-Calc::vmin(10.)).resolve(CONTEXT), -50.);
+   }
+
+   #[test]
+   fn resolve_min_max_clamp() {
+      assert_eq!(
+         Calc::vmax(10.).min(Calc::percent(25.)).resolve(CONTEXT),
+         50.
+      );
+      assert_eq!(
+         Calc::vmax(10.).max(Calc::percent(25.)).resolve(CONTEXT),
+         100.
+      );
+      assert_eq!(
+         Calc::clamp(100., Calc::vw(50.), Calc::percent(80.)).resolve(CONTEXT),
+         160.
+      );
+      assert_eq!(
+         Calc::clamp(100., Calc::px(20.), Calc::percent(80.)).resolve(CONTEXT),
+         100.
+      );
+   }
+
+   #[test]
+   fn resolve_values_along_axis() {
+      let mut values = CalcValues::default();
+      values.insert(CalcProperty::Width, Calc::percent(50.));
+      values.insert(CalcProperty::Height, Calc::percent(50.));
+      values.insert(CalcProperty::PaddingTop, Calc::percent(10.) + Calc::vh(10.));
+      values.insert(CalcProperty::Width, Calc::percent(25.));
+      assert_eq!(
+         values
+            .resolve((200., 100.), (1000., 500.))
+            .collect::<Vec<_>>(),
+         [
+            (CalcProperty::Width, 50.),
+            (CalcProperty::Height, 50.),
+            (CalcProperty::PaddingTop, 70.),
+         ]
+      );
+      assert_eq!(values.remove(CalcProperty::Width), Some(Calc::percent(25.)));
+      assert_eq!(values.get(CalcProperty::Width), None);
+   }
+}
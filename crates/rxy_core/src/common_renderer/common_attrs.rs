@@ -0,0 +1,118 @@
+use crate::{ElementAttrType, Renderer};
+
+/// Marks an attr of the common attr set of a renderer, see [`common_attrs!`](crate::common_attrs).
+///
+/// Implemented by the renderer for each attr the `common_attrs!` callback receives.
+pub trait CommonAttr<R: Renderer>: ElementAttrType<R> {}
+
+/// The one definition of the common attr set. Every [`CommonRenderer`](crate::common_renderer::CommonRenderer)
+/// defines these attrs in its `all_attrs` module, with the same names, and implements all of them. The value
+/// types are renderer specific, like the colors.
+///
+/// The invocation is passed to the given macro of the renderer crate with `common = [..];` appended, which
+/// defines the indices, the `CommonAttrs` functions and implements [`CommonAttr`] for each of the common attrs.
+/// The attrs only some renderers implement, like `z_index` or the grid attrs, are not part of the set, the
+/// renderer passes them on its own, after the common ones:
+///
+/// ```ignore
+/// rxy_core::common_attrs!(common_attrs_fn_define! {
+///    extra = [justify_items justify_self];
+/// });
+/// ```
+#[macro_export]
+macro_rules! common_attrs {
+   ($($callback:ident)::+! { $($args:tt)* }) => {
+      $($callback)::+! {
+         $($args)*
+         common = [
+            name
+            bg_color
+            border_left
+            border_right
+            border_top
+            border_bottom
+            border_color
+            display
+            position_type
+            overflow_x
+            overflow_y
+            direction
+            left
+            right
+            top
+            bottom
+            width
+            height
+            min_width
+            min_height
+            max_width
+            max_height
+            margin_left
+            margin_right
+            margin_top
+            margin_bottom
+            padding_left
+            padding_right
+            padding_top
+            padding_bottom
+            aspect_ratio
+            align_items
+            align_self
+            align_content
+            justify_content
+            flex_direction
+            flex_wrap
+            flex_grow
+            flex_shrink
+            flex_basis
+            column_gap
+            row_gap
+            visibility
+            translation
+            rotation
+            scale
+            text_color
+            font_size
+            text_linebreak
+            text_align
+            outline_width
+            outline_offset
+            outline_color
+         ];
+      }
+   };
+}
+
+/// Fails to compile when one of the attrs of `crate::all_attrs` is not a [`CommonAttr`] of the renderer.
+///
+/// Used by the tailwind and composite attrs, which are written once for all the renderers:
+///
+/// ```ignore
+/// rxy_core::assert_common_attrs!(NativeRenderer; width height);
+/// ```
+#[macro_export]
+macro_rules! assert_common_attrs {
+   ($renderer:ty; common = [$($attr:ident)*];) => {
+      $crate::assert_common_attrs!($renderer; $($attr)*);
+   };
+   ($renderer:ty; $($attr:ident)*) => {
+      const _: () = {
+         const fn assert_common_attr<EA: $crate::common_renderer::CommonAttr<$renderer>>() {}
+         $(assert_common_attr::<crate::all_attrs::$attr>();)*
+      };
+   };
+}
+
+/// Fails to compile when the renderer misses one of the attrs of [`common_attrs!`](crate::common_attrs).
+///
+/// Each [`CommonRenderer`](crate::common_renderer::CommonRenderer) runs it next to its attr definitions:
+///
+/// ```ignore
+/// rxy_core::assert_all_common_attrs!(NativeRenderer);
+/// ```
+#[macro_export]
+macro_rules! assert_all_common_attrs {
+   ($renderer:ty) => {
+      $crate::common_attrs!($crate::assert_common_attrs! { $renderer; });
+   };
+}
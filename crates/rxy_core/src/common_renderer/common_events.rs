@@ -0,0 +1,90 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use crate::{MaybeSend, MaybeSync, MemberOwner, Renderer, ViewMember};
+
+/// A key pressed while the node is focused.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CommonKeyEvent {
+   /// The key named like `KeyboardEvent.key` on the web, e.g. `"a"`, `" "`, `"Enter"` or `"ArrowLeft"`
+   pub key: Cow<'static, str>,
+}
+
+/// The event members a renderer provides for [`CommonEvents`].
+pub trait CommonEventRenderer: Renderer {
+   type ClickMember<F>: ViewMember<Self>
+   where
+      F: FnMut() + MaybeSend + MaybeSync + 'static;
+   type InputMember<F>: ViewMember<Self>
+   where
+      F: FnMut(String) + MaybeSend + MaybeSync + 'static;
+   type ToggleMember<F>: ViewMember<Self>
+   where
+      F: FnMut(bool) + MaybeSend + MaybeSync + 'static;
+   type KeyDownMember<F>: ViewMember<Self>
+   where
+      F: FnMut(CommonKeyEvent) + MaybeSend + MaybeSync + 'static;
+
+   fn crate_click_member<F>(f: F) -> Self::ClickMember<F>
+   where
+      F: FnMut() + MaybeSend + MaybeSync + 'static;
+   fn crate_input_member<F>(f: F) -> Self::InputMember<F>
+   where
+      F: FnMut(String) + MaybeSend + MaybeSync + 'static;
+   fn crate_toggle_member<F>(f: F) -> Self::ToggleMember<F>
+   where
+      F: FnMut(bool) + MaybeSend + MaybeSync + 'static;
+   fn crate_key_down_member<F>(f: F) -> Self::KeyDownMember<F>
+   where
+      F: FnMut(CommonKeyEvent) + MaybeSend + MaybeSync + 'static;
+}
+
+/// Events available on the views of every [`CommonEventRenderer`].
+///
+/// The renderers have richer event APIs of their own, whose methods may share these names,
+/// so this trait is not part of their preludes.
+pub trait CommonEvents<R>: MemberOwner<R> + Sized
+where
+   R: CommonEventRenderer,
+{
+   #[inline]
+   fn on_click<F>(self, f: F) -> Self::AddMember<R::ClickMember<F>>
+   where
+      F: FnMut() + MaybeSend + MaybeSync + 'static,
+   {
+      self.member(R::crate_click_member(f))
+   }
+
+   /// Called with the new value of a text input
+   #[inline]
+   fn on_input<F>(self, f: F) -> Self::AddMember<R::InputMember<F>>
+   where
+      F: FnMut(String) + MaybeSend + MaybeSync + 'static,
+   {
+      self.member(R::crate_input_member(f))
+   }
+
+   /// Called with the new checked state of a toggle
+   #[inline]
+   fn on_toggle<F>(self, f: F) -> Self::AddMember<R::ToggleMember<F>>
+   where
+      F: FnMut(bool) + MaybeSend + MaybeSync + 'static,
+   {
+      self.member(R::crate_toggle_member(f))
+   }
+
+   #[inline]
+   fn on_key_down<F>(self, f: F) -> Self::AddMember<R::KeyDownMember<F>>
+   where
+      F: FnMut(CommonKeyEvent) + MaybeSend + MaybeSync + 'static,
+   {
+      self.member(R::crate_key_down_member(f))
+   }
+}
+
+impl<R, T> CommonEvents<R> for T
+where
+   R: CommonEventRenderer,
+   T: MemberOwner<R> + Sized,
+{
+}
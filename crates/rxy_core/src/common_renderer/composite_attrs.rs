@@ -199,5 +199,11 @@ macro_rules! impl_composite_attrs {
       }
 
       impl<T> $name for T where T: $ty<$renderer> {}
+
+      $crate::assert_common_attrs! {
+         $renderer;
+         border_bottom border_left border_right border_top margin_bottom margin_left margin_right
+         margin_top padding_bottom padding_left padding_right padding_top
+      }
    };
 }
@@ -1,6 +1,11 @@
+mod common_attrs;
+mod common_events;
 mod composite_attrs;
 mod tailwind_attrs;
 
+pub use common_attrs::*;
+pub use common_events::*;
+
 use crate::{ElementAttrMember, ElementAttrType, ElementView, MapToAttrMarker, Renderer, XNest};
 use alloc::borrow::Cow;
 
@@ -9,6 +14,10 @@ pub trait CommonRenderer: Renderer {
     type TextView<T: ElementAttrMember<Self, Self::TextContentEA>>: ElementView<Self>;
     type ButtonView: ElementView<Self>;
     type ImgView: ElementView<Self>;
+    type ScrollView: ElementView<Self>;
+    type TextInputView: ElementView<Self>;
+    type ToggleView: ElementView<Self>;
+    type CanvasView: ElementView<Self>;
     type TextContentEA: ElementAttrType<Self, Value = Cow<'static, str>>;
 
     fn crate_text<T>(
@@ -19,6 +28,15 @@ pub trait CommonRenderer: Renderer {
     fn crate_div() -> Self::DivView;
     fn crate_button() -> Self::ButtonView;
     fn crate_img() -> Self::ImgView;
+    /// A container whose content can be scrolled when it overflows
+    fn crate_scroll() -> Self::ScrollView;
+    /// A single line text field, see [`CommonEvents::on_input`]
+    fn crate_text_input() -> Self::TextInputView;
+    /// A checkbox like node switching between checked and unchecked when clicked,
+    /// see [`CommonEvents::on_toggle`]
+    fn crate_toggle() -> Self::ToggleView;
+    /// A node drawn by the user with the drawing API of the renderer
+    fn crate_canvas() -> Self::CanvasView;
 }
 
 #[macro_export]
@@ -50,6 +68,26 @@ macro_rules! define_common_view_fns {
             <$renderer as CommonRenderer>::crate_button()
         }
 
+        #[inline]
+        pub fn scroll_view() -> <$renderer as CommonRenderer>::ScrollView {
+            <$renderer as CommonRenderer>::crate_scroll()
+        }
+
+        #[inline]
+        pub fn text_input() -> <$renderer as CommonRenderer>::TextInputView {
+            <$renderer as CommonRenderer>::crate_text_input()
+        }
+
+        #[inline]
+        pub fn toggle() -> <$renderer as CommonRenderer>::ToggleView {
+            <$renderer as CommonRenderer>::crate_toggle()
+        }
+
+        #[inline]
+        pub fn canvas() -> <$renderer as CommonRenderer>::CanvasView {
+            <$renderer as CommonRenderer>::crate_canvas()
+        }
+
         impl rxy_core::IntoView<$renderer> for std::borrow::Cow<'static, str> {
             type View = <$renderer as CommonRenderer>::TextView<
                 rxy_core::ElementAttr<$renderer, <$renderer as CommonRenderer>::TextContentEA>,
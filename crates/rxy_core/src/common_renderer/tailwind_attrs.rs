@@ -21,6 +21,7 @@ macro_rules! impl_tailwind_attrs {
          impl_tailwind_attrs!(@TAFFY;$renderer;$name;$ty);
       }
       impl<T> $name for T where T: $ty<$renderer> {}
+      impl_tailwind_attrs!(@ASSERT_TAFFY;$renderer);
    };
    ($renderer:ident;$name:ident;$ty:ident;include_text_and_z_index) => {
       pub trait $name: $ty<$renderer> + Sized {
@@ -36,13 +37,26 @@ macro_rules! impl_tailwind_attrs {
          }
       }
       impl<T> $name for T where T: $ty<$renderer> {}
+      impl_tailwind_attrs!(@ASSERT_TAFFY;$renderer);
+      $crate::assert_common_attrs!($renderer; text_linebreak text_align);
    };
    ($renderer:ident;$name:ident;$ty:ident;include_text) => {
       pub trait $name: $ty<$renderer> + Sized {
-         impl_tailwind_attrs!(@TAFFY;$renderer;$name;$ty)
-         impl_tailwind_attrs!(@TEXT;$renderer;$name;$ty)
+         impl_tailwind_attrs!(@TAFFY;$renderer;$name;$ty);
+         impl_tailwind_attrs!(@TEXT;$renderer;$name;$ty);
       }
       impl<T> $name for T where T: $ty<$renderer> {}
+      impl_tailwind_attrs!(@ASSERT_TAFFY;$renderer);
+      $crate::assert_common_attrs!($renderer; text_linebreak text_align);
+   };
+   (@ASSERT_TAFFY;$renderer:ident) => {
+      $crate::assert_common_attrs! {
+         $renderer;
+         align_items column_gap display flex_direction flex_grow flex_shrink flex_wrap height
+         justify_content margin_bottom margin_left margin_right margin_top max_height max_width
+         min_height min_width overflow_x overflow_y padding_bottom padding_left padding_right
+         padding_top position_type row_gap visibility width
+      }
    };
    (@TEXT;$renderer:ident;$name:ident;$ty:ident) => {
          #[inline]
@@ -8,7 +8,7 @@ pub use either::*;
 pub use erasure::*;
 pub use future::*;
 pub use option::*;
-#[cfg(all(feature = "xy_reactive", feature = "send_sync"))]
+#[cfg(feature = "xy_reactive")]
 pub use reactive::*;
 pub use rebuild_fn_receiver::*;
 pub use stream::*;
@@ -45,7 +45,7 @@ mod result;
 mod x_iter_source;
 // mod stream_with_default_value;
 
-#[cfg(all(feature = "xy_reactive", feature = "send_sync"))]
+#[cfg(feature = "xy_reactive")]
 mod reactive;
 mod recyclable;
 mod x_world;
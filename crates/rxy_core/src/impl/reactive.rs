@@ -7,8 +7,8 @@ use xy_reactive::prelude::{create_render_effect, Memo, ReadSignal, RwSignal, Sig
 use xy_reactive::render_effect::RenderEffect;
 
 use crate::{
-   DeferredNodeTreeScoped, ElementView, IntoView, MaybeSend, MaybeSync, MemberOwner, NodeTree,
-   Renderer, RendererNodeId, RendererWorld, View, ViewCtx, ViewKey, ViewMember, ViewMemberCtx,
+   DeferredNodeTreeScoped, ElementView, IntoView, MaybeSend, MemberOwner, NodeTree, Renderer,
+   RendererNodeId, RendererWorld, View, ViewCtx, ViewKey, ViewMember, ViewMemberCtx,
    ViewMemberIndex, ViewMemberOrigin,
 };
 
@@ -148,12 +148,16 @@ where
    }
 }
 
+// Not `MaybeSend`: the signals keep their values in the reactive arena of xy_reactive, which
+// stores them behind `Arc<RwLock<..>>` and always requires `Send + Sync`, also without the
+// `send_sync` feature. So on the web the signal values still have to be `Send + Sync`, `!Send`
+// values are not supported, while the closures of `rx` and the views follow `send_sync`.
 macro_rules! impl_view_member_for_signal_get {
    ($ident:ident) => {
       impl<R, VM> ViewMemberOrigin<R> for $ident<VM>
       where
          R: Renderer,
-         VM: ViewMemberOrigin<R> + MaybeSync + Clone,
+         VM: ViewMemberOrigin<R> + Send + Sync + Clone,
       {
          type Origin = VM::Origin;
       }
@@ -161,7 +165,7 @@ macro_rules! impl_view_member_for_signal_get {
       impl<R, VM> ViewMember<R> for $ident<VM>
       where
          R: Renderer,
-         VM: ViewMember<R> + MaybeSync + Clone,
+         VM: ViewMember<R> + Send + Sync + Clone,
       {
          fn count() -> ViewMemberIndex {
             VM::count()
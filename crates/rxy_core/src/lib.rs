@@ -10,6 +10,7 @@ pub use count_macro;
 pub use paste::paste;
 
 pub use build_info::ViewMemberBuildExt;
+pub use calc::*;
 pub use either::*;
 pub use element::*;
 pub use element_view::*;
@@ -27,6 +28,7 @@ pub use smallbox::*;
 pub use view::*;
 pub use view_member::*;
 
+mod calc;
 mod either;
 mod r#impl;
 mod into_view;
@@ -65,6 +67,7 @@ pub mod prelude {
    pub use crate::{rx, ElementViewRxExt, MemberOwnerRxExt};
    #[cfg(feature = "x_iter_source")]
    pub use crate::{use_list, x_iter_source};
+   pub use crate::{Calc, CalcVal};
    pub use crate::{ElementAttrType, ElementAttrUntyped, ElementType, ElementTypeUnTyped};
    pub use crate::{SchemaElementView, SchemaView};
 
@@ -327,11 +327,12 @@ pub mod impl_reactive {
    };
    use xy_reactive::prelude::{Memo, ReadSignal, RwSignal, SignalGet};
 
+   // `Send + Sync` instead of `MaybeSend`, the reactive arena requires it for the signal values
    macro_rules! impl_x_nest_for_signal {
       ($ty:ty) => {
          impl<X> XNest for $ty
          where
-            X: XNest + MaybeSend + MaybeSync + Clone + 'static,
+            X: XNest + Send + Sync + Clone + 'static,
          {
             type Inner = X::Inner;
             type MapInner<M> = InnerIvmToVm<Self, M>;
@@ -347,7 +348,7 @@ pub mod impl_reactive {
 
          impl<X, U> XNestMapper<U> for $ty
          where
-            X: XNestMapper<U> + MaybeSend + MaybeSync + Clone + 'static,
+            X: XNestMapper<U> + Send + Sync + Clone + 'static,
             U: 'static,
          {
             type MapInnerTo =
@@ -1,4 +1,4 @@
-use crate::{impl_attr_value, impl_attr_value_and_wrapper, impl_x_value_wrappers, smallbox, AttrValue, SmallBox, XValueWrapper, S1, impl_schema_prop_value_wrapper_into};
+use crate::{CalcVal, impl_attr_value, impl_attr_value_and_wrapper, impl_x_value_wrappers, smallbox, AttrValue, SmallBox, XValueWrapper, S1, impl_schema_prop_value_wrapper_into};
 use bevy_asset::Handle;
 use bevy_color::*;
 
@@ -70,6 +70,24 @@ impl Into<XValueWrapper<bevy_ui::Val>> for f32 {
    }
 }
 
+impl Into<XValueWrapper<CalcVal<bevy_ui::Val>>> for bevy_ui::Val {
+   fn into(self) -> XValueWrapper<CalcVal<bevy_ui::Val>> {
+      XValueWrapper(CalcVal::Val(self))
+   }
+}
+
+impl Into<XValueWrapper<CalcVal<bevy_ui::Val>>> for i32 {
+   fn into(self) -> XValueWrapper<CalcVal<bevy_ui::Val>> {
+      XValueWrapper(CalcVal::Val(bevy_ui::Val::Px(self as _)))
+   }
+}
+
+impl Into<XValueWrapper<CalcVal<bevy_ui::Val>>> for f32 {
+   fn into(self) -> XValueWrapper<CalcVal<bevy_ui::Val>> {
+      XValueWrapper(CalcVal::Val(bevy_ui::Val::Px(self)))
+   }
+}
+
 impl Into<XValueWrapper<i32>> for f32 {
    fn into(self) -> XValueWrapper<i32> {
       XValueWrapper(self as _)
@@ -57,7 +57,9 @@ impl_attr_value_and_wrapper! {
     Color => Color::rgba8(0, 0, 0, 0),
     // bevy_transform::prelude::Transform,
     glam::Affine2,
-    glam::Vec2
+    glam::Vec2,
+    glam::Vec3,
+    glam::Quat
 }
 
 
@@ -70,6 +72,18 @@ impl Into<XValueWrapper<glam::Vec2>> for f32 {
 
 
 
+impl Into<XValueWrapper<glam::Vec3>> for f32 {
+   fn into(self) -> XValueWrapper<glam::Vec3> {
+      XValueWrapper(glam::Vec3::new(self, self, self))
+   }
+}
+
+impl Into<XValueWrapper<glam::Quat>> for f32 {
+   fn into(self) -> XValueWrapper<glam::Quat> {
+      XValueWrapper(glam::Quat::from_rotation_z(self))
+   }
+}
+
 impl Into<XValueWrapper<i32>> for f32 {
    fn into(self) -> XValueWrapper<i32> {
       XValueWrapper(self as _)
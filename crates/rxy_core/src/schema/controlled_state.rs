@@ -6,7 +6,7 @@ use xy_reactive::prelude::{
    create_effect, use_rw_signal, ReadSignal, RwSignal, SignalGet, SignalGetUntracked, SignalSet,
 };
 
-use crate::{InnerSchemaCtx, MaybeSend, MaybeSync, RenderSchemaCtx, Renderer};
+use crate::{InnerSchemaCtx, RenderSchemaCtx, Renderer};
 
 impl<R> RenderSchemaCtx<R>
 where
@@ -18,7 +18,7 @@ where
       onchange: Sender<T>,
    ) -> RwSignal<T>
    where
-      T: Debug + MaybeSend + MaybeSync + PartialEq + Clone + 'static,
+      T: Debug + Send + Sync + PartialEq + Clone + 'static,
    {
       self.mut_scoped(|ctx| ctx.use_controlled_state(value, onchange))
    }
@@ -34,13 +34,14 @@ where
       onchange: Sender<T>,
    ) -> RwSignal<T>
    where
-      T: Debug + MaybeSend + MaybeSync + PartialEq + Clone + 'static,
+      T: Debug + Send + Sync + PartialEq + Clone + 'static,
    {
       let signal = use_rw_signal(value.get_untracked());
       let (read_signal, write_signal) = signal.split();
       let event_effect = create_effect(move |_| {
          let value = read_signal.get();
-         onchange.send_blocking(value).unwrap();
+         // The event channels are unbounded, unlike `send_blocking` this also works on wasm
+         onchange.try_send(value).unwrap();
       });
       let control_effect = create_effect(move |_| {
          let value = value.get();
@@ -1,11 +1,11 @@
 use alloc::boxed::Box;
-use core::any::TypeId;
+use core::any::{Any, TypeId};
 use core::cell::UnsafeCell;
 use core::marker::PhantomData;
 
 use crate::utils::HashMap;
 use crate::{
-   BoxedCloneableErasureView, BoxedErasureView, MaybeSendAnyBox, PropState, Renderer,
+   BoxedCloneableErasureView, BoxedErasureView, MaybeSend, MaybeSendAnyBox, PropState, Renderer,
    RendererNodeId, RendererWorld, SchemaParam,
 };
 
@@ -23,6 +23,7 @@ where
    pub(crate) slots: &'a mut HashMap<TypeId, BoxedErasureView<R>>,
    pub(crate) cloneable_slots: &'a mut HashMap<TypeId, BoxedCloneableErasureView<R>>,
    pub(crate) prop_state: &'a mut PropHashMap<R>,
+   pub(crate) drop_guards: &'a mut alloc::vec::Vec<MaybeSendAnyBox>,
    #[cfg(feature = "xy_reactive")]
    pub(crate) effect_state: &'a mut alloc::vec::Vec<xy_reactive::effect::ErasureEffect>,
    pub(crate) init_values: HashMap<TypeId, BoxedPropValue>,
@@ -37,6 +38,11 @@ where
       self.prop_state
    }
 
+   /// Keeps the guard until the schema view is removed, so its `Drop` can undo what the schema set up.
+   pub fn add_drop_guard(&mut self, guard: impl Any + MaybeSend) {
+      self.drop_guards.push(Box::new(guard));
+   }
+
    #[cfg(feature = "xy_reactive")]
    pub fn effect_state(&mut self) -> &mut alloc::vec::Vec<xy_reactive::effect::ErasureEffect> {
       self.effect_state
@@ -49,6 +55,7 @@ where
          slots: self.slots,
          init_values: self.init_values,
          prop_state: self.prop_state,
+         drop_guards: self.drop_guards,
          _marker: Default::default(),
          cloneable_slots: self.cloneable_slots,
          #[cfg(feature = "xy_reactive")]
@@ -0,0 +1,458 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::future::Future;
+use std::sync::Mutex;
+
+use async_channel::Sender;
+use futures_lite::future;
+
+use xy_reactive::prelude::{
+   create_effect, use_rw_signal, ReadSignal, RwSignal, SignalGet, SignalGetUntracked, SignalSet,
+   SignalWith,
+};
+#[cfg(feature = "send_sync")]
+use xy_reactive::spawn::spawn;
+// The validations are not `Send` futures without `send_sync`, e.g. on the web
+#[cfg(not(feature = "send_sync"))]
+use xy_reactive::spawn::spawn_local as spawn;
+
+use crate::{
+   BoxedFutureMaybeLocal, InnerSchemaCtx, MaybeSend, MaybeSync, RenderSchemaCtx, Renderer, ViewCtx,
+};
+
+pub type FieldError = String;
+
+type SyncValidator<T> = Box<dyn Fn(&T) -> Result<(), FieldError> + MaybeSend + MaybeSync>;
+type AsyncValidator<T> =
+   Box<dyn Fn(T) -> BoxedFutureMaybeLocal<Result<(), FieldError>> + MaybeSend + MaybeSync>;
+
+struct FieldValidators<T> {
+   sync: Vec<SyncValidator<T>>,
+   r#async: Vec<Arc<AsyncValidator<T>>>,
+   // Results of an outdated async validation are discarded
+   version: usize,
+}
+
+/// A form field bound through [`RenderSchemaCtx::use_form_field`].
+///
+/// `value` is the controlled state of the field. Reading `is_dirty`/`is_touched`/`error` inside a
+/// reactive scope tracks them.
+pub struct FormField<T: Send + Sync + 'static> {
+   pub value: RwSignal<T>,
+   initial: RwSignal<T>,
+   error: RwSignal<Option<FieldError>>,
+   touched: RwSignal<bool>,
+   validating: RwSignal<bool>,
+   validators: Arc<Mutex<FieldValidators<T>>>,
+}
+
+impl<T: Send + Sync + 'static> Clone for FormField<T> {
+   fn clone(&self) -> Self {
+      Self {
+         value: self.value,
+         initial: self.initial,
+         error: self.error,
+         touched: self.touched,
+         validating: self.validating,
+         validators: self.validators.clone(),
+      }
+   }
+}
+
+impl<T> FormField<T>
+where
+   T: Debug + PartialEq + Clone + Send + Sync + 'static,
+{
+   fn new(value: RwSignal<T>) -> Self {
+      Self {
+         value,
+         initial: use_rw_signal(value.get_untracked()),
+         error: use_rw_signal(None),
+         touched: use_rw_signal(false),
+         validating: use_rw_signal(false),
+         validators: Arc::new(Mutex::new(FieldValidators {
+            sync: Vec::new(),
+            r#async: Vec::new(),
+            version: 0,
+         })),
+      }
+   }
+
+   pub fn validator(
+      self,
+      f: impl Fn(&T) -> Result<(), FieldError> + MaybeSend + MaybeSync + 'static,
+   ) -> Self {
+      self.validators.lock().unwrap().sync.push(Box::new(f));
+      self
+   }
+
+   /// Async validators run after all sync validators passed.
+   pub fn async_validator<Fut>(self, f: impl Fn(T) -> Fut + MaybeSend + MaybeSync + 'static) -> Self
+   where
+      Fut: Future<Output = Result<(), FieldError>> + MaybeSend + 'static,
+   {
+      self
+         .validators
+         .lock()
+         .unwrap()
+         .r#async
+         .push(Arc::new(Box::new(move |value| Box::pin(f(value)))));
+      self
+   }
+
+   #[inline]
+   pub fn error(&self) -> ReadSignal<Option<FieldError>> {
+      self.error.read_only()
+   }
+
+   #[inline]
+   pub fn is_touched(&self) -> bool {
+      self.touched.get()
+   }
+
+   #[inline]
+   pub fn is_validating(&self) -> bool {
+      self.validating.get()
+   }
+
+   pub fn is_dirty(&self) -> bool {
+      self
+         .value
+         .with(|value| self.initial.with(|initial| value != initial))
+   }
+
+   pub fn touch(&self) {
+      if !self.touched.get_untracked() {
+         self.touched.set(true);
+      }
+   }
+
+   /// Restores the initial value and clears the touched and error states.
+   pub fn reset(&self) {
+      self.validators.lock().unwrap().version += 1;
+      self.value.set(self.initial.get_untracked());
+      self.touched.set(false);
+      self.error.set(None);
+      self.validating.set(false);
+   }
+
+   /// Uses the current value as the new initial value, so the field is no longer dirty.
+   pub fn commit(&self) {
+      self.initial.set(self.value.get_untracked());
+   }
+
+   // The validators are allocated once per field, and shared by its clones
+   fn id(&self) -> usize {
+      Arc::as_ptr(&self.validators) as *const () as usize
+   }
+
+   /// Runs all validators and writes the result into the error signal.
+   pub fn validate(&self) -> BoxedFutureMaybeLocal<bool> {
+      let value = self.value.get_untracked();
+      let (version, async_validators) = {
+         let mut validators = self.validators.lock().unwrap();
+         validators.version += 1;
+         if let Some(err) = validators.sync.iter().find_map(|f| f(&value).err()) {
+            self.error.set(Some(err));
+            self.validating.set(false);
+            return Box::pin(future::ready(false));
+         }
+         (validators.version, validators.r#async.clone())
+      };
+
+      if async_validators.is_empty() {
+         self.error.set(None);
+         self.validating.set(false);
+         return Box::pin(future::ready(true));
+      }
+
+      self.validating.set(true);
+      let field = self.clone();
+      Box::pin(async move {
+         let mut result = Ok(());
+         for validator in async_validators {
+            result = validator(value.clone()).await;
+            if result.is_err() {
+               break;
+            }
+         }
+         if field.validators.lock().unwrap().version != version {
+            return false;
+         }
+         let is_valid = result.is_ok();
+         field.error.set(result.err());
+         field.validating.set(false);
+         is_valid
+      })
+   }
+}
+
+trait ErasedFormField: MaybeSend + MaybeSync {
+   fn id(&self) -> usize;
+   fn validate(&self) -> BoxedFutureMaybeLocal<bool>;
+   fn reset(&self);
+   fn commit(&self);
+   fn touch(&self);
+   fn is_dirty(&self) -> bool;
+   fn has_error(&self) -> bool;
+}
+
+impl<T> ErasedFormField for FormField<T>
+where
+   T: Debug + PartialEq + Clone + Send + Sync + 'static,
+{
+   fn id(&self) -> usize {
+      FormField::id(self)
+   }
+
+   fn validate(&self) -> BoxedFutureMaybeLocal<bool> {
+      FormField::validate(self)
+   }
+
+   fn reset(&self) {
+      FormField::reset(self)
+   }
+
+   fn commit(&self) {
+      FormField::commit(self)
+   }
+
+   fn touch(&self) {
+      FormField::touch(self)
+   }
+
+   fn is_dirty(&self) -> bool {
+      FormField::is_dirty(self)
+   }
+
+   fn has_error(&self) -> bool {
+      self.error.with(|n| n.is_some())
+   }
+}
+
+/// Collects the fields of a form. Provided to descendant schemas as a context by the `form` schema.
+#[derive(Clone)]
+pub struct Form {
+   fields: Arc<Mutex<Vec<Arc<dyn ErasedFormField>>>>,
+   // Notifies readers of the field list when a field is registered or unregistered
+   fields_version: RwSignal<usize>,
+   submitting: RwSignal<bool>,
+   on_submit: Arc<Mutex<Option<Sender<()>>>>,
+}
+
+impl Default for Form {
+   fn default() -> Self {
+      Self {
+         fields: Default::default(),
+         fields_version: use_rw_signal(0),
+         submitting: use_rw_signal(false),
+         on_submit: Default::default(),
+      }
+   }
+}
+
+impl Form {
+   pub fn new() -> Self {
+      Self::default()
+   }
+
+   pub fn register<T>(&self, field: &FormField<T>)
+   where
+      T: Debug + PartialEq + Clone + Send + Sync + 'static,
+   {
+      self.fields.lock().unwrap().push(Arc::new(field.clone()));
+      self
+         .fields_version
+         .set(self.fields_version.get_untracked() + 1);
+   }
+
+   pub fn unregister<T>(&self, field: &FormField<T>)
+   where
+      T: Debug + PartialEq + Clone + Send + Sync + 'static,
+   {
+      self.unregister_id(field.id());
+   }
+
+   fn unregister_id(&self, id: usize) {
+      let mut fields = self.fields.lock().unwrap();
+      let len = fields.len();
+      fields.retain(|n| n.id() != id);
+      if fields.len() != len {
+         drop(fields);
+         self
+            .fields_version
+            .set(self.fields_version.get_untracked() + 1);
+      }
+   }
+
+   /// The sender is notified after a submit whose validation passed.
+   pub fn set_on_submit(&self, on_submit: Sender<()>) {
+      *self.on_submit.lock().unwrap() = Some(on_submit);
+   }
+
+   fn fields(&self) -> Vec<Arc<dyn ErasedFormField>> {
+      self.fields_version.with(|_| ());
+      self.fields.lock().unwrap().clone()
+   }
+
+   pub fn is_dirty(&self) -> bool {
+      self.fields().iter().any(|n| n.is_dirty())
+   }
+
+   pub fn is_valid(&self) -> bool {
+      !self.fields().iter().any(|n| n.has_error())
+   }
+
+   #[inline]
+   pub fn is_submitting(&self) -> ReadSignal<bool> {
+      self.submitting.read_only()
+   }
+
+   pub fn reset(&self) {
+      for field in self.fields() {
+         field.reset();
+      }
+   }
+
+   pub fn validate(&self) -> impl Future<Output = bool> + MaybeSend + 'static {
+      let validations = self
+         .fields
+         .lock()
+         .unwrap()
+         .iter()
+         .map(|n| n.validate())
+         .collect::<Vec<_>>();
+      async move {
+         let mut is_valid = true;
+         for validation in validations {
+            is_valid &= validation.await;
+         }
+         is_valid
+      }
+   }
+
+   /// Touches and validates all fields, then notifies the submit handler if they are valid.
+   /// The submitted values become the new initial values.
+   pub fn submit(&self) {
+      if self.submitting.get_untracked() {
+         return;
+      }
+      let fields = self.fields.lock().unwrap().clone();
+      for field in fields.iter() {
+         field.touch();
+      }
+      self.submitting.set(true);
+      let validation = self.validate();
+      let form = self.clone();
+      spawn(async move {
+         let is_valid = validation.await;
+         if is_valid {
+            for field in fields.iter() {
+               field.commit();
+            }
+            let on_submit = form.on_submit.lock().unwrap().clone();
+            if let Some(on_submit) = on_submit {
+               let _ = on_submit.send(()).await;
+            }
+         }
+         form.submitting.set(false);
+      });
+   }
+}
+
+/// Removes the field from the form when the schema of the field is removed.
+struct FormFieldRegistration {
+   form: Form,
+   id: usize,
+}
+
+impl Drop for FormFieldRegistration {
+   fn drop(&mut self) {
+      self.form.unregister_id(self.id);
+   }
+}
+
+impl<R> RenderSchemaCtx<R>
+where
+   R: Renderer,
+{
+   pub fn use_form(&mut self) -> Form {
+      Form::new()
+   }
+
+   pub fn use_form_field<T>(&mut self, value: ReadSignal<T>, onchange: Sender<T>) -> FormField<T>
+   where
+      T: Debug + PartialEq + Clone + Send + Sync + 'static,
+   {
+      self.mut_scoped(|ctx| ctx.use_form_field(value, onchange))
+   }
+}
+
+impl<'a, R, U> InnerSchemaCtx<'a, R, U>
+where
+   R: Renderer,
+{
+   /// Binds the field through [`use_controlled_state`](Self::use_controlled_state) and registers it
+   /// in the nearest [`Form`] context, if any. The field is validated on every change.
+   pub fn use_form_field<T>(&mut self, value: ReadSignal<T>, onchange: Sender<T>) -> FormField<T>
+   where
+      T: Debug + PartialEq + Clone + Send + Sync + 'static,
+   {
+      let field = FormField::new(self.use_controlled_state(value, onchange));
+
+      let form = ViewCtx::<R> {
+         world: &mut *self.world,
+         parent: self.parent.clone(),
+      }
+      .get_context::<Form>();
+      if let Some(form) = form {
+         form.register(&field);
+         self.add_drop_guard(FormFieldRegistration {
+            form,
+            id: field.id(),
+         });
+      }
+
+      let validate_effect = create_effect({
+         let field = field.clone();
+         move |prev: Option<()>| {
+            field.value.with(|_| ());
+            if prev.is_some() {
+               spawn({
+                  let validation = field.validate();
+                  async move {
+                     validation.await;
+                  }
+               });
+            }
+         }
+      });
+      self.effect_state().push(validate_effect.erase());
+
+      field
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn dropped_registration_removes_the_field() {
+      let form = Form::new();
+      let field = FormField::new(use_rw_signal(1));
+      form.register(&field);
+      field.value.set(2);
+      assert!(form.is_dirty());
+
+      drop(FormFieldRegistration {
+         form: form.clone(),
+         id: field.id(),
+      });
+      assert!(!form.is_dirty());
+      assert!(form.fields().is_empty());
+   }
+}
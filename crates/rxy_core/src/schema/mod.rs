@@ -4,6 +4,8 @@ pub use ctx::*;
 pub use element::*;
 #[cfg(all(feature = "async-channel", feature = "std"))]
 pub use event::*;
+#[cfg(all(feature = "xy_reactive", feature = "async-channel"))]
+pub use form::*;
 pub use param::*;
 pub use prop::*;
 pub use prop_state::*;
@@ -28,6 +30,8 @@ mod element;
 #[cfg(all(feature = "async-channel", feature = "std"))]
 mod event;
 mod r#fn;
+#[cfg(all(feature = "xy_reactive", feature = "async-channel"))]
+mod form;
 mod param;
 mod prop;
 mod prop_state;
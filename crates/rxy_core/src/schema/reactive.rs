@@ -13,8 +13,7 @@ use crate::{
    ReactiveDisposerState, SchemaProp, SchemaPropCtx,
 };
 use crate::{
-   ConstIndex, InnerSchemaCtx, MaybeSend, MaybeSync, Renderer, Required, SchemaParam,
-   SchemaParamDefault,
+   ConstIndex, InnerSchemaCtx, MaybeSend, Renderer, Required, SchemaParam, SchemaParamDefault,
 };
 use crate::{DeferredNodeTreeScoped, NodeTree};
 
@@ -26,10 +25,12 @@ use crate::{DeferredNodeTreeScoped, NodeTree};
 //     type Value = T;
 // }
 
+// The signal values and the memo closures are stored in the reactive arena, which always
+// requires `Send + Sync`, independent of the `send_sync` feature.
 impl<R, T> SchemaParam<R> for ReadSignal<T>
 where
    R: Renderer,
-   T: MaybeSend + MaybeSync + SchemaParamDefault<R> + 'static,
+   T: Send + Sync + SchemaParamDefault<R> + 'static,
 {
    fn from<const I: usize>(ctx: &mut InnerSchemaCtx<R>) -> Self {
       use xy_reactive::prelude::use_signal;
@@ -52,7 +53,7 @@ where
 impl<R, T> SchemaParam<R> for Required<ReadSignal<T>>
 where
    R: Renderer,
-   T: MaybeSend + MaybeSync + 'static,
+   T: Send + Sync + 'static,
 {
    fn from<const I: usize>(ctx: &mut InnerSchemaCtx<R>) -> Self {
       use xy_reactive::prelude::use_signal;
@@ -113,7 +114,7 @@ where
 impl<R, T> SchemaProp<R> for ReadSignal<T>
 where
    R: Renderer,
-   T: Clone + MaybeSend + MaybeSync + 'static,
+   T: Clone + Send + Sync + 'static,
 {
    type Value = T;
 
@@ -134,7 +135,7 @@ where
 impl<R, T> SchemaProp<R> for Memo<T>
 where
    R: Renderer,
-   T: Clone + MaybeSend + MaybeSync + 'static,
+   T: Clone + Send + Sync + 'static,
 {
    type Value = T;
 
@@ -176,8 +177,8 @@ where
 // impl<R, PV, T> IntoSchemaProp<R, PV> for ReadSignal<T>
 // where
 //     R: Renderer,
-//     T: IntoSchemaProp<R, PV> + MaybeSend + MaybeSync + 'static,
-//     PV: Clone + MaybeSend + MaybeSync + 'static,
+//     T: IntoSchemaProp<R, PV> + Send + Sync + 'static,
+//     PV: Clone + Send + Sync + 'static,
 // {
 //     type Prop = Memo<T::Prop>;
 
@@ -189,7 +190,7 @@ where
 impl<R, T> IntoSchemaProp<R, T> for ReadSignal<T>
 where
    R: Renderer,
-   T: Clone + MaybeSend + MaybeSync + 'static,
+   T: Clone + Send + Sync + 'static,
 {
    type Prop = Self;
 
@@ -201,7 +202,7 @@ where
 impl<R, T> IntoSchemaProp<R, T> for Memo<T>
 where
    R: Renderer,
-   T: Clone + MaybeSend + MaybeSync + 'static,
+   T: Clone + Send + Sync + 'static,
 {
    type Prop = Self;
 
@@ -213,7 +214,7 @@ where
 impl<R, T> IntoSchemaProp<R, T> for RwSignal<T>
 where
    R: Renderer,
-   T: Clone + MaybeSend + MaybeSync + 'static,
+   T: Clone + Send + Sync + 'static,
 {
    type Prop = ReadSignal<T>;
 
@@ -225,8 +226,8 @@ where
 impl<R, F, T> IntoSchemaProp<R, T> for Reactive<F, T>
 where
    R: Renderer,
-   F: Fn() -> T + MaybeSend + MaybeSync + 'static,
-   T: Clone + MaybeSend + MaybeSync + PartialEq + 'static,
+   F: Fn() -> T + Send + Sync + 'static,
+   T: Clone + Send + Sync + PartialEq + 'static,
 {
    type Prop = Memo<T>;
 
@@ -238,7 +239,7 @@ where
 pub struct SignalPropState<R, T>
 where
    R: Renderer,
-   T: MaybeSend + MaybeSync + 'static,
+   T: Send + Sync + 'static,
 {
    write_signal: WriteSignal<T>,
    _marker: PhantomData<R>,
@@ -247,7 +248,7 @@ where
 impl<R, T> SignalPropState<R, T>
 where
    R: Renderer,
-   T: MaybeSend + MaybeSync + 'static,
+   T: Send + Sync + 'static,
 {
    pub fn new(write_signal: WriteSignal<T>) -> Self {
       Self {
@@ -260,7 +261,7 @@ where
 impl<R, T> PropState<R> for SignalPropState<R, T>
 where
    R: Renderer,
-   T: MaybeSend + MaybeSync + 'static,
+   T: Send + Sync + 'static,
 {
    fn apply(&mut self, new_value: BoxedPropValue, _world: &mut R::NodeTree) {
       let Ok(new_value) = new_value.downcast::<T>().map(|n| *n) else {
@@ -3,8 +3,8 @@ use crate::utils::SyncCell;
 use crate::{
    into_view, BoxedCloneableErasureView, BoxedErasureView, BoxedPropValue, ConstIndex, DataNodeId,
    InnerSchemaCtx, IntoCloneableView, IntoSchemaProp, IntoView, IntoViewCloneableErasureExt,
-   IntoViewErasureExt, MaybeSend, NodeTree, PropHashMap, Renderer, RendererNodeId, RendererWorld,
-   Schema, SchemaProp, SchemaProps, View, ViewCtx, ViewKey,
+   IntoViewErasureExt, MaybeSend, MaybeSendAnyBox, NodeTree, PropHashMap, Renderer, RendererNodeId,
+   RendererWorld, Schema, SchemaProp, SchemaProps, View, ViewCtx, ViewKey,
 };
 use alloc::boxed::Box;
 use core::any::TypeId;
@@ -147,6 +147,7 @@ where
 
 pub struct SchemaViewState<R> {
    prop_state: SyncCell<Option<PropHashMap<R>>>,
+   _drop_guards: SyncCell<alloc::vec::Vec<MaybeSendAnyBox>>,
    #[cfg(feature = "xy_reactive")]
    _other_state: alloc::vec::Vec<xy_reactive::effect::ErasureEffect>,
 }
@@ -255,6 +256,7 @@ where
    init_values.extend(schema_view.static_values);
 
    let mut prop_state = PropHashMap::<R>::default();
+   let mut drop_guards = alloc::vec![];
    #[cfg(feature = "xy_reactive")]
    let mut _effect_state = alloc::vec![];
    let view = schema_view.u.view(InnerSchemaCtx {
@@ -264,6 +266,7 @@ where
       cloneable_slots: &mut schema_view.cloneable_slots,
       init_values,
       prop_state: &mut prop_state,
+      drop_guards: &mut drop_guards,
       #[cfg(feature = "xy_reactive")]
       effect_state: &mut _effect_state,
       _marker: Default::default(),
@@ -313,6 +316,7 @@ where
       &state_node_id,
       SchemaViewState {
          prop_state: SyncCell::new(Some(prop_state)),
+         _drop_guards: SyncCell::new(drop_guards),
          #[cfg(feature = "xy_reactive")]
          _other_state: _effect_state,
       },
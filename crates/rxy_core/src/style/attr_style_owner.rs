@@ -65,6 +65,7 @@ where
       Self: Sized,
    {
       self.check_style_sheet_type(style_sheet_definition)?;
+      style_sheet_definition.check_items_len()?;
       for (item_index, attr_id) in style_sheet_definition
          .items
          .iter()
@@ -196,7 +197,7 @@ where
                   let prev_len = heap.len();
                   let heap = heap
                      .into_iter()
-                     .filter(|n| n == &attr_style_item_id.item_id)
+                     .filter(|n| n != &attr_style_item_id.item_id)
                      .collect::<BinaryHeap<NodeStyleItemId>>();
                   if heap.len() == prev_len {
                      return Err(StyleError::NoFoundStyleItemId {
@@ -0,0 +1,207 @@
+use crate::style::{x, StyleSheetOwner};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum StyleOrientation {
+   Portrait,
+   Landscape,
+}
+
+/// The environment media query style sheets are matched against.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct StyleMedia {
+   /// Logical width of the window
+   pub width: f32,
+   /// Logical height of the window
+   pub height: f32,
+   pub scale_factor: f32,
+   pub reduced_motion: bool,
+   pub dark: bool,
+}
+
+impl StyleMedia {
+   pub fn orientation(&self) -> StyleOrientation {
+      if self.height > self.width {
+         StyleOrientation::Portrait
+      } else {
+         StyleOrientation::Landscape
+      }
+   }
+}
+
+/// The condition of a style sheet, all of the set fields must match.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct StyleMediaQuery {
+   pub min_width: Option<f32>,
+   pub max_width: Option<f32>,
+   pub min_height: Option<f32>,
+   pub max_height: Option<f32>,
+   pub orientation: Option<StyleOrientation>,
+   pub min_scale_factor: Option<f32>,
+   pub max_scale_factor: Option<f32>,
+   pub reduced_motion: Option<bool>,
+   pub dark: Option<bool>,
+}
+
+impl StyleMediaQuery {
+   pub fn matches(&self, media: &StyleMedia) -> bool {
+      self.min_width.map_or(true, |n| media.width >= n)
+         && self.max_width.map_or(true, |n| media.width <= n)
+         && self.min_height.map_or(true, |n| media.height >= n)
+         && self.max_height.map_or(true, |n| media.height <= n)
+         && self.orientation.map_or(true, |n| media.orientation() == n)
+         && self
+            .min_scale_factor
+            .map_or(true, |n| media.scale_factor >= n)
+         && self
+            .max_scale_factor
+            .map_or(true, |n| media.scale_factor <= n)
+         && self
+            .reduced_motion
+            .map_or(true, |n| media.reduced_motion == n)
+         && self.dark.map_or(true, |n| media.dark == n)
+   }
+}
+
+impl<T> StyleSheetOwner<T> {
+   /// Only apply the style sheet while `query` matches
+   pub fn media(self, query: StyleMediaQuery) -> Self {
+      StyleSheetOwner {
+         media: Some(query),
+         ..self
+      }
+   }
+}
+
+pub fn x_media(query: StyleMediaQuery) -> StyleSheetOwner<()> {
+   StyleSheetOwner {
+      media: Some(query),
+      ..x()
+   }
+}
+
+pub fn x_min_width(width: f32) -> StyleSheetOwner<()> {
+   x_media(StyleMediaQuery {
+      min_width: Some(width),
+      ..Default::default()
+   })
+}
+
+pub fn x_max_width(width: f32) -> StyleSheetOwner<()> {
+   x_media(StyleMediaQuery {
+      max_width: Some(width),
+      ..Default::default()
+   })
+}
+
+pub fn x_min_height(height: f32) -> StyleSheetOwner<()> {
+   x_media(StyleMediaQuery {
+      min_height: Some(height),
+      ..Default::default()
+   })
+}
+
+pub fn x_max_height(height: f32) -> StyleSheetOwner<()> {
+   x_media(StyleMediaQuery {
+      max_height: Some(height),
+      ..Default::default()
+   })
+}
+
+pub fn x_portrait() -> StyleSheetOwner<()> {
+   x_media(StyleMediaQuery {
+      orientation: Some(StyleOrientation::Portrait),
+      ..Default::default()
+   })
+}
+
+pub fn x_landscape() -> StyleSheetOwner<()> {
+   x_media(StyleMediaQuery {
+      orientation: Some(StyleOrientation::Landscape),
+      ..Default::default()
+   })
+}
+
+pub fn x_min_scale_factor(scale_factor: f32) -> StyleSheetOwner<()> {
+   x_media(StyleMediaQuery {
+      min_scale_factor: Some(scale_factor),
+      ..Default::default()
+   })
+}
+
+pub fn x_reduced_motion() -> StyleSheetOwner<()> {
+   x_media(StyleMediaQuery {
+      reduced_motion: Some(true),
+      ..Default::default()
+   })
+}
+
+pub fn x_dark() -> StyleSheetOwner<()> {
+   x_media(StyleMediaQuery {
+      dark: Some(true),
+      ..Default::default()
+   })
+}
+
+pub fn x_light() -> StyleSheetOwner<()> {
+   x_media(StyleMediaQuery {
+      dark: Some(false),
+      ..Default::default()
+   })
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   const MEDIA: StyleMedia = StyleMedia {
+      width: 800.,
+      height: 600.,
+      scale_factor: 2.,
+      reduced_motion: false,
+      dark: true,
+   };
+
+   #[test]
+   fn match_bounds_inclusively() {
+      assert!(StyleMediaQuery::default().matches(&MEDIA));
+      assert!(StyleMediaQuery {
+         min_width: Some(800.),
+         max_height: Some(600.),
+         ..Default::default()
+      }
+      .matches(&MEDIA));
+      assert!(!StyleMediaQuery {
+         min_width: Some(801.),
+         ..Default::default()
+      }
+      .matches(&MEDIA));
+      assert!(!StyleMediaQuery {
+         max_scale_factor: Some(1.5),
+         ..Default::default()
+      }
+      .matches(&MEDIA));
+   }
+
+   #[test]
+   fn match_all_set_fields() {
+      let query = StyleMediaQuery {
+         orientation: Some(StyleOrientation::Landscape),
+         dark: Some(true),
+         ..Default::default()
+      };
+      assert!(query.matches(&MEDIA));
+      assert!(!query.matches(&StyleMedia {
+         dark: false,
+         ..MEDIA
+      }));
+      assert!(!query.matches(&StyleMedia {
+         width: 400.,
+         ..MEDIA
+      }));
+      assert!(!StyleMediaQuery {
+         reduced_motion: Some(true),
+         ..query
+      }
+      .matches(&MEDIA));
+   }
+}
@@ -1,4 +1,5 @@
 use std::any::TypeId;
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use std::iter::{once, Chain};
@@ -7,8 +8,12 @@ use std::ops::{AddAssign, Deref, DerefMut};
 use bevy_utils::HashMap;
 
 pub use attr_style_owner::*;
+pub use media::*;
+pub use report::*;
+pub use selector::*;
 pub use style_sheet_definition::*;
 pub use style_sheet_items::*;
+pub use style_var::*;
 pub use view_member::*;
 
 use crate::utils::all_tuples;
@@ -18,8 +23,12 @@ use crate::{
 };
 
 mod attr_style_owner;
+mod media;
+mod report;
+mod selector;
 mod style_sheet_definition;
 mod style_sheet_items;
+mod style_var;
 mod view_member;
 
 pub trait StyledNodeTree<R>: NodeTree<R>
@@ -49,6 +58,14 @@ where
    ) -> Result<R>
    where
       T: StyleSheets<R>;
+
+   /// Define the style variable `name` on the node, or remove it when `value` is `None`.
+   fn set_style_var(
+      &mut self,
+      node_id: RendererNodeId<R>,
+      name: Cow<'static, str>,
+      value: Option<StyleAttrValue>,
+   );
 }
 
 pub type Result<R, T = ()> = core::result::Result<T, StyleError<R>>;
@@ -57,7 +74,12 @@ pub type StyleAttrValue = SmallBox<dyn AttrValue, S1>;
 pub mod prelude {
    pub use rxy_macro::TypedStyle;
 
-   pub use super::{x, x_active, x_focus, x_hover};
+   pub use super::{
+      style_var, var, x, x_active, x_checked, x_children, x_dark, x_descendants, x_disabled,
+      x_focus, x_group_hover, x_hover, x_invalid, x_landscape, x_light, x_max_height, x_max_width,
+      x_media, x_min_height, x_min_scale_factor, x_min_width, x_portrait, x_reduced_motion,
+      x_selected, x_state, StyleInteraction, StyleMediaQuery,
+   };
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -137,23 +159,95 @@ impl Deref for NodeAttrStyleItemId {
 
 pub type SharedStyleSheetId = TypeId;
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct StyleSheetOwner<T>(pub Option<StyleInteraction>, pub T);
+#[derive(Clone, Debug, PartialEq)]
+pub struct StyleSheetOwner<T> {
+   pub interaction: Option<StyleInteraction>,
+   pub items: T,
+   pub media: Option<StyleMediaQuery>,
+   pub selector: Option<StyleSelector>,
+}
+
+impl<T> StyleSheetOwner<T> {
+   /// An unconditional style sheet of `items`
+   pub fn new(items: T) -> Self {
+      Self {
+         interaction: None,
+         items,
+         media: None,
+         selector: None,
+      }
+   }
+
+   /// The style sheet with the same conditions and the items returned by `f`
+   pub fn map_items<U>(self, f: impl FnOnce(T) -> U) -> StyleSheetOwner<U> {
+      StyleSheetOwner {
+         interaction: self.interaction,
+         items: f(self.items),
+         media: self.media,
+         selector: self.selector,
+      }
+   }
+}
 
 pub fn x() -> StyleSheetOwner<()> {
-   StyleSheetOwner(None, ())
+   StyleSheetOwner::new(())
 }
 
 pub fn x_hover() -> StyleSheetOwner<()> {
-   StyleSheetOwner(Some(StyleInteraction::Hover), ())
+   StyleSheetOwner {
+      interaction: Some(StyleInteraction::Hover),
+      ..x()
+   }
 }
 
 pub fn x_active() -> StyleSheetOwner<()> {
-   StyleSheetOwner(Some(StyleInteraction::Active), ())
+   StyleSheetOwner {
+      interaction: Some(StyleInteraction::Active),
+      ..x()
+   }
 }
 
 pub fn x_focus() -> StyleSheetOwner<()> {
-   StyleSheetOwner(Some(StyleInteraction::Focus), ())
+   StyleSheetOwner {
+      interaction: Some(StyleInteraction::Focus),
+      ..x()
+   }
+}
+
+pub fn x_disabled() -> StyleSheetOwner<()> {
+   StyleSheetOwner {
+      interaction: Some(StyleInteraction::Disabled),
+      ..x()
+   }
+}
+
+pub fn x_checked() -> StyleSheetOwner<()> {
+   StyleSheetOwner {
+      interaction: Some(StyleInteraction::Checked),
+      ..x()
+   }
+}
+
+pub fn x_selected() -> StyleSheetOwner<()> {
+   StyleSheetOwner {
+      interaction: Some(StyleInteraction::Selected),
+      ..x()
+   }
+}
+
+pub fn x_invalid() -> StyleSheetOwner<()> {
+   StyleSheetOwner {
+      interaction: Some(StyleInteraction::Invalid),
+      ..x()
+   }
+}
+
+/// Style sheet of a custom state, see [`StyleInteraction::custom`]
+pub fn x_state(state: StyleInteraction) -> StyleSheetOwner<()> {
+   StyleSheetOwner {
+      interaction: Some(state),
+      ..x()
+   }
 }
 
 impl<R, T> MemberOwner<R> for StyleSheetOwner<T>
@@ -171,14 +265,14 @@ where
       (Self::VM, VM): ViewMember<R>,
       VM: ViewMember<R>,
    {
-      StyleSheetOwner(self.0, self.1.member(member))
+      self.map_items(|items| items.member(member))
    }
 
    fn members<VM: ViewMember<R>>(self, members: VM) -> Self::SetMembers<(VM,)>
    where
       VM: ViewMember<R>,
    {
-      StyleSheetOwner(self.0, self.1.members(members))
+      self.map_items(|items| items.members(members))
    }
 }
 
@@ -290,6 +384,15 @@ where
 
    // #[error("no found node: {node_id:?}")]
    NoFoundNode { node_id: RendererNodeId<R> },
+
+   // #[error("style sheet index {index} out of range of {location:?} style sheets")]
+   StyleSheetIndexOverflow {
+      index: usize,
+      location: StyleSheetLocation,
+   },
+
+   // #[error("style item index {index} out of range")]
+   StyleItemIndexOverflow { index: usize },
 }
 
 #[derive(Default, Debug)]
@@ -357,25 +460,20 @@ impl NodeInterStyleAttrInfos {
    ) -> Option<&NodeStyleAttrInfo> {
       self.get(&interaction).and_then(|n| n.get(&attr_id))
    }
+   /// Non-strict matching takes the attr info of the highest priority interaction contained in
+   /// `interaction`, see [`StyleInteraction::priority_iter`]
    pub fn match_attr(
       &self,
       attr_id: AttrIndex,
       interaction: StyleInteraction,
       strict: bool,
    ) -> Option<&NodeStyleAttrInfo> {
-      match interaction {
-         StyleInteraction::Active => self
-            .get(&StyleInteraction::Active)
-            .and_then(|n| n.get(&attr_id))
-            .condition(strict, |n| {
-               n.or_else(|| {
-                  self
-                     .get(&StyleInteraction::Hover)
-                     .and_then(|n| n.get(&attr_id))
-               })
-            }),
-         interaction => self.get(&interaction).and_then(|n| n.get(&attr_id)),
+      if strict {
+         return self.get_attr_info(interaction, attr_id);
       }
+      interaction
+         .match_iter(false)
+         .find_map(|interaction| self.get_attr_info(interaction, attr_id))
    }
 
    /// There are repeated AttrId
@@ -506,14 +604,19 @@ pub struct StyleItemValue {
 }
 
 pub struct StyleSheetsInfo {
-   pub inline_style_sheet_count: u8,
-   pub shared_style_sheet_count: u8,
+   pub inline_style_sheet_count: StyleSheetIndex,
+   pub shared_style_sheet_count: StyleSheetIndex,
 }
 
 impl AddAssign for StyleSheetsInfo {
+   /// Saturates, the overflowed indices are reported when the style sheets are added to the node
    fn add_assign(&mut self, rhs: Self) {
-      self.inline_style_sheet_count += rhs.inline_style_sheet_count;
-      self.shared_style_sheet_count += rhs.shared_style_sheet_count;
+      self.inline_style_sheet_count = self
+         .inline_style_sheet_count
+         .saturating_add(rhs.inline_style_sheet_count);
+      self.shared_style_sheet_count = self
+         .shared_style_sheet_count
+         .saturating_add(rhs.shared_style_sheet_count);
    }
 }
 
@@ -590,8 +693,10 @@ where
    ) {
       (
          once(AppliedStyleSheet::Inline(StyleSheetDefinition {
-            interaction: self.0,
-            items: T::iter(self.1, ctx).collect(),
+            interaction: self.interaction,
+            media: self.media,
+            selector: self.selector,
+            items: T::iter(self.items, ctx).collect(),
          })),
          StyleSheetsInfo {
             inline_style_sheet_count: 1,
@@ -0,0 +1,246 @@
+use core::fmt::{Display, Formatter};
+
+use crate::style::{
+   NodeInterStyleAttrInfos, NodeStyleAttrInfo, NodeStyleItemId, StyleAttrValue, StyleInteraction,
+};
+use crate::{AttrIndex, Either};
+
+impl NodeStyleAttrInfo {
+   /// All the candidate items of the attr, the first one has the highest precedence
+   pub fn iter_item_ids(&self) -> impl Iterator<Item = NodeStyleItemId> + '_ {
+      let mut item_ids = match &self.0 {
+         Either::Left(item_id) => vec![*item_id],
+         Either::Right(heap) => heap.iter().copied().collect(),
+      };
+      item_ids.sort_unstable_by(|a, b| b.cmp(a));
+      item_ids.into_iter()
+   }
+}
+
+/// A style item that may set the attr of a node, see [`StyleAttrReport`]
+#[derive(Clone, Debug)]
+pub struct StyleAttrCandidate {
+   pub item_id: NodeStyleItemId,
+   /// `None` for the items of the style sheets without interaction
+   pub interaction: Option<StyleInteraction>,
+   /// Whether the node currently matches the interaction of the item
+   pub is_active: bool,
+   pub value: Option<StyleAttrValue>,
+}
+
+/// Explains which style item sets an attr of a node, and why the other candidates don't.
+#[derive(Clone, Debug)]
+pub struct StyleAttrReport {
+   pub attr_id: AttrIndex,
+   pub attr_name: &'static str,
+   /// All the interactions and user states the node currently matches
+   pub node_interaction: StyleInteraction,
+   /// The attr is set on the element directly, which takes precedence over all the style items
+   pub is_set_on_element: bool,
+   /// Interaction candidates in [`StyleInteraction::priority_iter`] order, then the others
+   pub candidates: Vec<StyleAttrCandidate>,
+   /// The index of the candidate that sets the attr
+   pub winner: Option<usize>,
+}
+
+impl StyleAttrReport {
+   pub fn new(
+      attr_id: AttrIndex,
+      attr_name: &'static str,
+      node_interaction: StyleInteraction,
+      is_set_on_element: bool,
+      attr_info: Option<&NodeStyleAttrInfo>,
+      inter_attr_infos: Option<&NodeInterStyleAttrInfos>,
+   ) -> Self {
+      let mut candidates = vec![];
+      if let Some(inter_attr_infos) = inter_attr_infos {
+         for interaction in StyleInteraction::priority_iter() {
+            let Some(inter_attr_info) = inter_attr_infos.get_attr_info(interaction, attr_id) else {
+               continue;
+            };
+            candidates.extend(
+               inter_attr_info
+                  .iter_item_ids()
+                  .map(|item_id| StyleAttrCandidate {
+                     item_id,
+                     interaction: Some(interaction),
+                     is_active: node_interaction.contains(interaction),
+                     value: None,
+                  }),
+            );
+         }
+      }
+      if let Some(attr_info) = attr_info {
+         candidates.extend(attr_info.iter_item_ids().map(|item_id| StyleAttrCandidate {
+            item_id,
+            interaction: None,
+            is_active: true,
+            value: None,
+         }));
+      }
+
+      // the same matching as the one the attr values are synced with
+      let matched = inter_attr_infos
+         .and_then(|inter_attr_infos| {
+            node_interaction.match_iter(false).find_map(|interaction| {
+               inter_attr_infos
+                  .get_attr_info(interaction, attr_id)
+                  .map(|n| (Some(interaction), n.top_item_id()))
+            })
+         })
+         .or_else(|| attr_info.map(|n| (None, n.top_item_id())));
+      let winner = matched
+         .filter(|_| !is_set_on_element)
+         .and_then(|(interaction, item_id)| {
+            candidates
+               .iter()
+               .position(|n| n.interaction == interaction && n.item_id == item_id)
+         });
+
+      Self {
+         attr_id,
+         attr_name,
+         node_interaction,
+         is_set_on_element,
+         candidates,
+         winner,
+      }
+   }
+
+   pub fn winner_candidate(&self) -> Option<&StyleAttrCandidate> {
+      self.winner.map(|n| &self.candidates[n])
+   }
+}
+
+impl Display for StyleAttrReport {
+   fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+      writeln!(
+         f,
+         "attr `{}` ({}), node interaction: {:?}",
+         self.attr_name, self.attr_id, self.node_interaction
+      )?;
+      if self.is_set_on_element {
+         writeln!(f, "  set on the element, the style items are ignored")?;
+      }
+      if self.candidates.is_empty() {
+         writeln!(f, "  no style items")?;
+      }
+      for (index, candidate) in self.candidates.iter().enumerate() {
+         let is_winner = self.winner == Some(index);
+         write!(
+            f,
+            "  {} {:?} sheet {} item {}",
+            if is_winner { "*" } else { " " },
+            candidate.item_id.sheet_id.location,
+            candidate.item_id.sheet_id.index,
+            candidate.item_id.item_index,
+         )?;
+         if let Some(interaction) = candidate.interaction {
+            write!(f, " [{:?}]", interaction)?;
+         }
+         if !candidate.is_active {
+            write!(f, " inactive")?;
+         }
+         if let Some(value) = &candidate.value {
+            write!(f, " = {:?}", value)?;
+         }
+         writeln!(f)?;
+      }
+      Ok(())
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use crate::style::{NodeStyleAttrInfos, NodeStyleSheetId, StyleSheetLocation};
+   use crate::EitherExt;
+
+   use super::*;
+
+   fn item_id(location: StyleSheetLocation, index: u16, item_index: u16) -> NodeStyleItemId {
+      NodeStyleItemId {
+         item_index,
+         sheet_id: NodeStyleSheetId { index, location },
+      }
+   }
+
+   fn attr_info(item_ids: impl IntoIterator<Item = NodeStyleItemId>) -> NodeStyleAttrInfo {
+      item_ids
+         .into_iter()
+         .collect::<std::collections::BinaryHeap<_>>()
+         .either_right()
+         .into()
+   }
+
+   #[test]
+   fn report_winner_and_candidates() {
+      let normal_attr_info = attr_info([
+         item_id(StyleSheetLocation::Shared, 3, 0),
+         item_id(StyleSheetLocation::Inline, 0, 1),
+      ]);
+      let mut inter_attr_infos = NodeInterStyleAttrInfos::default();
+      for interaction in [StyleInteraction::Hover, StyleInteraction::Focus] {
+         let mut attr_infos = NodeStyleAttrInfos::default();
+         attr_infos.insert(0, attr_info([item_id(StyleSheetLocation::Inline, 1, 0)]));
+         inter_attr_infos.insert(interaction, attr_infos);
+      }
+
+      let report = StyleAttrReport::new(
+         0,
+         "bg_color",
+         StyleInteraction::Focus,
+         false,
+         Some(&normal_attr_info),
+         Some(&inter_attr_infos),
+      );
+      let candidates = report
+         .candidates
+         .iter()
+         .map(|n| (n.item_id, n.interaction, n.is_active))
+         .collect::<Vec<_>>();
+      assert_eq!(
+         candidates,
+         [
+            (
+               item_id(StyleSheetLocation::Inline, 1, 0),
+               Some(StyleInteraction::Hover),
+               false
+            ),
+            (
+               item_id(StyleSheetLocation::Inline, 1, 0),
+               Some(StyleInteraction::Focus),
+               true
+            ),
+            (item_id(StyleSheetLocation::Inline, 0, 1), None, true),
+            (item_id(StyleSheetLocation::Shared, 3, 0), None, true),
+         ]
+      );
+      assert_eq!(
+         report.winner_candidate().unwrap().interaction,
+         Some(StyleInteraction::Focus)
+      );
+
+      let report = StyleAttrReport::new(
+         0,
+         "bg_color",
+         StyleInteraction::empty(),
+         false,
+         Some(&normal_attr_info),
+         Some(&inter_attr_infos),
+      );
+      assert_eq!(
+         report.winner_candidate().unwrap().item_id,
+         item_id(StyleSheetLocation::Inline, 0, 1)
+      );
+
+      let report = StyleAttrReport::new(
+         0,
+         "bg_color",
+         StyleInteraction::Hover,
+         true,
+         Some(&normal_attr_info),
+         None,
+      );
+      assert_eq!(report.winner, None);
+   }
+}
@@ -0,0 +1,194 @@
+use core::any::TypeId;
+
+use crate::style::{x, StyleInteraction, StyleSheetOwner};
+
+/// The nodes the items of a selector style sheet are applied to, relative to the node owning it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum StyleCombinator {
+   Descendant,
+   Child,
+}
+
+/// The position of a node among the element children of its parent.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum StyleChildPosition {
+   First,
+   Last,
+   /// 1-based, like `:nth-child(n)`
+   Nth(usize),
+   /// 1-based from the end, like `:nth-last-child(n)`
+   NthLast(usize),
+}
+
+impl StyleChildPosition {
+   /// `index` is 0-based, `len` is the count of the element children of the parent
+   pub fn matches(&self, index: usize, len: usize) -> bool {
+      match *self {
+         StyleChildPosition::First => index == 0,
+         StyleChildPosition::Last => index + 1 == len,
+         StyleChildPosition::Nth(n) => index + 1 == n,
+         StyleChildPosition::NthLast(n) => len - index == n,
+      }
+   }
+}
+
+/// A style sheet with a selector is not applied to the node owning it, but to the matched
+/// descendants of the node, which are resolved again as the tree changes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct StyleSelector {
+   pub combinator: StyleCombinator,
+   /// Only nodes of the element with this tag name, like `span`
+   pub tag: Option<&'static str>,
+   /// Only nodes that applied the `TypedStyle` of this type
+   pub typed_style: Option<TypeId>,
+   pub position: Option<StyleChildPosition>,
+   /// Only while the node owning the style sheet matches the interaction, like `group-hover`
+   pub group_interaction: Option<StyleInteraction>,
+}
+
+/// A node checked against a [`StyleSelector`]
+pub struct StyleSelectorTarget<'a> {
+   /// 1 for the children of the node owning the style sheet
+   pub depth: usize,
+   pub tag: &'static str,
+   pub index: usize,
+   pub sibling_count: usize,
+   pub typed_styles: &'a [TypeId],
+}
+
+impl StyleSelector {
+   pub fn new(combinator: StyleCombinator) -> Self {
+      Self {
+         combinator,
+         tag: None,
+         typed_style: None,
+         position: None,
+         group_interaction: None,
+      }
+   }
+
+   pub fn matches(&self, target: &StyleSelectorTarget) -> bool {
+      (self.combinator == StyleCombinator::Descendant || target.depth == 1)
+         && self.tag.map_or(true, |n| n == target.tag)
+         && self
+            .typed_style
+            .map_or(true, |n| target.typed_styles.contains(&n))
+         && self
+            .position
+            .map_or(true, |n| n.matches(target.index, target.sibling_count))
+   }
+
+   /// Whether the style sheet currently applies, given the interaction of the node owning it
+   #[inline]
+   pub fn is_group_matched(&self, owner_interaction: StyleInteraction) -> bool {
+      self
+         .group_interaction
+         .map_or(true, |n| owner_interaction.contains(n))
+   }
+}
+
+impl<T> StyleSheetOwner<T> {
+   fn map_selector(mut self, f: impl FnOnce(&mut StyleSelector)) -> Self {
+      f(self
+         .selector
+         .get_or_insert_with(|| StyleSelector::new(StyleCombinator::Descendant)));
+      self
+   }
+
+   /// Only descendants of the element with this tag name
+   pub fn tag(self, tag: &'static str) -> Self {
+      self.map_selector(|n| n.tag = Some(tag))
+   }
+
+   /// Only descendants that applied the `TypedStyle` `S`
+   pub fn typed<S: 'static>(self) -> Self {
+      self.map_selector(|n| n.typed_style = Some(TypeId::of::<S>()))
+   }
+
+   pub fn first_child(self) -> Self {
+      self.map_selector(|n| n.position = Some(StyleChildPosition::First))
+   }
+
+   pub fn last_child(self) -> Self {
+      self.map_selector(|n| n.position = Some(StyleChildPosition::Last))
+   }
+
+   pub fn nth_child(self, n: usize) -> Self {
+      self.map_selector(|s| s.position = Some(StyleChildPosition::Nth(n)))
+   }
+
+   pub fn nth_last_child(self, n: usize) -> Self {
+      self.map_selector(|s| s.position = Some(StyleChildPosition::NthLast(n)))
+   }
+
+   /// Only while the node owning the style sheet matches `interaction`
+   pub fn group(self, interaction: StyleInteraction) -> Self {
+      self.map_selector(|n| n.group_interaction = Some(interaction))
+   }
+}
+
+pub fn x_descendants() -> StyleSheetOwner<()> {
+   StyleSheetOwner {
+      selector: Some(StyleSelector::new(StyleCombinator::Descendant)),
+      ..x()
+   }
+}
+
+pub fn x_children() -> StyleSheetOwner<()> {
+   StyleSheetOwner {
+      selector: Some(StyleSelector::new(StyleCombinator::Child)),
+      ..x()
+   }
+}
+
+/// Descendants while the node owning the style sheet is hovered
+pub fn x_group_hover() -> StyleSheetOwner<()> {
+   x_descendants().group(StyleInteraction::Hover)
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   struct ButtonStyle;
+
+   fn target(depth: usize, index: usize, typed_styles: &[TypeId]) -> StyleSelectorTarget {
+      StyleSelectorTarget {
+         depth,
+         tag: "span",
+         index,
+         sibling_count: 3,
+         typed_styles,
+      }
+   }
+
+   fn selector(style_sheet_owner: StyleSheetOwner<()>) -> StyleSelector {
+      style_sheet_owner.selector.unwrap()
+   }
+
+   #[test]
+   fn match_combinator_and_tag() {
+      assert!(selector(x_descendants().tag("span")).matches(&target(2, 0, &[])));
+      assert!(!selector(x_descendants().tag("div")).matches(&target(2, 0, &[])));
+      assert!(selector(x_children()).matches(&target(1, 0, &[])));
+      assert!(!selector(x_children()).matches(&target(2, 0, &[])));
+   }
+
+   #[test]
+   fn match_position() {
+      assert!(selector(x_children().first_child()).matches(&target(1, 0, &[])));
+      assert!(selector(x_children().last_child()).matches(&target(1, 2, &[])));
+      assert!(selector(x_children().nth_child(2)).matches(&target(1, 1, &[])));
+      assert!(!selector(x_children().nth_child(2)).matches(&target(1, 2, &[])));
+      assert!(selector(x_children().nth_last_child(1)).matches(&target(1, 2, &[])));
+   }
+
+   #[test]
+   fn match_typed_style_and_group() {
+      let selector = selector(x_group_hover().typed::<ButtonStyle>());
+      assert!(selector.matches(&target(3, 0, &[TypeId::of::<ButtonStyle>()])));
+      assert!(!selector.matches(&target(3, 0, &[])));
+      assert!(selector.is_group_matched(StyleInteraction::Active));
+      assert!(!selector.is_group_matched(StyleInteraction::Focus));
+   }
+}
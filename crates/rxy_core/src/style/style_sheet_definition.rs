@@ -1,24 +1,56 @@
 use bitflags::bitflags;
 
 use crate::style::attr_style_owner::AttrStyleOwner;
-use crate::style::view_member::{StyleSheetIndex, StyleSheetLocation};
-use crate::style::StyleItemValue;
+use crate::style::view_member::{to_style_item_index, StyleSheetIndex, StyleSheetLocation};
 use crate::style::{NodeAttrStyleItemId, NodeStyleItemId, NodeStyleSheetId};
+use crate::style::{StyleItemValue, StyleMedia, StyleMediaQuery, StyleSelector};
 use crate::{EitherExt, Renderer, RendererNodeId, RendererWorld};
 
 use super::Result;
 use alloc::vec::Vec;
 bitflags! {
+    /// Hover, active and focus come from the interaction of the node, the other states are set
+    /// by the user, see `disabled`, `checked` etc. attrs.
     #[repr(transparent)]
     #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
-    pub struct StyleInteraction: u8 {
-        const Focus  = 0b00000001;
-        const Hover  = 0b00000010;
-        const Active = 0b00000110;
+    pub struct StyleInteraction: u32 {
+        const Focus    = 0b00000001;
+        const Hover    = 0b00000010;
+        const Active   = 0b00000110;
+        const Disabled = 1 << 3;
+        const Checked  = 1 << 4;
+        const Selected = 1 << 5;
+        const Invalid  = 1 << 6;
+
+        // custom states
+        const _ = 0xFFFF_0000;
     }
 }
 
 impl StyleInteraction {
+   pub const CUSTOM_OFFSET: u32 = 16;
+   pub const CUSTOM_COUNT: u8 = 16;
+
+   /// The custom state at `index`, which is less than [`Self::CUSTOM_COUNT`]
+   #[inline]
+   pub fn custom(index: u8) -> Self {
+      assert!(
+         index < Self::CUSTOM_COUNT,
+         "custom state index out of range"
+      );
+      Self::from_bits_retain(1 << (Self::CUSTOM_OFFSET + index as u32))
+   }
+
+   /// The states that are set by the user instead of coming from the interaction of the node
+   #[inline]
+   pub fn user_states() -> Self {
+      Self::Disabled
+         | Self::Checked
+         | Self::Selected
+         | Self::Invalid
+         | Self::from_bits_retain(!0 << Self::CUSTOM_OFFSET)
+   }
+
    pub fn is_match(self, interaction: StyleInteraction, strict: bool) -> bool {
       if strict {
          self == interaction
@@ -27,8 +59,19 @@ impl StyleInteraction {
       }
    }
 
+   /// Disabled wins over the interaction, which wins over the other user states. Focus comes last.
    pub fn priority_iter() -> impl Iterator<Item = Self> {
-      [Self::Active, Self::Hover, Self::Focus].into_iter()
+      [
+         Self::Disabled,
+         Self::Active,
+         Self::Hover,
+         Self::Invalid,
+         Self::Checked,
+         Self::Selected,
+      ]
+      .into_iter()
+      .chain((0..Self::CUSTOM_COUNT).map(Self::custom))
+      .chain(core::iter::once(Self::Focus))
    }
 
    pub fn match_iter(self, strict: bool) -> impl Iterator<Item = Self> {
@@ -45,10 +88,37 @@ impl StyleInteraction {
 #[derive(Clone, Default, Debug)]
 pub struct StyleSheetDefinition {
    pub interaction: Option<StyleInteraction>,
+   /// The style sheet is only applied while this matches the current [`StyleMedia`]
+   pub media: Option<StyleMediaQuery>,
+   /// The items are applied to the matched descendants instead of the node owning the style sheet
+   pub selector: Option<StyleSelector>,
    pub items: Vec<StyleItemValue>,
 }
 
 impl StyleSheetDefinition {
+   #[inline]
+   pub fn is_media_matched(&self, media: &StyleMedia) -> bool {
+      self.media.map_or(true, |n| n.matches(media))
+   }
+
+   /// Whether the items are applied to the node owning the style sheet itself
+   #[inline]
+   pub fn is_applied_to_owner(&self, media: &StyleMedia) -> bool {
+      self.selector.is_none() && self.is_media_matched(media)
+   }
+
+   /// The item indices of the style sheet must fit [`StyleItemIndex`](super::StyleItemIndex)
+   pub fn check_items_len<R>(&self) -> Result<R>
+   where
+      R: Renderer,
+   {
+      match self.items.len() {
+         0 => Ok(()),
+         len => to_style_item_index(len - 1).map(|_| ()),
+      }
+   }
+
+   /// Call [`Self::check_items_len`] first, the item indices are truncated otherwise
    pub fn iter_attr_style_item_ids(
       &self,
       style_sheet_location: StyleSheetLocation,
@@ -82,6 +152,7 @@ impl StyleSheetDefinition {
       R: Renderer,
       T: AttrStyleOwner<R>,
    {
+      self.check_items_len()?;
       attr_style_owner.add_attr_style_items(
          self
             .iter_attr_style_item_ids(style_sheet_location, style_sheet_index)
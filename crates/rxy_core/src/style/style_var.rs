@@ -0,0 +1,230 @@
+use std::borrow::Cow;
+use std::iter::once;
+use std::marker::PhantomData;
+
+use crate::style::{
+   ApplyStyleSheets, StyleAttrValue, StyleItemValue, StyleSheetCtx, StyleSheetItems,
+   StyleSheetOwner, StyledNodeTree,
+};
+use crate::{
+   smallbox, AttrValue, ElementAttr, ElementAttrType, MapToAttrMarker, MaybeSend, Renderer,
+   SmallBox, ViewMember, ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin, XNest, XNestMapper, S1,
+};
+
+/// A reference to a style variable, used in place of the value of an attr.
+///
+/// The variable is looked up on the node the item is applied to and then on its ancestors,
+/// the value of the variable must have the value type of the attr.
+///
+/// ```ignore
+/// div().member(style_var("primary", Color::BLUE)).children(
+///    button().style(x().bg_color(var("--primary")).padding(var("gap"))),
+/// )
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct StyleVar(pub Cow<'static, str>);
+
+/// The leading `--` of `name` is optional
+pub fn var(name: impl Into<Cow<'static, str>>) -> StyleVar {
+   StyleVar(style_var_name(name.into()))
+}
+
+pub fn style_var_name(name: Cow<'static, str>) -> Cow<'static, str> {
+   match name {
+      Cow::Borrowed(n) => Cow::Borrowed(n.strip_prefix("--").unwrap_or(n)),
+      Cow::Owned(n) => match n.strip_prefix("--") {
+         Some(n) => Cow::Owned(n.to_string()),
+         None => Cow::Owned(n),
+      },
+   }
+}
+
+impl AttrValue for StyleVar {
+   fn clone_att_value(&self) -> SmallBox<dyn AttrValue, S1> {
+      smallbox!(self.clone())
+   }
+
+   fn default_value() -> Self {
+      Self(Cow::Borrowed(""))
+   }
+
+   #[cfg(not(feature = "bevy_reflect"))]
+   fn as_any(&self) -> &dyn core::any::Any {
+      self
+   }
+
+   fn eq(&self, other: &Self) -> bool {
+      self == other
+   }
+}
+
+impl StyleVar {
+   /// The variable referenced by a style item value, if it is a [`StyleVar`]
+   pub fn from_value(value: &StyleAttrValue) -> Option<&StyleVar> {
+      let value: &dyn AttrValue = value;
+      value.as_any().downcast_ref::<StyleVar>()
+   }
+}
+
+/// A [`StyleVar`] mapped to an attr, see [`XNest::MapInner`].
+pub struct StyleVarMapped<M>(pub StyleVar, PhantomData<M>);
+
+impl XNest for StyleVar {
+   type Inner = Self;
+   type MapInner<M> = StyleVarMapped<M>;
+
+   fn map_inner<M>(self) -> Self::MapInner<M> {
+      StyleVarMapped(self, PhantomData)
+   }
+
+   fn is_static() -> bool {
+      true
+   }
+}
+
+impl<U> XNestMapper<U> for StyleVar
+where
+   U: 'static,
+{
+   type MapInnerTo = U;
+
+   fn map_inner_to(
+      self,
+      f: impl FnOnce(Self::Inner) -> U + MaybeSend + Clone + 'static,
+   ) -> Self::MapInnerTo {
+      f(self)
+   }
+}
+
+impl<R, EA> StyleSheetItems<R> for StyleVarMapped<MapToAttrMarker<EA>>
+where
+   R: Renderer,
+   EA: ElementAttrType<R>,
+{
+   #[inline]
+   fn iter(self, _ctx: StyleSheetCtx<R>) -> impl Iterator<Item = StyleItemValue> + 'static {
+      once(StyleItemValue {
+         attr_id: EA::INDEX,
+         value: smallbox!(self.0),
+      })
+   }
+}
+
+impl<R, EA> ViewMemberOrigin<R> for StyleVarMapped<MapToAttrMarker<EA>>
+where
+   R: Renderer,
+   EA: ElementAttrType<R>,
+{
+   type Origin = ElementAttr<R, EA>;
+}
+
+/// Used on an element directly, the variable is applied as an inline style sheet of one item,
+/// so unlike a plain attr value it does not take precedence over the other inline style sheets.
+impl<R, EA> ViewMember<R> for StyleVarMapped<MapToAttrMarker<EA>>
+where
+   R: Renderer,
+   R::NodeTree: StyledNodeTree<R>,
+   EA: ElementAttrType<R>,
+{
+   #[inline]
+   fn count() -> ViewMemberIndex {
+      ApplyStyleSheets::<StyleSheetOwner<Self>>::count()
+   }
+
+   #[inline]
+   fn unbuild(ctx: ViewMemberCtx<R>, view_removed: bool) {
+      ApplyStyleSheets::<StyleSheetOwner<Self>>::unbuild(ctx, view_removed);
+   }
+
+   #[inline]
+   fn build(self, ctx: ViewMemberCtx<R>, will_rebuild: bool) {
+      ApplyStyleSheets(StyleSheetOwner::new(self)).build(ctx, will_rebuild);
+   }
+
+   #[inline]
+   fn rebuild(self, ctx: ViewMemberCtx<R>) {
+      ApplyStyleSheets(StyleSheetOwner::new(self)).rebuild(ctx);
+   }
+}
+
+/// Defines the style variable `name` on the node, visible to the node and its descendants.
+pub struct SetStyleVar<V> {
+   pub name: Cow<'static, str>,
+   pub value: V,
+}
+
+pub fn style_var<V>(name: impl Into<Cow<'static, str>>, value: V) -> SetStyleVar<V>
+where
+   V: AttrValue + Clone,
+{
+   SetStyleVar {
+      name: style_var_name(name.into()),
+      value,
+   }
+}
+
+#[derive(Clone, Debug)]
+struct SetStyleVarMemberState(Cow<'static, str>);
+
+impl<R, V> ViewMemberOrigin<R> for SetStyleVar<V>
+where
+   R: Renderer,
+   V: AttrValue + Clone,
+{
+   type Origin = Self;
+}
+
+impl<R, V> ViewMember<R> for SetStyleVar<V>
+where
+   R: Renderer,
+   R::NodeTree: StyledNodeTree<R>,
+   V: AttrValue + Clone,
+{
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(mut ctx: ViewMemberCtx<R>, view_removed: bool) {
+      if view_removed {
+         return;
+      }
+      let Some(SetStyleVarMemberState(name)) = ctx
+         .indexed_view_member_state_mut::<SetStyleVarMemberState>()
+         .cloned()
+      else {
+         return;
+      };
+      ctx.world.set_style_var(ctx.node_id, name, None);
+   }
+
+   fn build(self, mut ctx: ViewMemberCtx<R>, _will_rebuild: bool) {
+      ctx.set_indexed_view_member_state(SetStyleVarMemberState(self.name.clone()));
+      let value: StyleAttrValue = smallbox!(self.value);
+      ctx.world.set_style_var(ctx.node_id, self.name, Some(value));
+   }
+
+   fn rebuild(self, mut ctx: ViewMemberCtx<R>) {
+      if let Some(state) = ctx.indexed_view_member_state_mut::<SetStyleVarMemberState>() {
+         let prev_name = core::mem::replace(&mut state.0, self.name.clone());
+         if prev_name != self.name {
+            ctx.world
+               .set_style_var(ctx.node_id.clone(), prev_name, None);
+         }
+      }
+      let value: StyleAttrValue = smallbox!(self.value);
+      ctx.world.set_style_var(ctx.node_id, self.name, Some(value));
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn strip_var_name_prefix() {
+      assert_eq!(var("--primary"), var("primary"));
+      assert_eq!(var(String::from("--gap")).0, "gap");
+      assert_eq!(style_var_name(Cow::Borrowed("primary")), "primary");
+   }
+}
@@ -1,10 +1,42 @@
 #![allow(clippy::blocks_in_conditions)]
 
-use crate::style::{ApplyStyleSheets, StyleSheets, StyledNodeTree};
+use crate::style::{ApplyStyleSheets, Result, StyleError, StyleSheets, StyledNodeTree};
 use crate::{Renderer, ViewMember, ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin};
 
-pub type StyleItemIndex = u8;
-pub type StyleSheetIndex = u8;
+// The indices are u16 rather than usize on purpose: a `NodeStyleItemId` is kept for every item
+// applied to an attr of a node, so it stays as small as the style state it is stored in. Going
+// over the limits is an error, see `to_style_sheet_index` and `to_style_item_index`.
+
+/// The index of an item in its style sheet, so a style sheet has at most
+/// `StyleItemIndex::MAX + 1` items.
+pub type StyleItemIndex = u16;
+/// The index of a style sheet in its [`StyleSheetLocation`] of a node.
+///
+/// `StyleSheetIndex::MAX` is never used as an index, so the counts of style sheets fit it too and
+/// a node has at most `StyleSheetIndex::MAX` style sheets per location.
+pub type StyleSheetIndex = u16;
+
+/// Checked conversion of the position of a style sheet, see [`StyleSheetIndex`]
+pub fn to_style_sheet_index<R>(
+   index: usize,
+   location: StyleSheetLocation,
+) -> Result<R, StyleSheetIndex>
+where
+   R: Renderer,
+{
+   match StyleSheetIndex::try_from(index) {
+      Ok(n) if n != StyleSheetIndex::MAX => Ok(n),
+      _ => Err(StyleError::StyleSheetIndexOverflow { index, location }),
+   }
+}
+
+/// Checked conversion of the position of a style item, see [`StyleItemIndex`]
+pub fn to_style_item_index<R>(index: usize) -> Result<R, StyleItemIndex>
+where
+   R: Renderer,
+{
+   StyleItemIndex::try_from(index).map_err(|_| StyleError::StyleItemIndexOverflow { index })
+}
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub enum StyleSheetLocation {
@@ -21,22 +53,20 @@ pub struct ApplyStyleSheetsMemberState {
 }
 
 impl ApplyStyleSheetsMemberState {
-   pub fn get_and_increment_and_by_location(
+   pub fn get_and_increment_and_by_location<R>(
       &mut self,
       location: StyleSheetLocation,
-   ) -> StyleSheetIndex {
-      match location {
-         StyleSheetLocation::Inline => {
-            let r = self.inline_sheet_index;
-            self.inline_sheet_index += 1;
-            r
-         }
-         StyleSheetLocation::Shared => {
-            let r = self.shared_sheet_index;
-            self.shared_sheet_index += 1;
-            r
-         }
-      }
+   ) -> Result<R, StyleSheetIndex>
+   where
+      R: Renderer,
+   {
+      let index = match location {
+         StyleSheetLocation::Inline => &mut self.inline_sheet_index,
+         StyleSheetLocation::Shared => &mut self.shared_sheet_index,
+      };
+      let r = to_style_sheet_index(*index as usize, location)?;
+      *index += 1;
+      Ok(r)
    }
 }
 
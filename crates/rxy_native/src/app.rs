@@ -8,6 +8,7 @@ use winit::window::WindowBuilder;
 use rxy_core::{IntoView, Renderer, View, ViewCtx};
 
 use crate::{LayoutContext, NativeRenderer, NodeBundle, Style, UiLayoutTree, Val};
+use crate::input::FocusedNode;
 use crate::running_app::XyRunningApp;
 use crate::user_event::{EventLoopUserEvent, UserEventSender};
 use crate::window::{XyWindowScene, XyWindowSurfaceRenderer};
@@ -45,6 +46,7 @@ impl XyApp {
       });
 
       world.insert_resource(UiLayoutTree::new());
+      world.init_resource::<FocusedNode>();
       XyApp {
          world,
          window_builder: Some(window_builder),
@@ -120,6 +122,18 @@ impl XyApp {
                   running_app.resized(size);
                }
                WindowEvent::ScaleFactorChanged { .. } => {}
+               WindowEvent::CursorMoved { position, .. } => {
+                  running_app.cursor_moved(position);
+               }
+               WindowEvent::MouseInput { state, button, .. } => {
+                  running_app.mouse_input(state, button);
+               }
+               WindowEvent::MouseWheel { delta, .. } => {
+                  running_app.mouse_wheel(delta);
+               }
+               WindowEvent::KeyboardInput { event, .. } => {
+                  running_app.keyboard_input(&event);
+               }
                WindowEvent::CloseRequested => {
                   target.exit();
                }
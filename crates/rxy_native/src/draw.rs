@@ -9,7 +9,7 @@ use rxy_core::{Either, EitherExt};
 
 use crate::{GlobalTransform, Style, Text, TextLayoutInfo, UiRect, Val, ViewVisibility};
 use crate::draw_text::SceneExt;
-use crate::ui_node::{BackgroundColor, BorderColor, BorderRadius, Node, Outline};
+use crate::ui_node::{BackgroundColor, BorderColor, BorderRadius, Node, Outline, VelloFragment};
 
 
 pub trait RoundedRectExt {
@@ -44,6 +44,7 @@ pub struct DrawState {
       Or<(With<BackgroundColor>, With<BorderColor>, With<Outline>)>,
    >,
    pub bg_query_state: QueryState<(Entity, &'static BackgroundColor, &'static GlobalTransform)>,
+   pub fragment_query_state: QueryState<(&'static VelloFragment, &'static GlobalTransform)>,
    pub border_query_state: QueryState<(
       Entity,
       &'static BorderColor,
@@ -130,6 +131,13 @@ impl DrawState {
             );
          });
 
+      self
+         .fragment_query_state
+         .iter_mut(world)
+         .for_each(|(fragment, global_transform)| {
+            scene.append(fragment, Some(global_transform.into()));
+         });
+
       self.border_query_state.iter_mut(world).for_each(
          |(entity, border_color, global_transform, style)| {
             let Some((shape, calculated_size)) = self.shape_map.get(&entity) else {
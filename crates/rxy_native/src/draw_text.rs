@@ -8,6 +8,8 @@ use vello::peniko::{Brush, Color, Fill, Font};
 use vello::skrifa::FontRef;
 use vello::Scene;
 
+use crate::{BreakLineOn, JustifyText};
+
 pub(crate) fn to_font_ref(font: &Font) -> Option<FontRef<'_>> {
    use vello::skrifa::raw::FileRef;
    let file_ref = FileRef::new(font.data.as_ref()).ok()?;
@@ -33,6 +35,8 @@ pub struct TextStyle {
    pub line_height: f32,
    #[cfg_attr(feature = "reflect", reflect(ignore))]
    pub font: Option<Font>,
+   pub justify: JustifyText,
+   pub linebreak: BreakLineOn,
 }
 
 impl Default for TextStyle {
@@ -44,6 +48,8 @@ impl Default for TextStyle {
          hint: false,
          line_height: 1.,
          font: None,
+         justify: JustifyText::default(),
+         linebreak: BreakLineOn::default(),
       }
    }
 }
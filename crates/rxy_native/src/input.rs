@@ -0,0 +1,295 @@
+use bevy_ecs::prelude::{Component, Entity, Resource, World};
+use bevy_hierarchy::{Children, Parent};
+use winit::event::{ElementState, KeyEvent, MouseScrollDelta};
+use winit::keyboard::{Key, NamedKey};
+
+use rxy_core::common_renderer::{CommonEventRenderer, CommonKeyEvent};
+use rxy_core::{
+   MaybeSend, MaybeSync, ViewMember, ViewMemberCtx, ViewMemberIndex, ViewMemberOrigin,
+};
+
+use crate::ui_node::Node;
+use crate::{GlobalTransform, NativeRenderer, Text, Transform};
+
+/// The pixels scrolled by one line of the mouse wheel
+const SCROLL_LINE_HEIGHT: f32 = 20.;
+
+/// A single line text field edited with the keyboard while focused.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextInput {
+   pub value: String,
+}
+
+/// A node switching between checked and unchecked when clicked.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Toggle {
+   pub checked: bool,
+}
+
+/// The vertical scroll position of a node, its children are laid out offset by it.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct ScrollOffset {
+   pub y: f32,
+}
+
+/// The node receiving the keyboard input, set when a text input is clicked.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FocusedNode(pub Option<Entity>);
+
+/// The handlers of the node for the event `E`, keyed by the index of the view member that added them.
+#[derive(Component)]
+pub struct NodeEventHandlers<E: 'static>(
+   pub Vec<(ViewMemberIndex, Box<dyn FnMut(E) + Send + Sync>)>,
+);
+
+impl<E> Default for NodeEventHandlers<E> {
+   fn default() -> Self {
+      Self(vec![])
+   }
+}
+
+/// The new value of a text input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputValue(pub String);
+
+/// The new checked state of a toggle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToggleValue(pub bool);
+
+/// A click on the node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClickEvent;
+
+/// Calls the handlers of `target`, then of each of its ancestors.
+pub fn dispatch_event<E: Clone + 'static>(world: &mut World, target: Entity, event: E) {
+   let mut current_target = Some(target);
+   while let Some(entity) = current_target {
+      if let Some(mut handlers) = world.get_mut::<NodeEventHandlers<E>>(entity) {
+         for (_, handler) in handlers.0.iter_mut() {
+            handler(event.clone());
+         }
+      }
+      current_target = world.get::<Parent>(entity).map(|n| n.get());
+   }
+}
+
+/// The deepest node under `point`, the last child wins when siblings overlap.
+pub fn hit_test(world: &World, entity: Entity, point: glam::Vec2) -> Option<Entity> {
+   let (Some(node), Some(global_transform)) = (
+      world.get::<Node>(entity),
+      world.get::<GlobalTransform>(entity),
+   ) else {
+      return None;
+   };
+   if let Some(children) = world.get::<Children>(entity) {
+      if let Some(hit) = children
+         .iter()
+         .rev()
+         .find_map(|child| hit_test(world, *child, point))
+      {
+         return Some(hit);
+      }
+   }
+   let local = global_transform.0.inverse().transform_point2(point);
+   let size = node.calculated_size;
+   (local.x >= 0. && local.y >= 0. && local.x < size.x && local.y < size.y).then_some(entity)
+}
+
+fn find_ancestor<T: Component>(world: &World, mut entity: Entity) -> Option<Entity> {
+   loop {
+      if world.get::<T>(entity).is_some() {
+         return Some(entity);
+      }
+      entity = world.get::<Parent>(entity)?.get();
+   }
+}
+
+/// Focuses the clicked text input, flips the clicked toggle, then dispatches the click.
+pub fn handle_click(world: &mut World, target: Entity) {
+   world.resource_mut::<FocusedNode>().0 = find_ancestor::<TextInput>(world, target);
+
+   if let Some(toggle_entity) = find_ancestor::<Toggle>(world, target) {
+      let mut toggle = world.get_mut::<Toggle>(toggle_entity).unwrap();
+      toggle.checked = !toggle.checked;
+      let checked = toggle.checked;
+      dispatch_event(world, toggle_entity, ToggleValue(checked));
+   }
+
+   dispatch_event(world, target, ClickEvent);
+}
+
+fn key_name(key: &Key) -> Option<String> {
+   match key {
+      Key::Character(str) => Some(str.to_string()),
+      Key::Named(NamedKey::Space) => Some(" ".to_string()),
+      Key::Named(key) => Some(format!("{:?}", key)),
+      Key::Unidentified(_) | Key::Dead(_) => None,
+   }
+}
+
+/// Edits the focused text input, then dispatches the key down to the focused node, or to `root_entity`.
+pub fn handle_key(world: &mut World, root_entity: Entity, event: &KeyEvent) {
+   if event.state != ElementState::Pressed {
+      return;
+   }
+   let Some(key) = key_name(&event.logical_key) else {
+      return;
+   };
+   let focused = world.resource::<FocusedNode>().0;
+   if let Some(mut text_input) = focused.and_then(|n| world.get_mut::<TextInput>(n)) {
+      let changed = match &event.logical_key {
+         Key::Character(_) | Key::Named(NamedKey::Space) => {
+            text_input.value.push_str(&key);
+            true
+         }
+         Key::Named(NamedKey::Backspace) => text_input.value.pop().is_some(),
+         _ => false,
+      };
+      if changed {
+         let focused = focused.unwrap();
+         let value = text_input.value.clone();
+         if let Some(mut text) = world.get_mut::<Text>(focused) {
+            text.text = value.clone().into();
+         }
+         dispatch_event(world, focused, InputValue(value));
+      }
+   }
+   dispatch_event(
+      world,
+      focused.unwrap_or(root_entity),
+      CommonKeyEvent { key: key.into() },
+   );
+}
+
+/// Scrolls the nearest scroll view containing `target`, within the bounds of its last layout.
+pub fn handle_wheel(world: &mut World, target: Entity, delta: MouseScrollDelta) {
+   let delta = match delta {
+      MouseScrollDelta::LineDelta(_, y) => y * SCROLL_LINE_HEIGHT,
+      MouseScrollDelta::PixelDelta(position) => position.y as f32,
+   };
+   let Some(scroll_entity) = find_ancestor::<ScrollOffset>(world, target) else {
+      return;
+   };
+   let height = world
+      .get::<Node>(scroll_entity)
+      .map_or(0., |n| n.calculated_size.y);
+   // The child translations are the layout locations relative to the parent
+   let content_height = world
+      .get::<Children>(scroll_entity)
+      .into_iter()
+      .flat_map(|n| n.iter())
+      .filter_map(|child| Some((world.get::<Transform>(*child)?, world.get::<Node>(*child)?)))
+      .map(|(transform, node)| transform.0.translation.y + node.calculated_size.y)
+      .fold(0., f32::max);
+   let max_offset = (content_height - height).max(0.);
+   let mut scroll_offset = world.get_mut::<ScrollOffset>(scroll_entity).unwrap();
+   scroll_offset.y = (scroll_offset.y - delta).clamp(0., max_offset);
+}
+
+/// Adds the handler to the [`NodeEventHandlers<E>`] of the node.
+pub struct NativeEventViewMember<E: 'static> {
+   pub handler: Box<dyn FnMut(E) + Send + Sync>,
+}
+
+impl<E> NativeEventViewMember<E> {
+   pub fn new(handler: impl FnMut(E) + Send + Sync + 'static) -> Self {
+      Self {
+         handler: Box::new(handler),
+      }
+   }
+}
+
+impl<E> ViewMemberOrigin<NativeRenderer> for NativeEventViewMember<E>
+where
+   E: Send + 'static,
+{
+   type Origin = Self;
+}
+
+impl<E> ViewMember<NativeRenderer> for NativeEventViewMember<E>
+where
+   E: Send + 'static,
+{
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(ctx: ViewMemberCtx<NativeRenderer>, _view_removed: bool) {
+      if let Some(mut handlers) = ctx.world.get_mut::<NodeEventHandlers<E>>(ctx.node_id) {
+         handlers.0.retain(|(index, _)| *index != ctx.index);
+      }
+   }
+
+   fn build(self, ctx: ViewMemberCtx<NativeRenderer>, _will_rebuild: bool) {
+      let Some(mut entity_world_mut) = ctx.world.get_entity_mut(ctx.node_id) else {
+         return;
+      };
+      if !entity_world_mut.contains::<NodeEventHandlers<E>>() {
+         entity_world_mut.insert(NodeEventHandlers::<E>::default());
+      }
+      entity_world_mut
+         .get_mut::<NodeEventHandlers<E>>()
+         .unwrap()
+         .0
+         .push((ctx.index, self.handler));
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<NativeRenderer>) {
+      Self::unbuild(
+         ViewMemberCtx {
+            index: ctx.index,
+            world: &mut *ctx.world,
+            node_id: ctx.node_id,
+         },
+         false,
+      );
+      self.build(ctx, true);
+   }
+}
+
+impl CommonEventRenderer for NativeRenderer {
+   type ClickMember<F>
+      = NativeEventViewMember<ClickEvent>
+   where
+      F: FnMut() + MaybeSend + MaybeSync + 'static;
+   type InputMember<F>
+      = NativeEventViewMember<InputValue>
+   where
+      F: FnMut(String) + MaybeSend + MaybeSync + 'static;
+   type ToggleMember<F>
+      = NativeEventViewMember<ToggleValue>
+   where
+      F: FnMut(bool) + MaybeSend + MaybeSync + 'static;
+   type KeyDownMember<F>
+      = NativeEventViewMember<CommonKeyEvent>
+   where
+      F: FnMut(CommonKeyEvent) + MaybeSend + MaybeSync + 'static;
+
+   fn crate_click_member<F>(mut f: F) -> Self::ClickMember<F>
+   where
+      F: FnMut() + MaybeSend + MaybeSync + 'static,
+   {
+      NativeEventViewMember::new(move |_| f())
+   }
+
+   fn crate_input_member<F>(mut f: F) -> Self::InputMember<F>
+   where
+      F: FnMut(String) + MaybeSend + MaybeSync + 'static,
+   {
+      NativeEventViewMember::new(move |InputValue(value)| f(value))
+   }
+
+   fn crate_toggle_member<F>(mut f: F) -> Self::ToggleMember<F>
+   where
+      F: FnMut(bool) + MaybeSend + MaybeSync + 'static,
+   {
+      NativeEventViewMember::new(move |ToggleValue(checked)| f(checked))
+   }
+
+   fn crate_key_down_member<F>(f: F) -> Self::KeyDownMember<F>
+   where
+      F: FnMut(CommonKeyEvent) + MaybeSend + MaybeSync + 'static,
+   {
+      NativeEventViewMember::new(f)
+   }
+}
@@ -2,14 +2,15 @@ use bevy_ecs::change_detection::{Mut, Ref};
 use bevy_ecs::entity::Entity;
 use bevy_ecs::prelude::{QueryState, With};
 use bevy_ecs::world::World;
-use bevy_hierarchy::Children;
+use bevy_hierarchy::{Children, Parent};
 use taffy::AvailableSpace;
 use vello::skrifa::MetadataProvider;
 
 use crate::{
-    GlobalTransform, LayoutContext, PositionedGlyph, Style, Text, TextLayoutInfo, Transform,
+    resolve_calc_styles, CalcStyle, GlobalTransform, LayoutContext, PositionedGlyph, Style, Text, TextLayoutInfo, Transform,
     UiLayoutTree, ViewVisibility,
 };
+use crate::input::ScrollOffset;
 use crate::ui_node::Node;
 
 pub struct LayoutState {
@@ -32,6 +33,7 @@ pub struct LayoutState {
       &'static mut Transform,
       &'static mut GlobalTransform,
    )>,
+   pub calc_style_query_state: QueryState<(Entity, &'static CalcStyle, Option<&'static Parent>)>,
    pub style_query: QueryState<(Entity, Ref<'static, Style>), With<Node>>,
    pub children_query_state: QueryState<(Entity, Ref<'static, Children>), With<Node>>,
 }
@@ -84,12 +86,27 @@ impl LayoutState {
                })
                .collect();
 
+            let offset_factor = text.style.justify.offset_factor();
+            if offset_factor != 0. {
+               for line in layout.glyphs.split_mut(|n| n.is_none()) {
+                  let line_width = line
+                     .last()
+                     .and_then(|n| n.as_ref())
+                     .map_or(0., |n| n.position.x + n.size.x);
+                  let offset = (width - line_width) * offset_factor;
+                  for glyph in line.iter_mut().flatten() {
+                     glyph.position.x += offset;
+                  }
+               }
+            }
+
             layout.logical_size = glam::Vec2 {
                x: width,
                y: pen_y + line_height,
             };
          },
       );
+      resolve_calc_styles(world, &mut self.calc_style_query_state, &layout_context);
       world.resource_scope(|world, mut layout_tree: Mut<UiLayoutTree>| {
            for (entity, style) in self.style_query.iter(world) {
                layout_tree.upsert_node(entity, &style, &layout_context);
@@ -161,7 +178,11 @@ impl LayoutState {
                        inverse_target_scale_factor * glam::Vec2::new(layout.location.x, layout.location.y);
 
                    absolute_location += layout_location;
-                   global_transform.0.translation = absolute_location;
+                   // rotation and scale of the node are applied around its center
+                   let half_size = 0.5 * layout_size;
+                   global_transform.0 = glam::Affine2::from_translation(absolute_location + half_size)
+                       * glam::Affine2::from_mat2(transform.0.matrix2)
+                       * glam::Affine2::from_translation(-half_size);
 
                    let rounded_size = round_layout_coords(absolute_location + layout_size)
                        - round_layout_coords(absolute_location);
@@ -178,6 +199,9 @@ impl LayoutState {
                    if transform.0.translation.trunc() != rounded_location {
                        transform.0.translation = rounded_location;
                    }
+                   // the children of a scroll view are moved up by its offset
+                   let children_location = absolute_location
+                       - glam::Vec2::new(0., world.get::<ScrollOffset>(entity).map_or(0., |n| n.y));
                    if let Ok(children) = children_query.get(world,entity).map(|n|n.1.iter().copied().collect::<Vec<_>>()) {
                        for child_uinode in children {
                            update_uinode_geometry_recursive(
@@ -188,7 +212,7 @@ impl LayoutState {
                                children_query,
                                inverse_target_scale_factor,
                                rounded_size,
-                               absolute_location,
+                               children_location,
                            );
                        }
                    }
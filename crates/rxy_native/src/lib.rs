@@ -11,6 +11,7 @@ mod window;
 mod layout;
 mod draw;
 mod draw_text;
+pub mod input;
 pub mod world_ext;
 
 pub mod all_attrs {
@@ -24,6 +25,7 @@ pub mod prelude {
    pub use crate::renderer::*;
 
    pub use crate::Val;
+   pub use rxy_core::{Calc, CalcVal};
    pub use vello::peniko::Color;
 
    pub use crate::elements::prelude::*;
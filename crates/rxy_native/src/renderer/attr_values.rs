@@ -1,7 +1,7 @@
 use rxy_core::impl_attr_value_and_wrapper;
 use vello::kurbo::{Point, Rect, Shape};
 use rxy_core::{
-    impl_attr_value, impl_x_value_wrappers, smallbox, AttrValue,
+    impl_attr_value, impl_x_value_wrappers, smallbox, AttrValue, CalcVal,
     SmallBox, XValueWrapper, S1,
 };
 
@@ -23,7 +23,15 @@ impl_attr_value_and_wrapper! {
     // crate::GridTrack,
     // crate::GridPlacement,
     crate::Visibility,
-    crate::OverflowAxis
+    crate::OverflowAxis,
+    crate::BreakLineOn,
+    crate::JustifyText
+}
+
+impl Into<XValueWrapper<CalcVal<crate::Val>>> for crate::Val {
+    fn into(self) -> XValueWrapper<CalcVal<crate::Val>> {
+        XValueWrapper(CalcVal::Val(self))
+    }
 }
 
 
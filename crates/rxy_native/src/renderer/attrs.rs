@@ -1,10 +1,14 @@
 #![allow(non_camel_case_types)]
 
+use std::borrow::Cow;
+
+use glam::{Affine2, EulerRot, Quat, Vec3};
+use tracing::warn;
 use vello::peniko::{Brush, Color};
 
 use rxy_core::{
-    AttrIndex, attrs_fn_define, define_attr_get_fn, ElementAttrType, ElementAttrUntyped,
-    impl_index_for_tys, RendererNodeId, RendererWorld,
+    AttrIndex, attrs_fn_define, CalcProperty, CalcVal, define_attr_get_fn, ElementAttrType,
+    ElementAttrUntyped, impl_index_for_tys, RendererNodeId, RendererWorld,
 };
 
 pub use crate::{
@@ -12,12 +16,22 @@ pub use crate::{
     JustifyContent, JustifyItems, JustifySelf, NativeRenderer, OverflowAxis, PositionType, Text
     , Val, Visibility,
 };
+use crate::{BreakLineOn, CalcStyleEntityWorldMutExt, JustifyText, Transform};
+use crate::node_tree::Name;
 use crate::prelude::no_preclude::ALL_ATTRS;
 use crate::ui_node::{BackgroundColor, BorderColor, Outline};
 use crate::world_ext::ElementStyleEntityExt;
 
 macro_rules! common_attrs_fn_define {
-    ($($attr:ident)*) => {
+    (
+        extra = [$($extra:ident)*];
+        common = [$($common:ident)*];
+    ) => {
+        common_attrs_fn_define!(@DEFINE [$($common)* $($extra)*]);
+
+        $(impl rxy_core::common_renderer::CommonAttr<$crate::NativeRenderer> for $crate::all_attrs::$common {})*
+    };
+    (@DEFINE [$($attr:ident)*]) => {
         impl_index_for_tys! {
             $($crate::all_attrs::$attr)*
         }
@@ -94,73 +108,15 @@ macro_rules! element_attrs_fn_define {
     };
 }
 
-common_attrs_fn_define! {
-    // class
-    // name
-    // z_index
-    bg_color
-    border_left
-    border_right
-    border_top
-    border_bottom
-    border_color
-    display
-    position_type
-    overflow_x
-    overflow_y
-    direction
-    left
-    right
-    top
-    bottom
-    width
-    height
-    min_width
-    min_height
-    max_width
-    max_height
-    margin_left
-    margin_right
-    margin_top
-    margin_bottom
-    padding_left
-    padding_right
-    padding_top
-    padding_bottom
-    aspect_ratio
-    align_items
-    // justify_items TODO: support cfg
-    align_self
-    // justify_self
-    align_content
-    justify_content
-    flex_direction
-    flex_wrap
-    flex_grow
-    flex_shrink
-    flex_basis
-    column_gap
-    row_gap
-    visibility
-    // translation
-    // rotation
-    // scale
-    text_color
-    font_size
-    // text_linebreak
-    // text_align
-    // font
-    outline_width
-    outline_offset
-    outline_color
-    // grid_auto_flow
-    // grid_template_rows
-    // grid_template_columns
-    // grid_auto_rows
-    // grid_auto_columns
-    // grid_row
-    // grid_column
-}
+#[cfg(feature = "grid")]
+rxy_core::common_attrs!(common_attrs_fn_define! {
+    extra = [justify_items justify_self];
+});
+#[cfg(not(feature = "grid"))]
+rxy_core::common_attrs!(common_attrs_fn_define! {
+    extra = [];
+});
+rxy_core::assert_all_common_attrs!(NativeRenderer);
 define_attr_get_fn!(NativeRenderer);
 
 // #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
@@ -187,25 +143,25 @@ define_attr_get_fn!(NativeRenderer);
 //     }
 // }
 
-// #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
-// pub struct name;
-//
-// impl ElementAttrType<NativeRenderer> for name {
-//    type Value = Cow<'static, str>;
-//
-//    const NAME: &'static str = stringify!(name);
-//
-//    fn update_value(
-//       world: &mut RendererWorld<NativeRenderer>,
-//       node_id: RendererNodeId<NativeRenderer>,
-//       value: impl Into<Self::Value>,
-//    ) {
-//       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
-//          return;
-//       };
-//       entity_world_mut.insert(bevy_core::Name::new(value.into()));
-//    }
-// }
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub struct name;
+
+impl ElementAttrType<NativeRenderer> for name {
+   type Value = Cow<'static, str>;
+
+   const NAME: &'static str = stringify!(name);
+
+   fn update_value(
+      world: &mut RendererWorld<NativeRenderer>,
+      node_id: RendererNodeId<NativeRenderer>,
+      value: impl Into<Self::Value>,
+   ) {
+      let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
+         return;
+      };
+      entity_world_mut.insert(Name::new(value.into()));
+   }
+}
 
 // #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
 // pub struct z_index;
@@ -523,7 +479,7 @@ impl ElementAttrType<NativeRenderer> for direction {
 pub struct left;
 
 impl ElementAttrType<NativeRenderer> for left {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(left);
 
@@ -535,9 +491,7 @@ impl ElementAttrType<NativeRenderer> for left {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.left = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Left, value.into());
    }
 }
 
@@ -545,7 +499,7 @@ impl ElementAttrType<NativeRenderer> for left {
 pub struct right;
 
 impl ElementAttrType<NativeRenderer> for right {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(right);
    fn update_value(
@@ -556,9 +510,7 @@ impl ElementAttrType<NativeRenderer> for right {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.right = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Right, value.into());
    }
 }
 
@@ -566,7 +518,7 @@ impl ElementAttrType<NativeRenderer> for right {
 pub struct top;
 
 impl ElementAttrType<NativeRenderer> for top {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(top);
    fn update_value(
@@ -577,9 +529,7 @@ impl ElementAttrType<NativeRenderer> for top {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.top = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Top, value.into());
    }
 }
 
@@ -587,7 +537,7 @@ impl ElementAttrType<NativeRenderer> for top {
 pub struct bottom;
 
 impl ElementAttrType<NativeRenderer> for bottom {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(bottom);
    fn update_value(
@@ -598,9 +548,7 @@ impl ElementAttrType<NativeRenderer> for bottom {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.bottom = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Bottom, value.into());
    }
 }
 
@@ -608,7 +556,7 @@ impl ElementAttrType<NativeRenderer> for bottom {
 pub struct width;
 
 impl ElementAttrType<NativeRenderer> for width {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(width);
    fn update_value(
@@ -619,9 +567,7 @@ impl ElementAttrType<NativeRenderer> for width {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.width = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Width, value.into());
    }
 }
 
@@ -629,7 +575,7 @@ impl ElementAttrType<NativeRenderer> for width {
 pub struct height;
 
 impl ElementAttrType<NativeRenderer> for height {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(height);
    fn update_value(
@@ -640,9 +586,7 @@ impl ElementAttrType<NativeRenderer> for height {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.height = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::Height, value.into());
    }
 }
 
@@ -650,7 +594,7 @@ impl ElementAttrType<NativeRenderer> for height {
 pub struct min_width;
 
 impl ElementAttrType<NativeRenderer> for min_width {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(min_width);
    fn update_value(
@@ -661,9 +605,7 @@ impl ElementAttrType<NativeRenderer> for min_width {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.min_width = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MinWidth, value.into());
    }
 }
 
@@ -671,7 +613,7 @@ impl ElementAttrType<NativeRenderer> for min_width {
 pub struct min_height;
 
 impl ElementAttrType<NativeRenderer> for min_height {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(min_height);
    fn update_value(
@@ -682,9 +624,7 @@ impl ElementAttrType<NativeRenderer> for min_height {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.min_height = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MinHeight, value.into());
    }
 }
 
@@ -692,7 +632,7 @@ impl ElementAttrType<NativeRenderer> for min_height {
 pub struct max_width;
 
 impl ElementAttrType<NativeRenderer> for max_width {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(max_width);
    fn update_value(
@@ -703,9 +643,7 @@ impl ElementAttrType<NativeRenderer> for max_width {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.max_width = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MaxWidth, value.into());
    }
 }
 
@@ -713,7 +651,7 @@ impl ElementAttrType<NativeRenderer> for max_width {
 pub struct max_height;
 
 impl ElementAttrType<NativeRenderer> for max_height {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(max_height);
    fn update_value(
@@ -724,9 +662,7 @@ impl ElementAttrType<NativeRenderer> for max_height {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         style.max_height = value.into();
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MaxHeight, value.into());
    }
 }
 
@@ -734,7 +670,7 @@ impl ElementAttrType<NativeRenderer> for max_height {
 pub struct margin_left;
 
 impl ElementAttrType<NativeRenderer> for margin_left {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(margin_left);
    fn update_value(
@@ -745,10 +681,7 @@ impl ElementAttrType<NativeRenderer> for margin_left {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.margin.left = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MarginLeft, value.into());
    }
 }
 
@@ -756,7 +689,7 @@ impl ElementAttrType<NativeRenderer> for margin_left {
 pub struct margin_right;
 
 impl ElementAttrType<NativeRenderer> for margin_right {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(margin_right);
    fn update_value(
@@ -767,10 +700,7 @@ impl ElementAttrType<NativeRenderer> for margin_right {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.margin.right = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MarginRight, value.into());
    }
 }
 
@@ -778,7 +708,7 @@ impl ElementAttrType<NativeRenderer> for margin_right {
 pub struct margin_top;
 
 impl ElementAttrType<NativeRenderer> for margin_top {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(margin_top);
    fn update_value(
@@ -789,10 +719,7 @@ impl ElementAttrType<NativeRenderer> for margin_top {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.margin.top = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MarginTop, value.into());
    }
 }
 
@@ -800,7 +727,7 @@ impl ElementAttrType<NativeRenderer> for margin_top {
 pub struct margin_bottom;
 
 impl ElementAttrType<NativeRenderer> for margin_bottom {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(margin_bottom);
    fn update_value(
@@ -811,10 +738,7 @@ impl ElementAttrType<NativeRenderer> for margin_bottom {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.margin.bottom = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::MarginBottom, value.into());
    }
 }
 
@@ -822,7 +746,7 @@ impl ElementAttrType<NativeRenderer> for margin_bottom {
 pub struct padding_left;
 
 impl ElementAttrType<NativeRenderer> for padding_left {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(padding_left);
    fn update_value(
@@ -833,10 +757,7 @@ impl ElementAttrType<NativeRenderer> for padding_left {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.padding.left = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::PaddingLeft, value.into());
    }
 }
 
@@ -844,7 +765,7 @@ impl ElementAttrType<NativeRenderer> for padding_left {
 pub struct padding_right;
 
 impl ElementAttrType<NativeRenderer> for padding_right {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(padding_right);
    fn update_value(
@@ -855,10 +776,7 @@ impl ElementAttrType<NativeRenderer> for padding_right {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.padding.right = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::PaddingRight, value.into());
    }
 }
 
@@ -866,7 +784,7 @@ impl ElementAttrType<NativeRenderer> for padding_right {
 pub struct padding_top;
 
 impl ElementAttrType<NativeRenderer> for padding_top {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(padding_top);
    fn update_value(
@@ -877,10 +795,7 @@ impl ElementAttrType<NativeRenderer> for padding_top {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.padding.top = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::PaddingTop, value.into());
    }
 }
 
@@ -888,7 +803,7 @@ impl ElementAttrType<NativeRenderer> for padding_top {
 pub struct padding_bottom;
 
 impl ElementAttrType<NativeRenderer> for padding_bottom {
-   type Value = Val;
+   type Value = CalcVal<Val>;
 
    const NAME: &'static str = stringify!(padding_bottom);
    fn update_value(
@@ -899,10 +814,7 @@ impl ElementAttrType<NativeRenderer> for padding_bottom {
       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
          return;
       };
-      entity_world_mut.try_set_style(|style| {
-         let value = value.into();
-         style.padding.bottom = value;
-      });
+      entity_world_mut.set_calc_val(CalcProperty::PaddingBottom, value.into());
    }
 }
 
@@ -1222,68 +1134,72 @@ impl ElementAttrType<NativeRenderer> for visibility {
    }
 }
 
-// #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
-// pub struct translation;
-//
-// impl ElementAttrType<NativeRenderer> for translation {
-//    type Value = Vec2;
-//
-//    const NAME: &'static str = stringify!(translation);
-//    fn update_value(
-//       world: &mut RendererWorld<NativeRenderer>,
-//       node_id: RendererNodeId<NativeRenderer>,
-//       value: impl Into<Self::Value>,
-//    ) {
-//       let value = value.into();
-//       if let Some(tf) = world.get_mut::<Transform>(node_id) {
-//          tf.0.translation = value;
-//       } else {
-//          warn!("no found Transform component!");
-//       }
-//    }
-// }
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub struct translation;
 
-// #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
-// pub struct rotation;
-//
-// impl ElementAttrType<NativeRenderer> for rotation {
-//    type Value = f32;
-//
-//    const NAME: &'static str = stringify!(rotation);
-//    fn update_value(
-//       world: &mut RendererWorld<NativeRenderer>,
-//       node_id: RendererNodeId<NativeRenderer>,
-//       value: impl Into<Self::Value>,
-//    ) {
-//       let value = value.into();
-//       if let Some(mut tf) = world.get_mut::<Transform>(node_id) {
-//           tf.0 *= Affine2::from_angle(value);
-//       } else {
-//          warn!("no found Transform component!");
-//       }
-//    }
-// }
-//
-// #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
-// pub struct scale;
-//
-// impl ElementAttrType<NativeRenderer> for scale {
-//    type Value = Vec2;
-//
-//    const NAME: &'static str = stringify!(scale);
-//    fn update_value(
-//       world: &mut RendererWorld<NativeRenderer>,
-//       node_id: RendererNodeId<NativeRenderer>,
-//       value: impl Into<Self::Value>,
-//    ) {
-//       let value = value.into();
-//       if let Some(mut tf) = world.get_mut::<Transform>(node_id) {
-//          tf.0 *= Affine2::from_scale(value);
-//       } else {
-//          warn!("no found Transform component!");
-//       }
-//    }
-// }
+/// Like on Bevy, the layout overrides the `x` and `y` of the translation.
+impl ElementAttrType<NativeRenderer> for translation {
+   type Value = Vec3;
+
+   const NAME: &'static str = stringify!(translation);
+   fn update_value(
+      world: &mut RendererWorld<NativeRenderer>,
+      node_id: RendererNodeId<NativeRenderer>,
+      value: impl Into<Self::Value>,
+   ) {
+      let value = value.into();
+      if let Some(mut tf) = world.get_mut::<Transform>(node_id) {
+         tf.0.translation = value.truncate();
+      } else {
+         warn!("no found Transform component!");
+      }
+   }
+}
+
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub struct rotation;
+
+/// Only the rotation around the `z` axis is applied.
+impl ElementAttrType<NativeRenderer> for rotation {
+   type Value = Quat;
+
+   const NAME: &'static str = stringify!(rotation);
+   fn update_value(
+      world: &mut RendererWorld<NativeRenderer>,
+      node_id: RendererNodeId<NativeRenderer>,
+      value: impl Into<Self::Value>,
+   ) {
+      let (angle, _, _) = value.into().to_euler(EulerRot::ZYX);
+      if let Some(mut tf) = world.get_mut::<Transform>(node_id) {
+         let (scale, _, translation) = tf.0.to_scale_angle_translation();
+         tf.0 = Affine2::from_scale_angle_translation(scale, angle, translation);
+      } else {
+         warn!("no found Transform component!");
+      }
+   }
+}
+
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub struct scale;
+
+impl ElementAttrType<NativeRenderer> for scale {
+   type Value = Vec3;
+
+   const NAME: &'static str = stringify!(scale);
+   fn update_value(
+      world: &mut RendererWorld<NativeRenderer>,
+      node_id: RendererNodeId<NativeRenderer>,
+      value: impl Into<Self::Value>,
+   ) {
+      let value = value.into();
+      if let Some(mut tf) = world.get_mut::<Transform>(node_id) {
+         let (_, angle, translation) = tf.0.to_scale_angle_translation();
+         tf.0 = Affine2::from_scale_angle_translation(value.truncate(), angle, translation);
+      } else {
+         warn!("no found Transform component!");
+      }
+   }
+}
 
 #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
 pub struct text_color;
@@ -1330,50 +1246,50 @@ impl ElementAttrType<NativeRenderer> for font_size {
    }
 }
 
-// #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
-// pub struct text_linebreak;
-//
-// impl ElementAttrType<NativeRenderer> for text_linebreak {
-//    type Value = BreakLineOn;
-//
-//    const NAME: &'static str = stringify!(text_linebreak);
-//    fn update_value(
-//       world: &mut RendererWorld<NativeRenderer>,
-//       node_id: RendererNodeId<NativeRenderer>,
-//       value: impl Into<Self::Value>,
-//    ) {
-//       let value = value.into();
-//       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
-//          return;
-//       };
-//       entity_world_mut.scoped_text_styled_element_type(|text_schema_type, entity_ref| {
-//          text_schema_type.set_text_linebreak(entity_ref, value);
-//       });
-//    }
-// }
-//
-// #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
-// pub struct text_align;
-//
-// impl ElementAttrType<NativeRenderer> for text_align {
-//    type Value = JustifyText;
-//
-//    const NAME: &'static str = stringify!(text_align);
-//    fn update_value(
-//       world: &mut RendererWorld<NativeRenderer>,
-//       node_id: RendererNodeId<NativeRenderer>,
-//       value: impl Into<Self::Value>,
-//    ) {
-//       let value = value.into();
-//       let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
-//          return;
-//       };
-//       entity_world_mut.scoped_text_styled_element_type(|text_schema_type, entity_ref| {
-//          text_schema_type.set_text_align(entity_ref, value);
-//       });
-//    }
-// }
-//
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub struct text_linebreak;
+
+impl ElementAttrType<NativeRenderer> for text_linebreak {
+   type Value = BreakLineOn;
+
+   const NAME: &'static str = stringify!(text_linebreak);
+   fn update_value(
+      world: &mut RendererWorld<NativeRenderer>,
+      node_id: RendererNodeId<NativeRenderer>,
+      value: impl Into<Self::Value>,
+   ) {
+      let value = value.into();
+      let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
+         return;
+      };
+      if let Some(mut text) = entity_world_mut.get_mut::<Text>() {
+         text.style.linebreak = value;
+      }
+   }
+}
+
+#[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
+pub struct text_align;
+
+impl ElementAttrType<NativeRenderer> for text_align {
+   type Value = JustifyText;
+
+   const NAME: &'static str = stringify!(text_align);
+   fn update_value(
+      world: &mut RendererWorld<NativeRenderer>,
+      node_id: RendererNodeId<NativeRenderer>,
+      value: impl Into<Self::Value>,
+   ) {
+      let value = value.into();
+      let Some(mut entity_world_mut) = world.get_entity_mut(node_id) else {
+         return;
+      };
+      if let Some(mut text) = entity_world_mut.get_mut::<Text>() {
+         text.style.justify = value;
+      }
+   }
+}
+
 // #[derive(Copy, Clone, Debug, PartialOrd, PartialEq)]
 // pub struct font;
 //
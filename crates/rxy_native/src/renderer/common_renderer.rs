@@ -5,7 +5,9 @@ use rxy_core::common_renderer::CommonRenderer;
 use rxy_core::{
    define_common_view_fns, ElementAttrMember, ElementView, MapToAttrMarker, MemberOwner, XNest,
 };
-use crate::elements::element_img;
+use crate::elements::{
+   element_canvas, element_img, element_scroll_view, element_text_input, element_toggle,
+};
 
 define_common_view_fns!(NativeRenderer);
 
@@ -16,6 +18,10 @@ impl CommonRenderer for NativeRenderer {
       NativeElement<element_span, (T,)>;
    type ButtonView = NativeElement<element_div, ()>;
    type ImgView = NativeElement<element_img, ()>;
+   type ScrollView = NativeElement<element_scroll_view, ()>;
+   type TextInputView = NativeElement<element_text_input, ()>;
+   type ToggleView = NativeElement<element_toggle, ()>;
+   type CanvasView = NativeElement<element_canvas, ()>;
    type TextContentEA = all_attrs::content;
 
    fn crate_text<T>(
@@ -38,6 +44,22 @@ impl CommonRenderer for NativeRenderer {
    fn crate_img() -> Self::ImgView {
       NativeElement::default()
    }
+
+   fn crate_scroll() -> Self::ScrollView {
+      NativeElement::default()
+   }
+
+   fn crate_text_input() -> Self::TextInputView {
+      NativeElement::default()
+   }
+
+   fn crate_toggle() -> Self::ToggleView {
+      NativeElement::default()
+   }
+
+   fn crate_canvas() -> Self::CanvasView {
+      NativeElement::default()
+   }
 }
 
 #[cfg(feature = "dynamic_element")]
@@ -49,6 +71,10 @@ impl CommonRenderer for NativeRenderer {
    DynamicNativeElement<element_span>;
    type ButtonView = DynamicNativeElement<element_div>;
    type ImgView = DynamicNativeElement<element_img>;
+   type ScrollView = DynamicNativeElement<element_scroll_view>;
+   type TextInputView = DynamicNativeElement<element_text_input>;
+   type ToggleView = DynamicNativeElement<element_toggle>;
+   type CanvasView = DynamicNativeElement<element_canvas>;
    type TextContentEA = all_attrs::content;
 
    fn crate_text<T>(
@@ -76,4 +102,20 @@ impl CommonRenderer for NativeRenderer {
    fn crate_img() -> Self::ImgView {
       DynamicNativeElement::default()
    }
+
+   fn crate_scroll() -> Self::ScrollView {
+      DynamicNativeElement::default()
+   }
+
+   fn crate_text_input() -> Self::TextInputView {
+      DynamicNativeElement::default()
+   }
+
+   fn crate_toggle() -> Self::ToggleView {
+      DynamicNativeElement::default()
+   }
+
+   fn crate_canvas() -> Self::CanvasView {
+      DynamicNativeElement::default()
+   }
 }
@@ -0,0 +1,32 @@
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+
+use rxy_core::{ElementType, ElementTypeUnTyped, RendererNodeId, RendererWorld};
+
+use super::element_div;
+use crate::renderer::NativeRenderer;
+use crate::ui_node::VelloFragment;
+
+/// A div drawing the scene of its [`VelloFragment`] at the node location
+#[derive(Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+pub struct element_canvas;
+
+impl ElementType<NativeRenderer> for element_canvas {
+   const TAG_NAME: &'static str = "canvas";
+
+   fn get() -> &'static dyn ElementTypeUnTyped<NativeRenderer> {
+      &element_canvas
+   }
+
+   fn spawn(
+      world: &mut RendererWorld<NativeRenderer>,
+      parent: Option<&RendererNodeId<NativeRenderer>>,
+      reserve_node_id: Option<RendererNodeId<NativeRenderer>>,
+   ) -> RendererNodeId<NativeRenderer> {
+      let node_id =
+         <element_div as ElementType<NativeRenderer>>::spawn(world, parent, reserve_node_id);
+      world.entity_mut(node_id).insert(VelloFragment::default());
+      node_id
+   }
+}
@@ -1,12 +1,20 @@
 
+pub use canvas::*;
 pub use div::*;
 pub use img::*;
+pub use scroll_view::*;
 pub use span::*;
+pub use text_input::*;
+pub use toggle::*;
 use crate::NativeRenderer;
 
+mod canvas;
 mod div;
 mod img;
+mod scroll_view;
 mod span;
+mod text_input;
+mod toggle;
 
 pub mod prelude {
     use rxy_core::AttrIndex;
@@ -34,6 +42,18 @@ pub mod prelude {
            // flip_x
            // flip_y
        ]
+
+       [element_scroll_view]
+       attrs = []
+
+       [element_text_input]
+       attrs = []
+
+       [element_toggle]
+       attrs = []
+
+       [element_canvas]
+       attrs = []
    }
 }
 
@@ -48,6 +68,10 @@ impl ElementTypeRegisterExt for bevy_reflect::TypeRegistry {
       self.register::<element_div>();
       self.register::<element_span>();
       self.register::<element_img>();
+      self.register::<element_scroll_view>();
+      self.register::<element_text_input>();
+      self.register::<element_toggle>();
+      self.register::<element_canvas>();
 
       #[cfg(feature = "dynamic_element")]
       use rxy_core::ElementTypeTypeInfo;
@@ -55,7 +79,11 @@ impl ElementTypeRegisterExt for bevy_reflect::TypeRegistry {
       self
          .register_type_data::<element_div, ElementTypeTypeInfo<NativeRenderer>>()
          .register_type_data::<element_span, ElementTypeTypeInfo<NativeRenderer>>()
-         .register_type_data::<element_img, ElementTypeTypeInfo<NativeRenderer>>();
+         .register_type_data::<element_img, ElementTypeTypeInfo<NativeRenderer>>()
+         .register_type_data::<element_scroll_view, ElementTypeTypeInfo<NativeRenderer>>()
+         .register_type_data::<element_text_input, ElementTypeTypeInfo<NativeRenderer>>()
+         .register_type_data::<element_toggle, ElementTypeTypeInfo<NativeRenderer>>()
+         .register_type_data::<element_canvas, ElementTypeTypeInfo<NativeRenderer>>();
       self
    }
 }
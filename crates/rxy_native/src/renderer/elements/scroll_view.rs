@@ -0,0 +1,32 @@
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+
+use rxy_core::{ElementType, ElementTypeUnTyped, RendererNodeId, RendererWorld};
+
+use super::element_div;
+use crate::input::ScrollOffset;
+use crate::renderer::NativeRenderer;
+
+/// A div whose children are offset by its [`ScrollOffset`], scrolled with the mouse wheel
+#[derive(Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+pub struct element_scroll_view;
+
+impl ElementType<NativeRenderer> for element_scroll_view {
+   const TAG_NAME: &'static str = "scroll_view";
+
+   fn get() -> &'static dyn ElementTypeUnTyped<NativeRenderer> {
+      &element_scroll_view
+   }
+
+   fn spawn(
+      world: &mut RendererWorld<NativeRenderer>,
+      parent: Option<&RendererNodeId<NativeRenderer>>,
+      reserve_node_id: Option<RendererNodeId<NativeRenderer>>,
+   ) -> RendererNodeId<NativeRenderer> {
+      let node_id =
+         <element_div as ElementType<NativeRenderer>>::spawn(world, parent, reserve_node_id);
+      world.entity_mut(node_id).insert(ScrollOffset::default());
+      node_id
+   }
+}
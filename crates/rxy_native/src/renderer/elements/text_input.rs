@@ -0,0 +1,32 @@
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+
+use rxy_core::{ElementType, ElementTypeUnTyped, RendererNodeId, RendererWorld};
+
+use super::element_span;
+use crate::input::TextInput;
+use crate::renderer::NativeRenderer;
+
+/// A span showing the value of its [`TextInput`], edited with the keyboard after being clicked
+#[derive(Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+pub struct element_text_input;
+
+impl ElementType<NativeRenderer> for element_text_input {
+   const TAG_NAME: &'static str = "text_input";
+
+   fn get() -> &'static dyn ElementTypeUnTyped<NativeRenderer> {
+      &element_text_input
+   }
+
+   fn spawn(
+      world: &mut RendererWorld<NativeRenderer>,
+      parent: Option<&RendererNodeId<NativeRenderer>>,
+      reserve_node_id: Option<RendererNodeId<NativeRenderer>>,
+   ) -> RendererNodeId<NativeRenderer> {
+      let node_id =
+         <element_span as ElementType<NativeRenderer>>::spawn(world, parent, reserve_node_id);
+      world.entity_mut(node_id).insert(TextInput::default());
+      node_id
+   }
+}
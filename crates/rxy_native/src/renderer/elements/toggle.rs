@@ -0,0 +1,32 @@
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+
+use rxy_core::{ElementType, ElementTypeUnTyped, RendererNodeId, RendererWorld};
+
+use super::element_div;
+use crate::input::Toggle;
+use crate::renderer::NativeRenderer;
+
+/// A div flipping its [`Toggle`] when clicked
+#[derive(Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+pub struct element_toggle;
+
+impl ElementType<NativeRenderer> for element_toggle {
+   const TAG_NAME: &'static str = "toggle";
+
+   fn get() -> &'static dyn ElementTypeUnTyped<NativeRenderer> {
+      &element_toggle
+   }
+
+   fn spawn(
+      world: &mut RendererWorld<NativeRenderer>,
+      parent: Option<&RendererNodeId<NativeRenderer>>,
+      reserve_node_id: Option<RendererNodeId<NativeRenderer>>,
+   ) -> RendererNodeId<NativeRenderer> {
+      let node_id =
+         <element_div as ElementType<NativeRenderer>>::spawn(world, parent, reserve_node_id);
+      world.entity_mut(node_id).insert(Toggle::default());
+      node_id
+   }
+}
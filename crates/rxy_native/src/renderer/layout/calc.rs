@@ -0,0 +1,147 @@
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+#[cfg(feature = "reflect")]
+use bevy_ecs::prelude::ReflectComponent;
+use bevy_ecs::prelude::{EntityWorldMut, QueryState};
+use bevy_ecs::world::World;
+use bevy_hierarchy::Parent;
+#[cfg(feature = "reflect")]
+use bevy_reflect::prelude::*;
+
+use rxy_core::{CalcProperty, CalcVal, CalcValues};
+
+use crate::ui_node::Node;
+use crate::world_ext::ElementStyleEntityExt;
+use crate::{LayoutContext, Style, Val};
+
+/// The [`Calc`](rxy_core::Calc) values of a node, written into its [`Style`] as [`Val::Px`] before each layout.
+#[derive(Component, Default, Clone, Debug)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component, Default))]
+pub struct CalcStyle(pub CalcValues);
+
+pub fn style_val_mut(style: &mut Style, property: CalcProperty) -> &mut Val {
+   match property {
+      CalcProperty::Left => &mut style.left,
+      CalcProperty::Right => &mut style.right,
+      CalcProperty::Top => &mut style.top,
+      CalcProperty::Bottom => &mut style.bottom,
+      CalcProperty::Width => &mut style.width,
+      CalcProperty::Height => &mut style.height,
+      CalcProperty::MinWidth => &mut style.min_width,
+      CalcProperty::MinHeight => &mut style.min_height,
+      CalcProperty::MaxWidth => &mut style.max_width,
+      CalcProperty::MaxHeight => &mut style.max_height,
+      CalcProperty::MarginLeft => &mut style.margin.left,
+      CalcProperty::MarginRight => &mut style.margin.right,
+      CalcProperty::MarginTop => &mut style.margin.top,
+      CalcProperty::MarginBottom => &mut style.margin.bottom,
+      CalcProperty::PaddingLeft => &mut style.padding.left,
+      CalcProperty::PaddingRight => &mut style.padding.right,
+      CalcProperty::PaddingTop => &mut style.padding.top,
+      CalcProperty::PaddingBottom => &mut style.padding.bottom,
+   }
+}
+
+pub trait CalcStyleEntityWorldMutExt {
+   fn set_calc_val(&mut self, property: CalcProperty, value: CalcVal<Val>);
+}
+
+impl CalcStyleEntityWorldMutExt for EntityWorldMut<'_> {
+   fn set_calc_val(&mut self, property: CalcProperty, value: CalcVal<Val>) {
+      match value {
+         CalcVal::Val(value) => {
+            self.try_set::<CalcStyle>(|calc_style| {
+               calc_style.0.remove(property);
+            });
+            self.try_set_style(|style| {
+               *style_val_mut(style, property) = value;
+            });
+         }
+         CalcVal::Calc(calc) => {
+            if let Some(mut calc_style) = self.get_mut::<CalcStyle>() {
+               calc_style.0.insert(property, calc);
+            } else {
+               self.insert(CalcStyle(CalcValues(vec![(property, calc)])));
+            }
+         }
+      }
+   }
+}
+
+/// Values are resolved in logical pixels, the unit of [`Val::Px`].
+///
+/// Percentages resolve against the parent size of the last layout.
+pub fn resolve_calc_styles(
+   world: &mut World,
+   calc_style_query: &mut QueryState<(Entity, &'static CalcStyle, Option<&'static Parent>)>,
+   layout_context: &LayoutContext,
+) {
+   let viewport = layout_context.physical_size / layout_context.scale_factor;
+   let resolved = calc_style_query
+      .iter(world)
+      .flat_map(|(entity, calc_style, parent)| {
+         // the size of a node is already in logical pixels
+         let parent_size = parent
+            .and_then(|parent| world.get::<Node>(parent.get()))
+            .map(|node| node.calculated_size)
+            .unwrap_or(viewport);
+         calc_style
+            .0
+            .resolve(parent_size.into(), viewport.into())
+            .map(move |(property, value)| (entity, property, Val::Px(value)))
+      })
+      .collect::<Vec<_>>();
+
+   for (entity, property, value) in resolved {
+      let Some(mut style) = world.get_mut::<Style>(entity) else {
+         continue;
+      };
+      // only trigger change detection when the resolved value is different
+      if *style_val_mut(style.bypass_change_detection(), property) != value {
+         *style_val_mut(&mut style, property) = value;
+      }
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use bevy_hierarchy::BuildWorldChildren;
+   use glam::Vec2;
+   use rxy_core::Calc;
+
+   use super::*;
+
+   #[test]
+   fn resolve_in_logical_pixels() {
+      let mut world = World::new();
+      let parent = world
+         .spawn((
+            Style::default(),
+            Node {
+               calculated_size: Vec2::new(300., 200.),
+               ..Default::default()
+            },
+         ))
+         .id();
+      let mut root_calc = CalcValues::default();
+      root_calc.insert(CalcProperty::Width, Calc::vw(50.));
+      root_calc.insert(CalcProperty::Height, Calc::percent(50.));
+      let root = world.spawn((Style::default(), CalcStyle(root_calc))).id();
+      let mut child_calc = CalcValues::default();
+      child_calc.insert(CalcProperty::Width, Calc::percent(50.) - Calc::px(10.));
+      let child = world.spawn((Style::default(), CalcStyle(child_calc))).id();
+      world.entity_mut(parent).add_child(child);
+
+      let mut query = world.query();
+      resolve_calc_styles(
+         &mut world,
+         &mut query,
+         &LayoutContext::new(2., Vec2::new(1600., 1200.)),
+      );
+
+      let root_style = world.get::<Style>(root).unwrap();
+      assert_eq!(root_style.width, Val::Px(400.));
+      assert_eq!(root_style.height, Val::Px(300.));
+      assert_eq!(world.get::<Style>(child).unwrap().width, Val::Px(140.));
+   }
+}
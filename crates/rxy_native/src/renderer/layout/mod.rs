@@ -8,6 +8,7 @@ use tracing::warn;
 
 #[cfg(any(feature = "flexbox", feature = "grid"))]
 pub use alignment::*;
+pub use calc::*;
 #[cfg(feature = "flexbox")]
 pub use flex::*;
 pub use geometry::*;
@@ -20,6 +21,7 @@ use crate::TextLayoutInfo;
 
 #[cfg(any(feature = "flexbox", feature = "grid"))]
 mod alignment;
+mod calc;
 pub mod convert;
 #[cfg(feature = "flexbox")]
 mod flex;
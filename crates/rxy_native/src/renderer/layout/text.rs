@@ -29,3 +29,52 @@ impl Default for Direction {
       Self::DEFAULT
    }
 }
+
+/// Describes the horizontal alignment of multiple lines of text relative to each other.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Default, PartialEq))]
+#[cfg_attr(
+   all(feature = "reflect", feature = "serialize"),
+   reflect(Serialize, Deserialize)
+)]
+pub enum JustifyText {
+   /// Leftmost character is immediately to the right of the render position.
+   #[default]
+   Left,
+   /// Leftmost & rightmost characters are equidistant to the render position.
+   Center,
+   /// Rightmost character is immediately to the left of the render position.
+   Right,
+}
+
+impl JustifyText {
+   /// The part of the free space of a line that is placed before it
+   pub fn offset_factor(self) -> f32 {
+      match self {
+         JustifyText::Left => 0.,
+         JustifyText::Center => 0.5,
+         JustifyText::Right => 1.,
+      }
+   }
+}
+
+/// Determines how lines will be broken when preventing text from running out of bounds.
+///
+/// Only explicit `\n` breaks lines for now, so all the variants are laid out as [`BreakLineOn::NoWrap`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Default, PartialEq))]
+#[cfg_attr(
+   all(feature = "reflect", feature = "serialize"),
+   reflect(Serialize, Deserialize)
+)]
+pub enum BreakLineOn {
+   /// Breaks lines at word boundaries, as defined by the Unicode Line Breaking Algorithm.
+   #[default]
+   WordBoundary,
+   /// Breaks lines at any character.
+   AnyCharacter,
+   /// No soft wrapping, lines are only broken by `\n`.
+   NoWrap,
+}
@@ -1,9 +1,10 @@
 use crate::Visibility;
 use crate::{AlignItems, Display, FlexDirection, FlexWrap, JustifyContent, PositionType, Val};
-use rxy_core::{impl_tailwind_attrs, impl_tailwind_attrs_use};
+use crate::{BreakLineOn, JustifyText};
+use rxy_core::{impl_tailwind_attrs, impl_tailwind_attrs_use, StaticElementAttr};
 
 use crate::NativeRenderer;
 
 impl_tailwind_attrs_use!();
-impl_tailwind_attrs!(NativeRenderer;MemberOwnerTailwindAttrs;MemberOwner);
-impl_tailwind_attrs!(NativeRenderer;ElementViewTailwindAttrs;ElementView);
+impl_tailwind_attrs!(NativeRenderer;MemberOwnerTailwindAttrs;MemberOwner;include_text);
+impl_tailwind_attrs!(NativeRenderer;ElementViewTailwindAttrs;ElementView;include_text);
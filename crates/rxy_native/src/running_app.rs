@@ -4,10 +4,12 @@ use bevy_ecs::entity::Entity;
 use bevy_ecs::prelude::World;
 use bevy_ecs::system::SystemParam;
 use tracing::{info_span, instrument};
-use winit::dpi::PhysicalSize;
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event::{ElementState, KeyEvent, MouseButton, MouseScrollDelta};
 
 use crate::draw::DrawState;
 use crate::draw_text::SceneExt;
+use crate::input::{handle_click, handle_key, handle_wheel, hit_test};
 use crate::layout::LayoutState;
 use crate::LayoutContext;
 use crate::user_event::EventLoopUserEvent;
@@ -15,6 +17,7 @@ use crate::window::NativeWorldExt;
 
 pub struct XyRunningApp {
    pub root_entity: Entity,
+   pub cursor_position: glam::Vec2,
    pub draw_state: DrawState,
    pub layout_state: LayoutState,
    pub world: World,
@@ -23,9 +26,11 @@ impl XyRunningApp {
    pub fn new(mut world: World, root_entity: Entity) -> Self {
       Self {
          root_entity,
+         cursor_position: glam::Vec2::ZERO,
          draw_state: DrawState {
             shape_query_state: world.query_filtered(),
             bg_query_state: world.query_filtered(),
+            fragment_query_state: world.query(),
             border_query_state: world.query_filtered(),
             outline_query_state: world.query_filtered(),
             text_query_state: world.query_filtered(),
@@ -36,6 +41,7 @@ impl XyRunningApp {
             layout_query_state: world.query_filtered(),
             node_transform_query_state: world.query_filtered(),
             children_query_state: world.query_filtered(),
+            calc_style_query_state: world.query(),
             style_query: world.query_filtered(),
          },
          world,
@@ -66,6 +72,38 @@ impl XyRunningApp {
       });
    }
 
+   pub fn cursor_moved(&mut self, position: PhysicalPosition<f64>) {
+      self.cursor_position = glam::Vec2::new(position.x as _, position.y as _);
+   }
+
+   pub fn mouse_input(&mut self, state: ElementState, button: MouseButton) {
+      if state != ElementState::Pressed || button != MouseButton::Left {
+         return;
+      }
+      if let Some(target) = hit_test(&self.world, self.root_entity, self.cursor_position) {
+         handle_click(&mut self.world, target);
+      }
+      self.request_redraw();
+   }
+
+   pub fn mouse_wheel(&mut self, delta: MouseScrollDelta) {
+      if let Some(target) = hit_test(&self.world, self.root_entity, self.cursor_position) {
+         handle_wheel(&mut self.world, target, delta);
+      }
+      self.request_redraw();
+   }
+
+   pub fn keyboard_input(&mut self, event: &KeyEvent) {
+      handle_key(&mut self.world, self.root_entity, event);
+      self.request_redraw();
+   }
+
+   fn request_redraw(&mut self) {
+      self.world.window_scope(|_, xy_window| {
+         xy_window.window.request_redraw();
+      });
+   }
+
    #[instrument(name = "redraw",skip(self))]
    pub fn redraw_requested(&mut self) {
       let root_entity = self.root_entity;
@@ -1,9 +1,24 @@
 mod renderer;
 
 pub use renderer::*;
+use rxy_core::{
+   CloneableSchemaSlot, FnSchema, IntoViewSchemaFnWrapper, RebuildFnReceiver, RenderSchemaCtx,
+   RendererSchemaView, SchemaSlot,
+};
+
+pub type FnSchemaView<F, P = ()> =
+   RendererSchemaView<WebRenderer, FnSchema<IntoViewSchemaFnWrapper<F, WebRenderer>, P>, (), ()>;
+
+pub type SchemaCtx = RenderSchemaCtx<WebRenderer>;
+
+pub type ReceiverProp<T> = RebuildFnReceiver<WebRenderer, T>;
+
+pub type Slot = SchemaSlot<WebRenderer>;
+pub type CloneableSlot = CloneableSchemaSlot<WebRenderer>;
 
 pub mod prelude {
    pub use super::attrs::CommonAttrsViewBuilder;
+   pub use super::{CloneableSlot, FnSchemaView, ReceiverProp, SchemaCtx, Slot};
    pub use crate::build_on_body;
    pub use crate::elements::*;
    pub use crate::renderer::common_renderer::*;
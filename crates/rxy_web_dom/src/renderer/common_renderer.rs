@@ -1,9 +1,14 @@
-use crate::attrs::node_value;
-use crate::elements::{element_button, element_div, element_img, element_text};
+use crate::attrs::{node_value, overflow_y};
+use crate::elements::{
+   element_button, element_canvas, element_checkbox, element_div, element_img, element_input,
+   element_text,
+};
 use crate::renderer::WebRenderer;
 use crate::WebElement;
 use rxy_core::common_renderer::CommonRenderer;
-use rxy_core::{define_common_view_fns, ElementAttrMember, MapToAttrMarker, MemberOwner, XNest};
+use rxy_core::{
+   define_common_view_fns, ElementAttr, ElementAttrMember, MapToAttrMarker, MemberOwner, XNest,
+};
 
 define_common_view_fns!(WebRenderer);
 
@@ -12,6 +17,10 @@ impl CommonRenderer for WebRenderer {
    type TextView<T: ElementAttrMember<Self, Self::TextContentEA>> = WebElement<element_text, (T,)>;
    type ButtonView = WebElement<element_button, ()>;
    type ImgView = WebElement<element_img, ()>;
+   type ScrollView = WebElement<element_div, ElementAttr<Self, overflow_y>>;
+   type TextInputView = WebElement<element_input, ()>;
+   type ToggleView = WebElement<element_checkbox, ()>;
+   type CanvasView = WebElement<element_canvas, ()>;
    type TextContentEA = node_value;
 
    fn crate_text<T>(
@@ -34,4 +43,20 @@ impl CommonRenderer for WebRenderer {
    fn crate_img() -> Self::ImgView {
       WebElement::default()
    }
+
+   fn crate_scroll() -> Self::ScrollView {
+      WebElement::new(ElementAttr::new("auto".into()))
+   }
+
+   fn crate_text_input() -> Self::TextInputView {
+      WebElement::default()
+   }
+
+   fn crate_toggle() -> Self::ToggleView {
+      WebElement::default()
+   }
+
+   fn crate_canvas() -> Self::CanvasView {
+      WebElement::default()
+   }
 }
@@ -7,7 +7,7 @@ use rxy_core::{count_macro, paste, view_children, ElementAttrMember, ElementView
 use rxy_core::{
    ElementAttrUntyped, ElementType, ElementTypeUnTyped, RendererNodeId, RendererWorld,
 };
-use wasm_bindgen::intern;
+use wasm_bindgen::{intern, JsCast};
 use web_sys::wasm_bindgen::JsValue;
 use web_sys::Node;
 
@@ -96,6 +96,7 @@ define_html_elements! {
     figure
     figcaption
     main
+    canvas
 }
 
 macro_rules! define_view_fns {
@@ -193,3 +194,28 @@ impl ElementType<WebRenderer> for element_text {
       element.into()
    }
 }
+
+/// An `<input type="checkbox">`
+pub struct element_checkbox;
+
+impl ElementType<WebRenderer> for element_checkbox {
+   const TAG_NAME: &'static str = "input";
+
+   fn get() -> &'static dyn ElementTypeUnTyped<WebRenderer> {
+      &Self
+   }
+
+   #[inline]
+   fn spawn(
+      _world: &mut RendererWorld<WebRenderer>,
+      parent: Option<&RendererNodeId<WebRenderer>>,
+      reserve_node_id: Option<RendererNodeId<WebRenderer>>,
+   ) -> RendererNodeId<WebRenderer> {
+      let node = spawn_element(intern(Self::TAG_NAME), parent, reserve_node_id);
+      node
+         .unchecked_ref::<web_sys::Element>()
+         .set_attribute(intern("type"), intern("checkbox"))
+         .unwrap();
+      node
+   }
+}
@@ -1,29 +1,36 @@
 use crate::WebRenderer;
-use rxy_core::MemberOwner;
+use core::marker::PhantomData;
+use rxy_core::common_renderer::{CommonEventRenderer, CommonKeyEvent};
 use rxy_core::{
    count_macro,
    prelude::{ViewMember, ViewMemberCtx},
    ViewMemberIndex,
 };
+use rxy_core::{MaybeSend, MaybeSync, MemberOwner};
 use wasm_bindgen::{
    intern,
    prelude::{Closure, JsCast, JsValue},
 };
+use web_sys::HtmlInputElement;
+pub use web_sys::{
+   DragEvent, Event, FocusEvent, InputEvent, KeyboardEvent, MouseEvent, WheelEvent,
+};
 
 pub trait WebRendererEventType {
    const NAME: &'static str;
 }
 
 macro_rules! define_events {
-    ($($name:ident $html:literal)*) => {
+    ($($name:ident $html:literal $payload:ident)*) => {
       impl<T> HtmlElementEvents for T where T: MemberOwner<WebRenderer> + Sized {}
 
+      /// The listeners receive the event cast to its `web_sys` type, like [`MouseEvent`] for `click`
       pub trait HtmlElementEvents: MemberOwner<WebRenderer> + Sized {
          count_macro::count! {
             $(
-            fn $name(self, closure: impl FnMut(JsValue) + 'static) -> Self::AddMember<WebEventViewMember<_int_>> {
+            fn $name(self, mut closure: impl FnMut($payload) + 'static) -> Self::AddMember<WebEventViewMember<_int_>> {
                self.member(WebEventViewMember {
-                  closure: Box::new(closure),
+                  closure: Box::new(move |event: JsValue| closure(event.unchecked_into())),
                })
             }
             )*
@@ -40,74 +47,96 @@ macro_rules! define_events {
 }
 
 define_events! {
-   on_abort "abort"
-   on_auto_complete "autocomplete"
-   on_auto_complete_error "autocompleteerror"
-   on_blur "blur"
-   on_cancel "cancel"
-   on_canplay "canplay"
-   on_canplay_through "canplaythrough"
-   on_change "change"
-   on_click "click"
-   on_close "close"
-   on_context_menu "contextmenu"
-   on_cue_change "cuechange"
-   on_dbl_click "dblclick"
-   on_drag "drag"
-   on_dragend "dragend"
-   on_dragenter "dragenter"
-   on_dragleave "dragleave"
-   on_dragover "dragover"
-   on_dragstart "dragstart"
-   on_drop "drop"
-   on_duration_change "durationchange"
-   on_emptied "emptied"
-   on_ended "ended"
-   on_error "error"
-   on_focus "focus"
-   on_input "input"
-   on_invalid "invalid"
-   on_key_down "keydown"
-   on_key_press "keypress"
-   on_keyup "keyup"
-   on_load "load"
-   on_loaded_data "loadeddata"
-   on_loaded_metadata "loadedmetadata"
-   on_load_start "loadstart"
-   on_mouse_down "mousedown"
-   on_mouse_enter "mouseenter"
-   on_mouse_leave "mouseleave"
-   on_mouse_move "mousemove"
-   on_mouse_out "mouseout"
-   on_mouse_over "mouseover"
-   on_mouse_up "mouseup"
-   on_mouse_wheel "mousewheel"
-   on_pause "pause"
-   on_play "play"
-   on_playing "playing"
-   on_progress "progress"
-   on_rate_change "ratechange"
-   on_reset "reset"
-   on_resize "resize"
-   on_scroll "scroll"
-   on_seeked "seeked"
-   on_seeking "seeking"
-   on_select "select"
-   on_show "show"
-   on_sort "sort"
-   on_stalled "stalled"
-   on_submit "submit"
-   on_suspend "suspend"
-   on_time_update "timeupdate"
-   on_toggle "toggle"
-   on_volume_change "volumechange"
-   on_waiting "waiting"
+   on_abort "abort" Event
+   on_auto_complete "autocomplete" Event
+   on_auto_complete_error "autocompleteerror" Event
+   on_blur "blur" FocusEvent
+   on_cancel "cancel" Event
+   on_canplay "canplay" Event
+   on_canplay_through "canplaythrough" Event
+   on_change "change" Event
+   on_click "click" MouseEvent
+   on_close "close" Event
+   on_context_menu "contextmenu" MouseEvent
+   on_cue_change "cuechange" Event
+   on_dbl_click "dblclick" MouseEvent
+   on_drag "drag" DragEvent
+   on_dragend "dragend" DragEvent
+   on_dragenter "dragenter" DragEvent
+   on_dragleave "dragleave" DragEvent
+   on_dragover "dragover" DragEvent
+   on_dragstart "dragstart" DragEvent
+   on_drop "drop" DragEvent
+   on_duration_change "durationchange" Event
+   on_emptied "emptied" Event
+   on_ended "ended" Event
+   on_error "error" Event
+   on_focus "focus" FocusEvent
+   on_input "input" InputEvent
+   on_invalid "invalid" Event
+   on_key_down "keydown" KeyboardEvent
+   on_key_press "keypress" KeyboardEvent
+   on_keyup "keyup" KeyboardEvent
+   on_load "load" Event
+   on_loaded_data "loadeddata" Event
+   on_loaded_metadata "loadedmetadata" Event
+   on_load_start "loadstart" Event
+   on_mouse_down "mousedown" MouseEvent
+   on_mouse_enter "mouseenter" MouseEvent
+   on_mouse_leave "mouseleave" MouseEvent
+   on_mouse_move "mousemove" MouseEvent
+   on_mouse_out "mouseout" MouseEvent
+   on_mouse_over "mouseover" MouseEvent
+   on_mouse_up "mouseup" MouseEvent
+   on_mouse_wheel "mousewheel" WheelEvent
+   on_pause "pause" Event
+   on_play "play" Event
+   on_playing "playing" Event
+   on_progress "progress" Event
+   on_rate_change "ratechange" Event
+   on_reset "reset" Event
+   on_resize "resize" Event
+   on_scroll "scroll" Event
+   on_seeked "seeked" Event
+   on_seeking "seeking" Event
+   on_select "select" Event
+   on_show "show" Event
+   on_sort "sort" Event
+   on_stalled "stalled" Event
+   on_submit "submit" Event
+   on_suspend "suspend" Event
+   on_time_update "timeupdate" Event
+   on_toggle "toggle" Event
+   on_volume_change "volumechange" Event
+   on_waiting "waiting" Event
 }
 
 pub struct WebEventState {
    pub closure: Closure<dyn FnMut(JsValue)>,
 }
 
+fn add_event_listener(
+   mut ctx: ViewMemberCtx<WebRenderer>,
+   name: &'static str,
+   closure: Box<dyn FnMut(JsValue)>,
+) {
+   let closure = Closure::wrap(closure);
+   ctx.node_id
+      .add_event_listener_with_callback(intern(name), closure.as_ref().unchecked_ref())
+      .unwrap();
+   ctx.set_indexed_view_member_state(WebEventState { closure });
+}
+
+fn remove_event_listener(mut ctx: ViewMemberCtx<WebRenderer>, name: &'static str) {
+   let state = ctx
+      .take_indexed_view_member_state::<WebEventState>()
+      .unwrap();
+
+   ctx.node_id
+      .remove_event_listener_with_callback(intern(name), state.closure.as_ref().unchecked_ref())
+      .unwrap();
+}
+
 pub struct WebEventViewMember<const I: usize> {
    pub closure: Box<dyn FnMut(JsValue)>,
 }
@@ -120,28 +149,12 @@ where
       1
    }
 
-   fn unbuild(mut ctx: ViewMemberCtx<WebRenderer>, _view_removed: bool) {
-      let state = ctx
-         .take_indexed_view_member_state::<WebEventState>()
-         .unwrap();
-
-      ctx.node_id
-         .remove_event_listener_with_callback(
-            intern(<Self as WebRendererEventType>::NAME),
-            state.closure.as_ref().unchecked_ref(),
-         )
-         .unwrap();
+   fn unbuild(ctx: ViewMemberCtx<WebRenderer>, _view_removed: bool) {
+      remove_event_listener(ctx, <Self as WebRendererEventType>::NAME);
    }
 
-   fn build(self, mut ctx: ViewMemberCtx<WebRenderer>, _will_rebuild: bool) {
-      let closure = wasm_bindgen::closure::Closure::wrap(self.closure);
-      ctx.node_id
-         .add_event_listener_with_callback(
-            intern(<Self as WebRendererEventType>::NAME),
-            closure.as_ref().unchecked_ref(),
-         )
-         .unwrap();
-      ctx.set_indexed_view_member_state(WebEventState { closure });
+   fn build(self, ctx: ViewMemberCtx<WebRenderer>, _will_rebuild: bool) {
+      add_event_listener(ctx, <Self as WebRendererEventType>::NAME, self.closure);
    }
 
    fn rebuild(self, ctx: ViewMemberCtx<WebRenderer>) {
@@ -156,3 +169,138 @@ where
       self.build(ctx, true);
    }
 }
+
+pub struct CommonClickEvent;
+pub struct CommonInputEvent;
+pub struct CommonToggleEvent;
+pub struct CommonKeyDownEvent;
+
+impl WebRendererEventType for CommonClickEvent {
+   const NAME: &'static str = "click";
+}
+
+impl WebRendererEventType for CommonInputEvent {
+   const NAME: &'static str = "input";
+}
+
+impl WebRendererEventType for CommonToggleEvent {
+   const NAME: &'static str = "change";
+}
+
+impl WebRendererEventType for CommonKeyDownEvent {
+   const NAME: &'static str = "keydown";
+}
+
+/// The event members of [`CommonEventRenderer`], which convert the `JsValue` of the event
+/// before calling the handler
+pub struct WebCommonEventViewMember<E> {
+   pub closure: Box<dyn FnMut(JsValue)>,
+   _marker: PhantomData<E>,
+}
+
+impl<E> WebCommonEventViewMember<E> {
+   pub fn new(closure: impl FnMut(JsValue) + 'static) -> Self {
+      Self {
+         closure: Box::new(closure),
+         _marker: PhantomData,
+      }
+   }
+}
+
+impl<E> ViewMember<WebRenderer> for WebCommonEventViewMember<E>
+where
+   E: WebRendererEventType + 'static,
+{
+   fn count() -> ViewMemberIndex {
+      1
+   }
+
+   fn unbuild(ctx: ViewMemberCtx<WebRenderer>, _view_removed: bool) {
+      remove_event_listener(ctx, E::NAME);
+   }
+
+   fn build(self, ctx: ViewMemberCtx<WebRenderer>, _will_rebuild: bool) {
+      add_event_listener(ctx, E::NAME, self.closure);
+   }
+
+   fn rebuild(self, ctx: ViewMemberCtx<WebRenderer>) {
+      Self::unbuild(
+         ViewMemberCtx {
+            index: ctx.index,
+            world: &mut *ctx.world,
+            node_id: ctx.node_id.clone(),
+         },
+         false,
+      );
+      self.build(ctx, true);
+   }
+}
+
+impl CommonEventRenderer for WebRenderer {
+   type ClickMember<F>
+      = WebCommonEventViewMember<CommonClickEvent>
+   where
+      F: FnMut() + MaybeSend + MaybeSync + 'static;
+   type InputMember<F>
+      = WebCommonEventViewMember<CommonInputEvent>
+   where
+      F: FnMut(String) + MaybeSend + MaybeSync + 'static;
+   type ToggleMember<F>
+      = WebCommonEventViewMember<CommonToggleEvent>
+   where
+      F: FnMut(bool) + MaybeSend + MaybeSync + 'static;
+   type KeyDownMember<F>
+      = WebCommonEventViewMember<CommonKeyDownEvent>
+   where
+      F: FnMut(CommonKeyEvent) + MaybeSend + MaybeSync + 'static;
+
+   fn crate_click_member<F>(mut f: F) -> Self::ClickMember<F>
+   where
+      F: FnMut() + MaybeSend + MaybeSync + 'static,
+   {
+      WebCommonEventViewMember::new(move |_| f())
+   }
+
+   fn crate_input_member<F>(mut f: F) -> Self::InputMember<F>
+   where
+      F: FnMut(String) + MaybeSend + MaybeSync + 'static,
+   {
+      WebCommonEventViewMember::new(move |event: JsValue| {
+         let Some(input) = event
+            .unchecked_into::<Event>()
+            .target()
+            .and_then(|n| n.dyn_into::<HtmlInputElement>().ok())
+         else {
+            return;
+         };
+         f(input.value())
+      })
+   }
+
+   fn crate_toggle_member<F>(mut f: F) -> Self::ToggleMember<F>
+   where
+      F: FnMut(bool) + MaybeSend + MaybeSync + 'static,
+   {
+      WebCommonEventViewMember::new(move |event: JsValue| {
+         let Some(input) = event
+            .unchecked_into::<Event>()
+            .target()
+            .and_then(|n| n.dyn_into::<HtmlInputElement>().ok())
+         else {
+            return;
+         };
+         f(input.checked())
+      })
+   }
+
+   fn crate_key_down_member<F>(mut f: F) -> Self::KeyDownMember<F>
+   where
+      F: FnMut(CommonKeyEvent) + MaybeSend + MaybeSync + 'static,
+   {
+      WebCommonEventViewMember::new(move |event: JsValue| {
+         f(CommonKeyEvent {
+            key: event.unchecked_into::<KeyboardEvent>().key().into(),
+         })
+      })
+   }
+}
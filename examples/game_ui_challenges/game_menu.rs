@@ -4,7 +4,7 @@
 
 use bevy::app::AppExit;
 use bevy::prelude::*;
-use rxy_bevy::navigation::RxyKeyboardNavigationPlugin;
+use rxy_bevy::navigation::{RxyKeyboardNavigationPlugin, RxySpatialNavigationPlugin};
 use rxy_ui::prelude::*;
 
 use std::fmt::Debug;
@@ -30,6 +30,7 @@ fn main() {
       RxyPlugin::default(),
       RxyStyleSheetPlugin::default(),
       RxyKeyboardNavigationPlugin::default(),
+      RxySpatialNavigationPlugin::default(),
    ))
    .init_state::<GameState>()
    .add_systems(Startup, setup);
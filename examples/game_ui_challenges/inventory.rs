@@ -16,7 +16,6 @@ use bevy::color::palettes::tailwind;
 
 mod components;
 
-use crate::InventoryDraggingStatus::NoDragging;
 use components::*;
 use hooked_collection::{HookVec, HookedVec, VecOperation};
 use rxy_bevy::vec_data_source::use_hooked_vec_resource_source;
@@ -31,8 +30,6 @@ fn main() {
       RxyStyleSheetPlugin::default(),
       RxyKeyboardNavigationPlugin::default(),
    ))
-   .init_resource::<DraggingInventoryItem>()
-   .init_resource::<InventoryDraggingStatus>()
    .init_resource::<InventoryCursorPosition>()
    .init_resource::<HoveredInventoryItem>()
    .add_systems(
@@ -148,9 +145,9 @@ fn game_ui() -> impl IntoView<BevyRenderer> {
             .gap(10)
             .padding(10)
             .on_pointer_move(
-               |mut cursor_position: ResMut<InventoryCursorPosition>,
-                e: Res<ListenerInputPointerMove>| {
-                  cursor_position.0 = e.pointer_location.position;
+               |In(e): In<PointerEventData>,
+                mut cursor_position: ResMut<InventoryCursorPosition>| {
+                  cursor_position.0 = e.position;
                },
             )
             .grid_template_columns(vec![RepeatedGridTrack::auto(INVENTORY_WIDTH)])
@@ -212,33 +209,9 @@ fn mange_hover_inventory_item_ui(world: &mut World) {
    );
 }
 
-#[derive(Resource, Default)]
-pub struct DraggingInventoryItem {
-   delta: Vec2,
-   index: usize,
-   view_key: Option<ViewRemoveOnDrop>,
-}
-
-impl DraggingInventoryItem {
-   pub fn reset(&mut self) {
-      self.delta = Default::default();
-      self.view_key = None;
-   }
-}
-
-#[derive(Resource, Default, PartialEq, Eq, Debug, Copy, Clone)]
-pub enum InventoryDraggingStatus {
-   #[default]
-   NoDragging,
-   FullDrag,
-   PartialDrag,
-}
-
-impl InventoryDraggingStatus {
-   pub fn is_dragging(&self) -> bool {
-      *self != NoDragging
-   }
-}
+/// The payload of a dragged item: the index of the slot it is dragged from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InventorySlot(usize);
 
 #[derive(ElementSchema)]
 pub struct InventoryItemView {
@@ -253,32 +226,31 @@ impl SchemaElementView<BevyRenderer> for InventoryItemView {
          index: Required(Static(index)),
       } = self;
 
+      let (drop_hover, set_drop_hover) = use_rw_signal(DropHoverState::None).split();
       let root = div()
-            .size(50)
-            .style((
-                x().relative()
-                    .bg_color(Color::WHITE)
-                    .border(1)
-                    .border_color(Color::BLACK),
-                x_hover().bg_color(tailwind::GRAY_500),
-            ))
-            .on_pointer_drop(
-                move |e: Res<ListenerInputPointerDrop>,
-                      mut dragging: ResMut<DraggingInventoryItem>,
-                      mut is_dragging: ResMut<InventoryDraggingStatus>,
-                      mut inventory_items: ResMut<InventoryItems>| {
-                    if inventory_items[dragging.index].0.is_none() {
-                        return;
-                    }
-                    inventory_items.swap(index, dragging.index);
-                    dragging.reset();
-                    *is_dragging = NoDragging;
-                },
+         .size(50)
+         .style((
+            x().relative().bg_color(Color::WHITE).border(1),
+            x_hover().bg_color(tailwind::GRAY_500),
+         ))
+         .border_color(rx(move || match drop_hover.get() {
+            DropHoverState::None => Color::BLACK,
+            DropHoverState::Accept => tailwind::GREEN_500.into(),
+            DropHoverState::Reject => tailwind::RED_500.into(),
+         }))
+         .member(
+            drop_target(
+               move |In(e): In<DropEvent<InventorySlot>>,
+                     mut inventory_items: ResMut<InventoryItems>| {
+                  let InventorySlot(from) = e.payload;
+                  if from != index {
+                     inventory_items.swap(index, from);
+                  }
+               },
             )
-            // .style(x_res(|is_dragging: &InventoryDraggingStatus| {
-            //     is_dragging.0.then_some(x_hover().border_color(Color::BLUE))
-            // }))
-            ;
+            .accept(move |slot: &InventorySlot| slot.0 != index)
+            .hover_signal(set_drop_hover),
+         );
       root.children(rx(move || {
          if let Some(item) = item.get().0 {
             fn item_view(
@@ -305,48 +277,19 @@ impl SchemaElementView<BevyRenderer> for InventoryItemView {
                         .bundle(pickable),
                   ))
             }
+            let drag = draggable(InventorySlot(index)).preview({
+               let item = item.clone();
+               move |_: &InventorySlot| div().size(50).children(item_view(item.clone(), true))
+            });
             let events = ()
-               .on_pointer_drag(
-                  move |e: Res<ListenerInputPointerDrag>,
-                        mut dargging: ResMut<DraggingInventoryItem>| {
-                     dargging.delta += e.delta;
-                  },
-               )
-               .on_pointer_drag_end(
-                  move |mut dragging: ResMut<DraggingInventoryItem>,
-                        mut is_dragging: ResMut<InventoryDraggingStatus>| {
-                     dragging.reset();
-                     *is_dragging = NoDragging;
-                  },
-               )
-               .on_pointer_drag_start({
-                  let item = item.clone();
-                  move |world: &mut World| {
-                     world.resource_mut::<HoveredInventoryItem>().item = None;
-                     *world.resource_mut::<InventoryDraggingStatus>() =
-                        InventoryDraggingStatus::FullDrag;
-                     let e = world.resource::<ListenerInputPointerDragStart>();
-                     let parent = world.get_parent(&e.listener()).unwrap();
-                     let view_key = world.spawn_view(
-                        into_view(item_view(item.clone(), true).z(1).member(x_res(
-                           move |dragging: &DraggingInventoryItem| {
-                              ().left(dragging.delta.x).top(dragging.delta.y)
-                           },
-                        ))),
-                        move |_| parent,
-                     );
-                     *world.resource_mut::<DraggingInventoryItem>() = DraggingInventoryItem {
-                        delta: Vec2::default(),
-                        index,
-                        view_key: Some(world.remove_on_drop(view_key)),
-                     };
-                  }
+               .on_pointer_drag_start(|mut hovered_inventory_item: ResMut<HoveredInventoryItem>| {
+                  hovered_inventory_item.item = None;
                })
                .on_pointer_over({
                   let item = item.clone();
                   move |mut hovered_inventory_item: ResMut<HoveredInventoryItem>,
-                        dragging_status: Res<InventoryDraggingStatus>| {
-                     if dragging_status.is_dragging() {
+                        drag_drop: Res<DragDropState>| {
+                     if drag_drop.is_dragging() {
                         return;
                      }
                      hovered_inventory_item.item = Some((index, item.clone()));
@@ -354,8 +297,8 @@ impl SchemaElementView<BevyRenderer> for InventoryItemView {
                })
                .on_pointer_out(
                   move |mut hovered_inventory_item: ResMut<HoveredInventoryItem>,
-                        dragging_status: Res<InventoryDraggingStatus>| {
-                     if dragging_status.is_dragging() {
+                        drag_drop: Res<DragDropState>| {
+                     if drag_drop.is_dragging() {
                         return;
                      }
                      hovered_inventory_item.item = None;
@@ -363,18 +306,15 @@ impl SchemaElementView<BevyRenderer> for InventoryItemView {
                );
             into_view(
                item_view(item, false)
-                  .visibility(x_res(move |dragging_status: &InventoryDraggingStatus| {
-                     let dragging_status = dragging_status.clone();
-                     member_builder(move |ctx: ViewMemberCtx<BevyRenderer>, _| {
-                        if ctx.world.resource::<DraggingInventoryItem>().index == index
-                           && dragging_status == InventoryDraggingStatus::FullDrag
-                        {
-                           Visibility::Hidden
-                        } else {
-                           Visibility::Inherited
-                        }
-                     })
+                  // The preview follows the cursor instead
+                  .visibility(x_res(move |drag_drop: &DragDropState| {
+                     if drag_drop.payload::<InventorySlot>() == Some(&InventorySlot(index)) {
+                        Visibility::Hidden
+                     } else {
+                        Visibility::Inherited
+                     }
                   }))
+                  .member(drag)
                   .member(events),
             )
             .either_left()
@@ -529,25 +529,23 @@ fn sample_context() -> impl IntoView<BevyRenderer> {
 }
 
 fn sample_system() -> impl IntoView<BevyRenderer> {
-   div().flex_col().gap(10).children(unsafe {
-      system_with_config(
-         Update,
-         |config| config.run_if(|| true),
-         |query: Query<Entity, With<Style>>| {
-            x_iter_keyed(
-               query
-                  .iter()
-                  .map(|entity| {
-                     Keyed(
-                        entity,
-                        span(format!("Style Entity: {:?}", entity)).margin(10),
-                     )
-                  })
-                  .collect::<Vec<_>>(),
-            )
-         },
-      )
-   })
+   div().flex_col().gap(10).children(system_with_config(
+      Update,
+      |config| config.run_if(|| true),
+      |query: Query<Entity, With<Style>>| {
+         x_iter_keyed(
+            query
+               .iter()
+               .map(|entity| {
+                  Keyed(
+                     entity,
+                     span(format!("Style Entity: {:?}", entity)).margin(10),
+                  )
+               })
+               .collect::<Vec<_>>(),
+         )
+      },
+   ))
 }
 
 fn sample_bundle() -> impl IntoView<BevyRenderer> {
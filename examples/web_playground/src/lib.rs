@@ -1,3 +1,4 @@
+use rxy_ui::common_renderer::{CommonEventRenderer, CommonRenderer};
 use rxy_ui::{prelude::*, web_dom::log};
 use wasm_bindgen::prelude::wasm_bindgen;
 
@@ -37,5 +38,49 @@ fn test_view() -> impl IntoView<WebRenderer> {
                 })
                 .children("Alert Btn"),
         )),
+        counter(),
+        checkbox_demo(),
+    ))
+}
+
+fn counter() -> impl IntoView<WebRenderer> {
+    let count = use_rw_signal(0);
+    div().display("flex").gap(5).children((
+        button()
+            .padding(10)
+            .border(rx(move || {
+                if count.get() % 2 == 0 {
+                    "2px solid green"
+                } else {
+                    "2px solid blue"
+                }
+            }))
+            .on_click(move |_| count.update(|count| *count += 1))
+            .children("Increase"),
+        rx(move || format!("Count: {}", count.get())),
+        x_if(rx(move || count.get() >= 10), "Count reached 10!"),
+    ))
+}
+
+/// The same schema builds on every renderer, e.g. `checkbox::<BevyRenderer>()` with the `bevy` feature
+#[schema(R)]
+fn schema_checkbox<R>(
+    mut ctx: RenderSchemaCtx<R>,
+    value: ReadSignal<bool>,
+    onchange: Sender<bool>,
+) -> impl IntoView<R>
+where
+    R: CommonRenderer + CommonEventRenderer,
+{
+    let checked = ctx.use_controlled_state(value, onchange);
+    R::crate_toggle().member(R::crate_toggle_member(move |value| checked.set(value)))
+}
+
+fn checkbox_demo() -> impl IntoView<WebRenderer> {
+    let checked = use_rw_signal(false);
+    div().display("flex").gap(5).children((
+        checkbox::<WebRenderer>().onchange(move |value| checked.set(value)),
+        rx(move || format!("Checked: {}", checked.get())),
+        x_if(checked, "The box is checked"),
     ))
 }
@@ -20,7 +20,7 @@ pub mod style {
 pub mod prelude {
    #[cfg(feature = "bevy")]
    pub use rxy_bevy::prelude::*;
-   #[cfg(feature = "bevy")]
+   #[cfg(any(feature = "bevy", feature = "web"))]
    pub use rxy_bevy_macro::schema;
    pub use rxy_core::prelude::*;
    pub use rxy_macro::PropValueWrapper;